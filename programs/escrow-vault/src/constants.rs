@@ -8,15 +8,42 @@ pub const USDC_VAULT_SEED: &[u8] = b"usdc_vault";
 /// USDC has 6 decimals (same as SPL Token standard)
 pub const USDC_DECIMALS: u8 = 6;
 
-/// 1 USDC in lamports (smallest unit)
-pub const USDC_UNIT: u64 = 1_000_000; // 1.0 USDC
+/// Most decimals a collateral mint may have (wrapped SOL has 9)
+pub const MAX_COLLATERAL_DECIMALS: u8 = 9;
 
-/// Collateral required per pair (1 USDC)
-/// 1 pair = 1 YES + 1 NO = 1 USDC locked
-pub const COLLATERAL_PER_PAIR: u64 = USDC_UNIT;
+/// Seed for the global ProtocolConfig PDA
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+
+/// Basis points denominator (100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum mint / redemption fee (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Seed for the per-market AMM pool PDA
+pub const AMM_POOL_SEED: &[u8] = b"amm_pool";
+
+/// Seed for the AMM pool LP share mint
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+
+/// Maximum settlement-worker authorities allowed to mint from the hot wallet
+pub const MAX_SETTLEMENT_AUTHORITIES: usize = 5;
+
+/// Maximum outcome mints tracked by a vault (matches the market registry limit)
+pub const MAX_VAULT_OUTCOMES: usize = market_registry::MAX_OUTCOMES;
 
 /// Maximum users that can be batch settled at once
 pub const MAX_BATCH_SETTLE_SIZE: u8 = 10;
 
 /// Number of decimals for outcome tokens (YES/NO)
-pub const OUTCOME_TOKEN_DECIMALS: u8 = 6;
\ No newline at end of file
+pub const OUTCOME_TOKEN_DECIMALS: u8 = 6;
+
+/// Default time holders have to claim after settlement (1 year)
+pub const DEFAULT_CLAIM_WINDOW: i64 = 365 * 24 * 60 * 60;
+
+/// Shortest claim window the admin can configure (1 day, 10 seconds under the testing feature)
+#[cfg(not(feature = "testing"))]
+pub const MIN_CLAIM_WINDOW: i64 = 24 * 60 * 60;
+
+#[cfg(feature = "testing")]
+pub const MIN_CLAIM_WINDOW: i64 = 10;
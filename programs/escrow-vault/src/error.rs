@@ -82,4 +82,73 @@ pub enum EscrowVaultError {
 
     #[msg("USDC vault mismatch")]
     UsdcVaultMismatch,
+
+    #[msg("Invalid batch accounts (expected YES, NO and USDC account per holder)")]
+    InvalidBatchAccounts,
+
+    #[msg("Holder has not delegated burn authority to the vault")]
+    MissingBurnDelegation,
+
+    #[msg("Insufficient YES/NO balance to merge")]
+    InsufficientOutcomeTokens,
+
+    #[msg("Signer is not an allowed settlement authority")]
+    UnauthorizedSettlementAuthority,
+
+    #[msg("Settlement authority already added")]
+    SettlementAuthorityAlreadyExists,
+
+    #[msg("Settlement authority not found")]
+    SettlementAuthorityNotFound,
+
+    #[msg("Maximum settlement authorities reached")]
+    TooManySettlementAuthorities,
+
+    #[msg("Fee exceeds maximum allowed basis points")]
+    FeeTooHigh,
+
+    #[msg("Treasury account mismatch")]
+    TreasuryMismatch,
+
+    #[msg("Outcome mints and token accounts do not match the vault")]
+    InvalidOutcomeAccounts,
+
+    #[msg("Pool has no liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Trade output is below the minimum requested")]
+    SlippageExceeded,
+
+    #[msg("Invalid LP share amount")]
+    InvalidLpAmount,
+
+    #[msg("Pool account does not match the market")]
+    PoolMismatch,
+
+    #[msg("Vault still holds collateral, owes swept collateral to late holders or the close grace period has not passed")]
+    VaultNotClosable,
+
+    #[msg("Claim window is below the minimum")]
+    InvalidClaimWindow,
+
+    #[msg("Claim deadline has passed, unclaimed collateral belongs to the treasury")]
+    ClaimWindowClosed,
+
+    #[msg("Claim deadline has not passed yet")]
+    ClaimWindowOpen,
+
+    #[msg("No unclaimed collateral left in the vault")]
+    NothingToSweep,
+
+    #[msg("Late claim exceeds the collateral swept from this vault")]
+    LateClaimExceedsSwept,
+
+    #[msg("Token program does not match the vault")]
+    TokenProgramMismatch,
+
+    #[msg("Collateral mint does not match the vault")]
+    CollateralMintMismatch,
+
+    #[msg("Collateral mint has too many decimals")]
+    UnsupportedCollateralDecimals,
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::OutcomeSide;
+
 
 #[event]
 
@@ -13,6 +15,10 @@ pub struct VaultInitialized {
     pub yes_token_mint : Pubkey,
 
     pub no_token_mint :Pubkey,
+
+    pub collateral_mint : Pubkey,
+
+    pub collateral_decimals : u8,
     
     pub timestamp : i64
 }
@@ -31,6 +37,8 @@ pub struct PairsMinted {
 
     pub collateral_locked : u64,
 
+    pub fee_amount : u64,
+
     pub total_locked :u64,
 
     pub total_yes_minted : u64,
@@ -39,6 +47,27 @@ pub struct PairsMinted {
 
     pub timestamp : i64
 }
+#[event]
+pub struct PairsMerged {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub user : Pubkey,
+
+    pub pairs : u64,
+
+    pub collateral_released : u64,
+
+    pub total_locked :u64,
+
+    pub total_yes_minted : u64,
+
+    pub total_no_minted : u64,
+
+    pub timestamp : i64
+}
+
 #[event]
 pub struct SettlementInitialized{
     pub vault : Pubkey,
@@ -47,6 +76,8 @@ pub struct SettlementInitialized{
 
     pub total_collateral : u64 ,
 
+    pub claim_deadline : i64,
+
     pub timestamp : i64,
 
 }
@@ -62,6 +93,8 @@ pub struct PayoutClaimed {
 
     pub payout_amount : u64,
 
+    pub fee_amount : u64,
+
     pub yes_burned : u64 ,
 
     pub no_burned : u64 ,
@@ -71,6 +104,22 @@ pub struct PayoutClaimed {
     pub timestamp : i64
 }
 
+#[event]
+pub struct BatchSettled {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub holders_settled : u8,
+
+    pub total_payout : u64,
+
+    pub total_fees : u64,
+
+    pub remaning_collateral : u64,
+
+    pub timestamp : i64
+}
 
 #[event]
 
@@ -90,4 +139,255 @@ pub struct  MintingResumed {
     pub market : Pubkey,
 
     pub timestamp : i64
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct SettlementAuthorityAdded {
+    pub vault : Pubkey,
+
+    pub authority : Pubkey,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct SettlementAuthorityRemoved {
+    pub vault : Pubkey,
+
+    pub authority : Pubkey,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub admin : Pubkey,
+
+    pub treasury_owner : Pubkey,
+
+    pub mint_fee_bps : u16,
+
+    pub redemption_fee_bps : u16,
+
+    pub timestamp : i64
+}
+
+
+#[event]
+pub struct CategoricalVaultInitialized {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub usdc_vault : Pubkey,
+
+    pub outcome_mints : Vec<Pubkey>,
+
+    pub collateral_mint : Pubkey,
+
+    pub collateral_decimals : u8,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct CompleteSetsMinted {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub user : Pubkey,
+
+    pub sets : u64,
+
+    pub collateral_locked : u64,
+
+    pub fee_amount : u64,
+
+    pub total_locked : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct CompleteSetsRedeemed {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub user : Pubkey,
+
+    pub sets : u64,
+
+    pub collateral_released : u64,
+
+    pub total_locked : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct CategoricalPayoutClaimed {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub user : Pubkey,
+
+    pub payout_amount : u64,
+
+    pub fee_amount : u64,
+
+    pub tokens_burned : Vec<u64>,
+
+    pub remaning_collateral : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct PoolInitialized {
+    pub pool : Pubkey,
+
+    pub market : Pubkey,
+
+    pub lp_mint : Pubkey,
+
+    pub fee_bps : u16,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub pool : Pubkey,
+
+    pub provider : Pubkey,
+
+    pub pairs : u64,
+
+    pub lp_shares : u64,
+
+    // usdc paid into the fee vault for the fees the new shares are entitled to
+    pub fee_buy_in : u64,
+
+    pub yes_reserve : u64,
+
+    pub no_reserve : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub pool : Pubkey,
+
+    pub provider : Pubkey,
+
+    pub lp_shares : u64,
+
+    pub yes_out : u64,
+
+    pub no_out : u64,
+
+    pub usdc_out : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct OutcomeBought {
+    pub pool : Pubkey,
+
+    pub buyer : Pubkey,
+
+    pub side : OutcomeSide,
+
+    // collateral actually taken from the buyer (whole pairs plus fees)
+    pub usdc_in : u64,
+
+    pub lp_fee : u64,
+
+    pub tokens_out : u64,
+
+    pub yes_reserve : u64,
+
+    pub no_reserve : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct OutcomeSold {
+    pub pool : Pubkey,
+
+    pub seller : Pubkey,
+
+    pub side : OutcomeSide,
+
+    pub tokens_in : u64,
+
+    pub lp_fee : u64,
+
+    pub usdc_out : u64,
+
+    pub yes_reserve : u64,
+
+    pub no_reserve : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+    // rent receiver (original payer)
+    pub payer : Pubkey,
+    // leftover usdc (rounding, donations) swept to the treasury
+    pub dust_swept : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct ClaimWindowUpdated {
+    pub admin : Pubkey,
+
+    pub claim_window : i64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct UnclaimedCollateralSwept {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub treasury : Pubkey,
+
+    pub amount : u64,
+    // outstanding supply per outcome mint (vault.outcome_mints order, binary = [YES, NO])
+    pub residual_supply : Vec<u64>,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct LatePayoutClaimed {
+    pub vault : Pubkey,
+
+    pub market : Pubkey,
+
+    pub user : Pubkey,
+
+    pub payout_amount : u64,
+
+    pub tokens_burned : Vec<u64>,
+
+    pub remaining_swept : u64,
+
+    pub timestamp : i64
+}
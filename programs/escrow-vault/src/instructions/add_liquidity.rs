@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{AMM_POOL_SEED, PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::LiquidityAdded, state::{AmmPool, EscrowVault, ProtocolConfig}, utils::{calculate_fee_buy_in, calculate_liquidity_deposit, collateral_transfer_amount, log_vault_state, verify_vault_invariant}
+};
+
+// provider deposits usdc for `pairs` complete sets, the pool keeps them at the current price
+// and returns the surplus of the lighter side to the provider, new shares also buy into the accrued fees
+#[derive(Accounts)]
+pub struct AddLiquidity<'info>{
+    pub provider : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    #[account(
+        seeds = [AMM_POOL_SEED,vault.market.as_ref()],
+        bump = pool.bump,
+        constraint = pool.vault == vault.key() @ EscrowVaultError::PoolMismatch
+    )]
+    pub pool : Account<'info,AmmPool>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == pool.lp_mint @ EscrowVaultError::PoolMismatch
+    )]
+    pub lp_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = yes_reserve.key() == pool.yes_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub yes_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = no_reserve.key() == pool.no_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub no_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.key() == pool.fee_vault @ EscrowVaultError::PoolMismatch
+    )]
+    pub fee_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_usdc.mint == usdc_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_usdc.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_yes_account.mint == yes_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_yes_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_yes_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_no_account.mint == no_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_no_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_no_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_lp_account.mint == lp_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_lp_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_lp_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<AddLiquidity>,pairs:u64)->Result<()>{
+    let pool_key = ctx.accounts.pool.key();
+    let clock = Clock::get()?;
+
+    require!(pairs>0,EscrowVaultError::InvalidPairCount);
+    require!(ctx.accounts.vault.can_mint(),EscrowVaultError::MintingPaused);
+
+    market_registry::cpi::assert_market_open(CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    ))?;
+
+    let (lp_shares, yes_kept, no_kept) = calculate_liquidity_deposit(
+        ctx.accounts.yes_reserve.amount,
+        ctx.accounts.no_reserve.amount,
+        ctx.accounts.lp_mint.supply,
+        pairs
+    )?;
+    require!(lp_shares>0,EscrowVaultError::InvalidLpAmount);
+    let fee_buy_in = calculate_fee_buy_in(ctx.accounts.fee_vault.amount, lp_shares, ctx.accounts.lp_mint.supply)?;
+
+    let vault = &mut ctx.accounts.vault;
+    let collateral = vault.lock_pairs(pairs)?;
+    let fee_amount = ctx.accounts.protocol_config.mint_fee(collateral)?;
+
+    msg!("Adding liquidity: {} pairs ({} collateral)", pairs, format_amount(collateral, vault.collateral_decimals));
+    msg!("  LP shares: {}", lp_shares);
+    msg!("  YES kept: {} / NO kept: {}", yes_kept, no_kept);
+    msg!("  Fee buy-in: {}", fee_buy_in);
+
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.provider_usdc.to_account_info(),
+                mint : ctx.accounts.usdc_mint.to_account_info(),
+                to : ctx.accounts.usdc_vault.to_account_info(),
+                authority : ctx.accounts.provider.to_account_info()
+            }
+        ),
+        collateral_transfer_amount(&ctx.accounts.usdc_mint, collateral)?, ctx.accounts.usdc_mint.decimals)?;
+
+    if fee_buy_in > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.provider_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.fee_vault.to_account_info(),
+                    authority : ctx.accounts.provider.to_account_info()
+                }
+            ),
+            collateral_transfer_amount(&ctx.accounts.usdc_mint, fee_buy_in)?, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    if fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.provider_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : ctx.accounts.provider.to_account_info()
+                }
+            ),
+            fee_amount, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump]
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    // every pair is minted once: kept part into the reserve, surplus straight to the provider
+    let legs = [
+        (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_reserve, &ctx.accounts.provider_yes_account, yes_kept),
+        (&ctx.accounts.no_token_mint, &ctx.accounts.no_reserve, &ctx.accounts.provider_no_account, no_kept),
+    ];
+    for (mint, reserve, provider_account, kept) in legs {
+        let surplus = pairs.checked_sub(kept).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        for (to, amount) in [(reserve.to_account_info(), kept), (provider_account.to_account_info(), surplus)] {
+            if amount == 0 {
+                continue;
+            }
+            token_interface::mint_to(
+                CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                    MintTo{
+                        mint : mint.to_account_info(),
+                        to,
+                        authority : vault.to_account_info()
+                    },
+                    vault_signer
+                ),
+                amount)?;
+        }
+    }
+
+    verify_vault_invariant(vault)?;
+    log_vault_state(vault, "add_liquidity");
+
+    let pool_market = ctx.accounts.pool.market;
+    let pool_seeds = &[
+        AMM_POOL_SEED,
+        pool_market.as_ref(),
+        &[ctx.accounts.pool.bump]
+    ];
+    token_interface::mint_to(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            MintTo{
+                mint : ctx.accounts.lp_mint.to_account_info(),
+                to : ctx.accounts.provider_lp_account.to_account_info(),
+                authority : ctx.accounts.pool.to_account_info()
+            },
+            &[&pool_seeds[..]]
+        ),
+        lp_shares)?;
+
+    emit!(LiquidityAdded{
+        pool : pool_key,
+        provider : ctx.accounts.provider.key(),
+        pairs,
+        lp_shares,
+        fee_buy_in,
+        yes_reserve : ctx.accounts.yes_reserve.amount.checked_add(yes_kept).ok_or(EscrowVaultError::ArithmeticOverflow)?,
+        no_reserve : ctx.accounts.no_reserve.amount.checked_add(no_kept).ok_or(EscrowVaultError::ArithmeticOverflow)?,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
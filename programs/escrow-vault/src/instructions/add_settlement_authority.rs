@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+
+use crate::{constants::{MAX_SETTLEMENT_AUTHORITIES, VAULT_SEED}, error::EscrowVaultError, events::SettlementAuthorityAdded, state::EscrowVault
+};
+
+#[derive(Accounts)]
+
+pub struct AddSettlementAuthority<'info> {
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    // read at call time rather than copied at vault creation, so it survives an admin handover
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault : Account<'info,EscrowVault>
+}
+
+pub fn handler(ctx:Context<AddSettlementAuthority>,authority:Pubkey)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(!vault.is_settlement_authority(&authority),EscrowVaultError::SettlementAuthorityAlreadyExists);
+    require!(
+        vault.settlement_authorities.len() < MAX_SETTLEMENT_AUTHORITIES,
+        EscrowVaultError::TooManySettlementAuthorities
+    );
+
+    vault.settlement_authorities.push(authority);
+
+    msg!("Settlement authority added for vault: {}", vault_key);
+    msg!("Authority: {}", authority);
+
+    emit!(SettlementAuthorityAdded {
+        vault: vault_key,
+        authority,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+use market_registry::format_amount;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::state::Market;
+use crate::{constants::{MAX_BATCH_SETTLE_SIZE, PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::{BatchSettled, PayoutClaimed}, state::{EscrowVault, PayoutCalculation, ProtocolConfig}
+};
+
+/// Number of remaining accounts passed per holder:
+/// [user_yes_account, user_no_account, user_usdc]
+pub const ACCOUNTS_PER_HOLDER: usize = 3;
+
+// settles many holders in one transaction (crank operated by the protocol admin)
+// every holder must have approved the vault as delegate on both YES and NO accounts
+#[derive(Accounts)]
+pub struct BatchSettle<'info>{
+    #[account(mut)]
+    pub authority : Signer<'info>,
+
+    // only the registry's protocol admin runs the crank
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == authority.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch,
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut ,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut ,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, BatchSettle<'info>>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    // same checks as claim_payout -> vault must be settled and market resolved
+    require!(ctx.accounts.vault.is_ready_for_claims(),EscrowVaultError::NotSettled);
+    require!(ctx.accounts.vault.is_claim_window_open(clock.unix_timestamp),EscrowVaultError::ClaimWindowClosed);
+
+    let market = &ctx.accounts.market;
+    require!(market.is_resolved(),EscrowVaultError::NotSettled);
+    let outcome = market.resolution_outcome.ok_or(EscrowVaultError::MarketNotResolved)?;
+
+    // validate batch size
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !remaining.is_empty() && remaining.len() % ACCOUNTS_PER_HOLDER == 0,
+        EscrowVaultError::InvalidBatchAccounts
+    );
+    let holder_count = remaining.len() / ACCOUNTS_PER_HOLDER;
+    require!(holder_count <= MAX_BATCH_SETTLE_SIZE as usize,EscrowVaultError::BatchSizeExceeded);
+
+    msg!("Batch settling {} holders", holder_count);
+    msg!("  Outcome: {:?}", outcome);
+
+    let market_key = ctx.accounts.vault.market.key();
+    let vault_bump = ctx.accounts.vault.bump;
+    let collateral_per_pair = ctx.accounts.vault.collateral_per_pair;
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault_bump]
+    ];
+    let vault_signer= &[&vault_seeds[..]];
+
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let redemption_fee_bps = ctx.accounts.protocol_config.redemption_fee_bps;
+    let mut holders_settled : u8 = 0;
+    let mut total_payout : u64 = 0;
+    let mut total_fees : u64 = 0;
+
+    for holder_accounts in remaining.chunks(ACCOUNTS_PER_HOLDER){
+        let yes_info = &holder_accounts[0];
+        let no_info = &holder_accounts[1];
+        let usdc_info = &holder_accounts[2];
+
+        let user_yes_account = InterfaceAccount::<TokenAccount>::try_from(yes_info)?;
+        let user_no_account = InterfaceAccount::<TokenAccount>::try_from(no_info)?;
+        let user_usdc = InterfaceAccount::<TokenAccount>::try_from(usdc_info)?;
+
+        // all three accounts must belong to the same holder and hold the right tokens
+        let holder = user_yes_account.owner;
+        require!(
+            user_yes_account.mint == ctx.accounts.yes_token_mint.key()
+                && user_no_account.mint == ctx.accounts.no_token_mint.key()
+                && user_usdc.mint == ctx.accounts.usdc_vault.mint,
+            EscrowVaultError::TokenMintMismatch
+        );
+        require!(
+            user_no_account.owner == holder && user_usdc.owner == holder,
+            EscrowVaultError::InvalidRecipientAccount
+        );
+
+        let yes_balance = user_yes_account.amount;
+        let no_balance = user_no_account.amount;
+
+        // nothing to settle for this holder
+        if yes_balance == 0 && no_balance == 0 {
+            msg!("Skipping holder {} (no tokens)", holder);
+            continue;
+        }
+
+        let payout = PayoutCalculation::for_outcome(outcome, market.scalar_bounds, yes_balance, no_balance, collateral_per_pair)?
+            .with_redemption_fee(redemption_fee_bps)?;
+
+        // vault burns as delegate -> holder must have approved the vault for the full balance
+        if payout.yes_token_to_burn > 0 {
+            require!(
+                user_yes_account.delegate == COption::Some(vault_key)
+                    && user_yes_account.delegated_amount >= payout.yes_token_to_burn,
+                EscrowVaultError::MissingBurnDelegation
+            );
+
+            token_interface::burn(
+                CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                    Burn{
+                        mint : ctx.accounts.yes_token_mint.to_account_info(),
+                        from : yes_info.clone(),
+                        authority : vault_info.clone()
+                    },
+                    vault_signer
+                ),
+                payout.yes_token_to_burn)?;
+        }
+
+        if payout.no_token_to_burn > 0 {
+            require!(
+                user_no_account.delegate == COption::Some(vault_key)
+                    && user_no_account.delegated_amount >= payout.no_token_to_burn,
+                EscrowVaultError::MissingBurnDelegation
+            );
+
+            token_interface::burn(
+                CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                    Burn{
+                        mint : ctx.accounts.no_token_mint.to_account_info(),
+                        from : no_info.clone(),
+                        authority : vault_info.clone()
+                    },
+                    vault_signer
+                ),
+                payout.no_token_to_burn)?;
+        }
+
+        if payout.payout_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                    TransferChecked{
+                        from : ctx.accounts.usdc_vault.to_account_info(),
+                        mint : ctx.accounts.usdc_mint.to_account_info(),
+                        to : usdc_info.clone(),
+                        authority : vault_info.clone()
+                    },
+                    vault_signer
+                ),
+                payout.payout_amount, ctx.accounts.usdc_mint.decimals)?;
+        }
+
+        let vault = &mut ctx.accounts.vault;
+        vault.total_locked_collateral = vault.total_locked_collateral.checked_sub(payout.total_released()?).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+        total_payout = total_payout.checked_add(payout.payout_amount).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+        total_fees = total_fees.checked_add(payout.fee_amount).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+        holders_settled = holders_settled.checked_add(1).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+        msg!("Settled holder {}: {} collateral (YES burned {}, NO burned {})",
+            holder,
+            format_amount(payout.payout_amount, vault.collateral_decimals),
+            payout.yes_token_to_burn,
+            payout.no_token_to_burn
+        );
+
+        emit!(PayoutClaimed{
+            vault : vault_key,
+            market: vault.market,
+            user: holder,
+            payout_amount: payout.payout_amount,
+            fee_amount: payout.fee_amount,
+            yes_burned: payout.yes_token_to_burn,
+            no_burned: payout.no_token_to_burn,
+            remaning_collateral: vault.total_locked_collateral,
+            timestamp: clock.unix_timestamp
+        });
+    }
+
+    // fees for the whole batch go to the treasury in one transfer
+    if total_fees > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.usdc_vault.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : vault_info.clone()
+                },
+                vault_signer
+            ),
+            total_fees, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Redemption fees sent to treasury: {} collateral", format_amount(total_fees, ctx.accounts.vault.collateral_decimals));
+    }
+
+    let vault = &ctx.accounts.vault;
+
+    msg!("Batch settlement complete");
+    msg!("  Holders settled: {}", holders_settled);
+    msg!("  Total paid: {} collateral", format_amount(total_payout, vault.collateral_decimals));
+    msg!("Remaining collateral in vault: {} collateral",
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
+
+    emit!(BatchSettled{
+        vault : vault_key,
+        market : vault.market,
+        holders_settled,
+        total_payout,
+        total_fees,
+        remaning_collateral : vault.total_locked_collateral,
+        timestamp : clock.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{AMM_POOL_SEED, PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::OutcomeBought, state::{AmmPool, EscrowVault, OutcomeSide, ProtocolConfig}, utils::{calculate_buy_amount, calculate_fee, collateral_transfer_amount, log_vault_state, verify_vault_invariant}
+};
+
+// buys one side from the pool: usdc is split into pairs exactly like mint_pairs,
+// the opposite leg goes into the pool and the pool pays out of its reserve of the bought side
+#[derive(Accounts)]
+pub struct Buy<'info>{
+    pub buyer : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    #[account(
+        seeds = [AMM_POOL_SEED,vault.market.as_ref()],
+        bump = pool.bump,
+        constraint = pool.vault == vault.key() @ EscrowVaultError::PoolMismatch
+    )]
+    pub pool : Account<'info,AmmPool>,
+
+    #[account(
+        mut,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = yes_reserve.key() == pool.yes_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub yes_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = no_reserve.key() == pool.no_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub no_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.key() == pool.fee_vault @ EscrowVaultError::PoolMismatch
+    )]
+    pub fee_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = buyer_usdc.mint == usdc_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = buyer_usdc.owner == buyer.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub buyer_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    // token account of the side being bought
+    #[account(
+        mut,
+        constraint = buyer_outcome_account.owner == buyer.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub buyer_outcome_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<Buy>,side:OutcomeSide,usdc_in:u64,min_out:u64)->Result<()>{
+    let pool_key = ctx.accounts.pool.key();
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.vault.can_mint(),EscrowVaultError::MintingPaused);
+
+    market_registry::cpi::assert_market_open(CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    ))?;
+
+    // LP fee and protocol mint fee both come out of usdc_in, the rest is split into whole pairs
+    let lp_fee = calculate_fee(usdc_in, ctx.accounts.pool.fee_bps)?;
+    let protocol_fee = ctx.accounts.protocol_config.mint_fee(usdc_in)?;
+    let net_in = usdc_in
+        .checked_sub(lp_fee)
+        .and_then(|amount| amount.checked_sub(protocol_fee))
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+    let pairs = net_in / ctx.accounts.vault.collateral_per_pair;
+    require!(pairs>0,EscrowVaultError::InvalidPairCount);
+
+    let (buy_mint, other_mint, buy_reserve, other_reserve) = match side {
+        OutcomeSide::Yes => (&ctx.accounts.yes_token_mint, &ctx.accounts.no_token_mint, &ctx.accounts.yes_reserve, &ctx.accounts.no_reserve),
+        OutcomeSide::No => (&ctx.accounts.no_token_mint, &ctx.accounts.yes_token_mint, &ctx.accounts.no_reserve, &ctx.accounts.yes_reserve),
+    };
+    require!(
+        ctx.accounts.buyer_outcome_account.mint == buy_mint.key(),
+        EscrowVaultError::TokenMintMismatch
+    );
+
+    let tokens_out = calculate_buy_amount(buy_reserve.amount, other_reserve.amount, pairs)?;
+    require!(tokens_out >= min_out,EscrowVaultError::SlippageExceeded);
+
+    let vault = &mut ctx.accounts.vault;
+    let collateral = vault.lock_pairs(pairs)?;
+
+    let usdc_transfers = [
+        (ctx.accounts.usdc_vault.to_account_info(), collateral_transfer_amount(&ctx.accounts.usdc_mint, collateral)?),
+        (ctx.accounts.fee_vault.to_account_info(), lp_fee),
+        (ctx.accounts.treasury.to_account_info(), protocol_fee),
+    ];
+    // the remainder of net_in below one pair is never taken from the buyer
+    let usdc_spent = usdc_transfers
+        .iter()
+        .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+    msg!("Buying {:?}: {} collateral -> {} tokens", side, format_amount(usdc_spent, vault.collateral_decimals), tokens_out);
+    msg!("  Pairs minted: {}", pairs);
+    msg!("  LP fee: {} collateral / protocol fee: {} collateral", format_amount(lp_fee, vault.collateral_decimals), format_amount(protocol_fee, vault.collateral_decimals));
+
+    for (to, amount) in usdc_transfers {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.buyer_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to,
+                    authority : ctx.accounts.buyer.to_account_info()
+                }
+            ),
+            amount, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump]
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    // opposite leg joins the pool, bought leg goes straight to the buyer
+    for (mint, to) in [
+        (other_mint.to_account_info(), other_reserve.to_account_info()),
+        (buy_mint.to_account_info(), ctx.accounts.buyer_outcome_account.to_account_info()),
+    ] {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                MintTo{
+                    mint,
+                    to,
+                    authority : vault.to_account_info()
+                },
+                vault_signer
+            ),
+            pairs)?;
+    }
+
+    // rest of the output comes out of the pool reserve
+    let from_reserve = tokens_out.checked_sub(pairs).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+    if from_reserve > 0 {
+        let pool_market = ctx.accounts.pool.market;
+        let pool_seeds = &[
+            AMM_POOL_SEED,
+            pool_market.as_ref(),
+            &[ctx.accounts.pool.bump]
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                TransferChecked{
+                    from : buy_reserve.to_account_info(),
+                    mint : buy_mint.to_account_info(),
+                    to : ctx.accounts.buyer_outcome_account.to_account_info(),
+                    authority : ctx.accounts.pool.to_account_info()
+                },
+                &[&pool_seeds[..]]
+            ),
+            from_reserve, buy_mint.decimals)?;
+    }
+
+    verify_vault_invariant(vault)?;
+    log_vault_state(vault, "buy");
+
+    ctx.accounts.yes_reserve.reload()?;
+    ctx.accounts.no_reserve.reload()?;
+
+    emit!(OutcomeBought{
+        pool : pool_key,
+        buyer : ctx.accounts.buyer.key(),
+        side,
+        usdc_in : usdc_spent,
+        lp_fee,
+        tokens_out,
+        yes_reserve : ctx.accounts.yes_reserve.amount,
+        no_reserve : ctx.accounts.no_reserve.amount,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, Burn, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::state::Market;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::CategoricalPayoutClaimed, state::{CategoricalPayout, EscrowVault, ProtocolConfig}, utils::split_outcome_accounts
+};
+
+// winning outcome token pays 1 USDC, invalid markets pay 1/N USDC per token
+// remaining accounts: [outcome mints..., user outcome token accounts...] in vault.outcome_mints order
+#[derive(Accounts)]
+pub struct ClaimCategoricalPayout<'info>{
+    #[account(mut)]
+    pub user : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == usdc_vault.mint,
+        constraint = user_usdc.owner == user.key()
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, ClaimCategoricalPayout<'info>>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.vault.is_ready_for_claims(),EscrowVaultError::NotSettled);
+    require!(ctx.accounts.vault.is_claim_window_open(clock.unix_timestamp),EscrowVaultError::ClaimWindowClosed);
+
+    let market = &ctx.accounts.market;
+    require!(market.is_resolved(),EscrowVaultError::NotSettled);
+    let outcome = market.resolution_outcome.ok_or(EscrowVaultError::MarketNotResolved)?;
+
+    let (mint_infos, account_infos, balances) = split_outcome_accounts(
+        &ctx.accounts.vault,
+        ctx.remaining_accounts,
+        &ctx.accounts.user.key()
+    )?;
+
+    require!(balances.iter().any(|balance| *balance > 0),EscrowVaultError::NoTokensToClaim);
+
+    let payout = CategoricalPayout::for_outcome(outcome, &balances, ctx.accounts.vault.collateral_per_pair)?
+        .with_redemption_fee(ctx.accounts.protocol_config.redemption_fee_bps)?;
+
+    msg!("Categorical payout calculation:");
+    msg!("  Outcome: {:?}", outcome);
+    msg!("  Balances: {:?}", balances);
+    msg!("  Payout: {} collateral", format_amount(payout.payout_amount, ctx.accounts.vault.collateral_decimals));
+    msg!("  Redemption fee: {} collateral", format_amount(payout.fee_amount, ctx.accounts.vault.collateral_decimals));
+
+    // burn every outcome token the user holds (user is the owner so user signs)
+    for ((mint_info, account_info), amount) in mint_infos.iter().zip(account_infos.iter()).zip(payout.tokens_to_burn.iter()) {
+        if *amount == 0 {
+            continue;
+        }
+        token_interface::burn(
+            CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+                Burn{
+                    mint : mint_info.clone(),
+                    from : account_info.clone(),
+                    authority : ctx.accounts.user.to_account_info()
+                }
+            ),
+            *amount)?;
+    }
+
+    let market_key = ctx.accounts.vault.market.key();
+    let vault_bump = ctx.accounts.vault.bump;
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault_bump]
+    ];
+    let vault_signer= &[&vault_seeds[..]];
+
+    if payout.payout_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.usdc_vault.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.user_usdc.to_account_info(),
+                    authority : ctx.accounts.vault.to_account_info()
+                },
+                vault_signer
+            ),
+            payout.payout_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Transferred {} collateral to user", format_amount(payout.payout_amount, ctx.accounts.vault.collateral_decimals));
+    }
+
+    if payout.fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.usdc_vault.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : ctx.accounts.vault.to_account_info()
+                },
+                vault_signer
+            ),
+            payout.fee_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Redemption fee sent to treasury");
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.total_locked_collateral = vault.total_locked_collateral.checked_sub(payout.total_released()?).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    msg!("Payout claimed successfully");
+    msg!("Remaining collateral in vault: {} collateral",
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
+
+    emit!(CategoricalPayoutClaimed{
+        vault : vault_key,
+        market : vault.market,
+        user : ctx.accounts.user.key(),
+        payout_amount : payout.payout_amount,
+        fee_amount : payout.fee_amount,
+        tokens_burned : payout.tokens_to_burn,
+        remaning_collateral : vault.total_locked_collateral,
+        timestamp : clock.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, Burn, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::state::Market;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::LatePayoutClaimed, state::{CategoricalPayout, EscrowVault, PayoutCalculation, ProtocolConfig}, utils::split_outcome_accounts
+};
+
+// holders who missed the claim deadline are paid out of the treasury, co-signed by its owner,
+// capped at what sweep_unclaimed took from this vault
+// remaining accounts: [outcome mints..., user outcome token accounts...] in vault.outcome_mints order
+#[derive(Accounts)]
+pub struct ClaimLate<'info>{
+    pub user : Signer<'info>,
+
+    pub treasury_authority : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch,
+        constraint = treasury.owner == treasury_authority.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == treasury.mint,
+        constraint = user_usdc.owner == user.key()
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, ClaimLate<'info>>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.vault.unclaimed_swept > 0,EscrowVaultError::NothingToSweep);
+
+    let market = &ctx.accounts.market;
+    require!(market.is_resolved(),EscrowVaultError::NotSettled);
+    let outcome = market.resolution_outcome.ok_or(EscrowVaultError::MarketNotResolved)?;
+
+    let (mint_infos, account_infos, balances) = split_outcome_accounts(
+        &ctx.accounts.vault,
+        ctx.remaining_accounts,
+        &ctx.accounts.user.key()
+    )?;
+    require!(balances.iter().any(|balance| *balance > 0),EscrowVaultError::NoTokensToClaim);
+
+    let fee_bps = ctx.accounts.protocol_config.redemption_fee_bps;
+    // (net payout, gross released, tokens to burn per outcome)
+    let (payout_amount, released, tokens_to_burn) = if ctx.accounts.vault.is_categorical() {
+        let payout = CategoricalPayout::for_outcome(outcome, &balances, ctx.accounts.vault.collateral_per_pair)?.with_redemption_fee(fee_bps)?;
+        (payout.payout_amount, payout.total_released()?, payout.tokens_to_burn)
+    } else {
+        let payout = PayoutCalculation::for_outcome(outcome, market.scalar_bounds, balances[0], balances[1], ctx.accounts.vault.collateral_per_pair)?
+            .with_redemption_fee(fee_bps)?;
+        (payout.payout_amount, payout.total_released()?, vec![payout.yes_token_to_burn, payout.no_token_to_burn])
+    };
+
+    let vault = &mut ctx.accounts.vault;
+    vault.unclaimed_swept = vault.unclaimed_swept
+        .checked_sub(released)
+        .ok_or(EscrowVaultError::LateClaimExceedsSwept)?;
+
+    for ((mint_info, account_info), amount) in mint_infos.iter().zip(account_infos.iter()).zip(tokens_to_burn.iter()) {
+        if *amount == 0 {
+            continue;
+        }
+        token_interface::burn(
+            CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+                Burn{
+                    mint : mint_info.clone(),
+                    from : account_info.clone(),
+                    authority : ctx.accounts.user.to_account_info()
+                }
+            ),
+            *amount)?;
+    }
+
+    // the redemption fee simply stays in the treasury
+    if payout_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.treasury.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.user_usdc.to_account_info(),
+                    authority : ctx.accounts.treasury_authority.to_account_info()
+                }
+            ),
+            payout_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Transferred {} collateral from treasury to user", format_amount(payout_amount, vault.collateral_decimals));
+    }
+
+    msg!("Late payout claimed");
+    msg!("Swept collateral still owed: {} collateral", format_amount(vault.unclaimed_swept, vault.collateral_decimals));
+
+    emit!(LatePayoutClaimed{
+        vault : vault_key,
+        market : vault.market,
+        user : ctx.accounts.user.key(),
+        payout_amount,
+        tokens_burned : tokens_to_burn,
+        remaining_swept : vault.unclaimed_swept,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
-use market_registry::{ResultOutcome, state::Market};
-use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::PayoutClaimed, state::{EscrowVault, PayoutCalculation}
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::state::Market;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::PayoutClaimed, state::{EscrowVault, PayoutCalculation, ProtocolConfig}
 };
 
 #[derive(Accounts)]
@@ -12,7 +13,10 @@ pub struct ClaimPayouts<'info>{
     #[account(
         mut,
         seeds = [VAULT_SEED,vault.market.as_ref()],
-        bump = vault.bump
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
     )]
     pub vault : Account<'info,EscrowVault>,
 
@@ -25,43 +29,59 @@ pub struct ClaimPayouts<'info>{
         mut,
         constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
     )]
-    pub usdc_vault : Account<'info,TokenAccount>,
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
 
     #[account(
         mut,
         constraint = user_usdc.mint == usdc_vault.mint,
         constraint = user_usdc.owner == user.key() 
     )]
-    pub user_usdc : Account<'info,TokenAccount>,
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
 
     #[account(
         mut ,
         constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
     )]
-    pub yes_token_mint : Account<'info,Mint>,
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
 
     
     #[account(
         mut ,
         constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
     )]
-    pub no_token_mint : Account<'info,Mint>,
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
 
     #[account(
         mut,
         constraint = user_yes_account.mint == yes_token_mint.key(),  //this say token account actually hold correct token 
         constraint = user_yes_account.owner == user.key()
     )]
-    pub user_yes_account : Account<'info,TokenAccount>,
+    pub user_yes_account : InterfaceAccount<'info,TokenAccount>,
     
     #[account(
         mut,
         constraint = user_no_account.mint == no_token_mint.key(),
         constraint = user_no_account.owner == user.key(),
     )]
-    pub user_no_account : Account<'info,TokenAccount>,
+    pub user_no_account : InterfaceAccount<'info,TokenAccount>,
 
-    pub token_program : Program<'info,Token>
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
 }
 
 pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
@@ -70,6 +90,7 @@ pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
     let clock = Clock::get()?;
     // check first vault is settled -> If vault is settled then only Claim payout is available 
     require!(vault.is_ready_for_claims(),EscrowVaultError::NotSettled);
+    require!(vault.is_claim_window_open(clock.unix_timestamp),EscrowVaultError::ClaimWindowClosed);
 
     // get users token balances 
     let yes_balance = ctx.accounts.user_yes_account.amount;
@@ -87,27 +108,18 @@ pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
     let market = &mut ctx.accounts.market; // as we just want to read the data and we know that our market is resolved 
                                                                     // then we can pass it as a Account 
 
-    require!(market.state == market_registry::state::MarketState::Resolved,EscrowVaultError::NotSettled);
+    require!(market.is_resolved(),EscrowVaultError::NotSettled);
 
     let outcome = market.resolution_outcome.ok_or(EscrowVaultError::MarketNotResolved)?;
 
      // based on outcome calculate payout
-    let payout = match outcome{
-        ResultOutcome::Yes => {
-            PayoutCalculation::for_yes_outcome(yes_balance, no_balance)?
-        }
-        ResultOutcome::No => {
-            PayoutCalculation::for_no_outcome(yes_balance, no_balance)?
-        }
-
-        ResultOutcome::Invalid => {
-            PayoutCalculation::for_invalid_outcome(yes_balance, no_balance)?
-        }
-    };
+    let payout = PayoutCalculation::for_outcome(outcome, market.scalar_bounds, yes_balance, no_balance, vault.collateral_per_pair)?
+        .with_redemption_fee(ctx.accounts.protocol_config.redemption_fee_bps)?;
 
     msg!("Payout calculation:");
     msg!("  Outcome: {:?}", outcome);
-    msg!("  USDC payout: {} USDC", payout.payout_amount as f64 / crate::constants::USDC_UNIT as f64);
+    msg!("  Payout: {} collateral", format_amount(payout.payout_amount, vault.collateral_decimals));
+    msg!("  Redemption fee: {} collateral", format_amount(payout.fee_amount, vault.collateral_decimals));
     msg!("  YES to burn: {}", payout.yes_token_to_burn);
     msg!("  NO to burn: {}", payout.no_token_to_burn);
 
@@ -129,8 +141,8 @@ pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
 
     // Burn Yes No token 
     if payout.yes_token_to_burn > 0 {
-        token::burn(
-            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+        token_interface::burn(
+            CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
                 Burn{
                     mint : ctx.accounts.yes_token_mint.to_account_info(),
                     from : ctx.accounts.user_yes_account.to_account_info(),
@@ -144,8 +156,8 @@ pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
     // Burn No token 
 
     if payout.no_token_to_burn > 0 {
-        token::burn( 
-            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+        token_interface::burn( 
+            CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
                 Burn{
                     mint : ctx.accounts.no_token_mint.to_account_info(),
                     from : ctx.accounts.user_no_account.to_account_info(),
@@ -157,32 +169,51 @@ pub fn handler(ctx:Context<ClaimPayouts>)->Result<()>{
     // Transfer usdc payout 
 
     if payout.payout_amount > 0 {
-        token::transfer(
+        token_interface::transfer_checked(
         CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), 
-        Transfer{
+        TransferChecked{
             from : ctx.accounts.usdc_vault.to_account_info(),
+            mint : ctx.accounts.usdc_mint.to_account_info(),
             to  : ctx.accounts.user_usdc.to_account_info(),
             authority : vault.to_account_info()
         },
          
         vault_signer)
-        , payout.payout_amount)?;
-        msg!("Transferred {} USDC to user", 
-        payout.payout_amount as f64 / crate::constants::USDC_UNIT as f64)
+        , payout.payout_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Transferred {} collateral to user", 
+        format_amount(payout.payout_amount, vault.collateral_decimals))
+    }
+
+    // Transfer protocol fee
+
+    if payout.fee_amount > 0 {
+        token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+        TransferChecked{
+            from : ctx.accounts.usdc_vault.to_account_info(),
+            mint : ctx.accounts.usdc_mint.to_account_info(),
+            to  : ctx.accounts.treasury.to_account_info(),
+            authority : vault.to_account_info()
+        },
+
+        vault_signer)
+        , payout.fee_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Redemption fee sent to treasury");
     }
 
-    //  Update Vault State
+    //  Update Vault State -> gross payout (user + fee) leaves the vault
 
-    vault.total_locked_collateral = vault.total_locked_collateral.checked_sub(payout.payout_amount).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+    vault.total_locked_collateral = vault.total_locked_collateral.checked_sub(payout.total_released()?).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
     msg!("Payout claimed successfully");
-    msg!("Remaining collateral in vault: {} USDC", 
-        vault.total_locked_collateral as f64 / crate::constants::USDC_UNIT as f64);
+    msg!("Remaining collateral in vault: {} collateral", 
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
 
         emit!(PayoutClaimed{
             vault : vault_key,
             market: vault.market,
             user: ctx.accounts.user.key(),
             payout_amount: payout.payout_amount,
+            fee_amount: payout.fee_amount,
             yes_burned: payout.yes_token_to_burn,
             no_burned: payout.no_token_to_burn,
             remaning_collateral: vault.total_locked_collateral,
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, CloseAccount, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::state::Market;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::VaultClosed, state::{EscrowVault, ProtocolConfig}
+};
+
+// permissionless clean-up once every payout is claimed and the market grace period passed
+// leftover usdc dust goes to the treasury, rent of the vault and its usdc account goes back to whoever paid for them
+#[derive(Accounts)]
+pub struct CloseVault<'info>{
+    pub closer : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        close = payer
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    /// CHECK: original payer of the vault accounts, receives the rent
+    #[account(
+        mut,
+        address = vault.payer @ EscrowVaultError::Unauthorized
+    )]
+    pub payer : UncheckedAccount<'info>,
+
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<CloseVault>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    // a swept vault stays open, claim_late needs its unclaimed_swept record to pay late holders
+    require!(
+        vault.is_settled
+            && vault.total_locked_collateral == 0
+            && vault.unclaimed_swept == 0
+            && ctx.accounts.market.is_closable(clock.unix_timestamp),
+        EscrowVaultError::VaultNotClosable
+    );
+
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump]
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    let dust = ctx.accounts.usdc_vault.amount;
+    if dust > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.usdc_vault.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : vault.to_account_info()
+                },
+                vault_signer
+            ),
+            dust, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Swept {} collateral dust to treasury", format_amount(dust, vault.collateral_decimals));
+    }
+
+    token_interface::close_account(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            CloseAccount{
+                account : ctx.accounts.usdc_vault.to_account_info(),
+                destination : ctx.accounts.payer.to_account_info(),
+                authority : vault.to_account_info()
+            },
+            vault_signer
+        ))?;
+
+    msg!("Vault closed: {}", vault_key);
+    msg!("Rent returned to: {}", vault.payer);
+
+    emit!(VaultClosed{
+        vault : vault_key,
+        market : vault.market,
+        payer : vault.payer,
+        dust_swept : dust,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
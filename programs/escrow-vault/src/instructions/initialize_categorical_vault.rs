@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use market_registry::state::Market;
+use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::CategoricalVaultInitialized, state::EscrowVault
+    };
+
+// vault for a market created with initialize_categorical_market (created through CPI from the registry)
+// outcome mints are copied from the market account (already validated by the registry)
+#[derive(Accounts)]
+pub struct InitializeCategoricalVault<'info>{
+    // market creator, pays rent of the vault accounts
+    #[account(mut)]
+    pub payer  : Signer<'info>,
+
+    // signed by the market PDA -> created by initialize_categorical_market only
+    #[account(
+        signer,
+        constraint = market.escrow_vault == vault.key() @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EscrowVault::LEN,
+        seeds = [VAULT_SEED,market.key().as_ref()],
+        bump
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub usdc_vault: InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub system_program : Program<'info,System>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx:Context<InitializeCategoricalVault>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let market = &ctx.accounts.market;
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.market = market.key();
+    vault.mrarket_registery_program = market_registry::ID;
+    vault.usdc_vault = ctx.accounts.usdc_vault.key();
+    vault.yes_token_mint = market.yes_token_mint;
+    vault.no_token_mint = market.no_token_mint;
+    vault.total_locked_collateral = 0;
+    vault.total_yes_minted = 0;
+    vault.total_no_minted = 0;
+    vault.is_settled = false;
+    vault.is_minting_paused = false;
+    vault.payer = ctx.accounts.payer.key();
+    vault.bump = ctx.bumps.vault;
+    vault.settlement_authorities = Vec::new();
+    vault.outcome_mints = market.outcome_mints.clone();
+    vault.total_sets_minted = 0;
+    vault.claim_deadline = 0;
+    vault.unclaimed_swept = 0;
+    vault.usdc_mint = ctx.accounts.usdc_mint.key();
+    // any mint can back a market, collateral per pair follows its decimals
+    vault.collateral_decimals = ctx.accounts.usdc_mint.decimals;
+    vault.collateral_per_pair = EscrowVault::collateral_unit(vault.collateral_decimals)?;
+    vault.token_program = ctx.accounts.token_program.key();
+    vault.outcome_token_program = market.outcome_token_program;
+
+    msg!("Categorical escrow vault initialized");
+    msg!("Vault: {}", vault_key);
+    msg!("Market: {}", vault.market);
+    msg!("USDC Vault: {}", vault.usdc_vault);
+    msg!("Collateral mint: {} ({} decimals)", vault.usdc_mint, vault.collateral_decimals);
+    msg!("Outcomes: {}", vault.outcome_mints.len());
+
+    emit!(CategoricalVaultInitialized{
+        vault : vault_key,
+        market : vault.market,
+        usdc_vault : vault.usdc_vault,
+        outcome_mints : vault.outcome_mints.clone(),
+        collateral_mint : vault.usdc_mint,
+        collateral_decimals : vault.collateral_decimals,
+        timestamp : clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::{constants::{AMM_POOL_SEED, LP_MINT_SEED, MAX_FEE_BPS, OUTCOME_TOKEN_DECIMALS, VAULT_SEED}, error::EscrowVaultError, events::PoolInitialized, state::{AmmPool, EscrowVault}
+};
+
+// creates the constant-product pool of a binary market (one pool per market)
+#[derive(Accounts)]
+pub struct InitializePool<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    // protocol admin of the registry
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        constraint = !vault.is_categorical() @ EscrowVaultError::InvalidOutcome,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AmmPool::LEN,
+        seeds = [AMM_POOL_SEED,vault.market.as_ref()],
+        bump
+    )]
+    pub pool : Account<'info,AmmPool>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [LP_MINT_SEED,pool.key().as_ref()],
+        bump,
+        mint::decimals = OUTCOME_TOKEN_DECIMALS,
+        mint::authority = pool,
+        mint::token_program = token_program
+    )]
+    pub lp_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        constraint = usdc_mint.key() == usdc_vault.mint @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = yes_token_mint,
+        associated_token::authority = pool,
+        associated_token::token_program = outcome_token_program
+    )]
+    pub yes_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = no_token_mint,
+        associated_token::authority = pool,
+        associated_token::token_program = outcome_token_program
+    )]
+    pub no_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub fee_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub system_program : Program<'info,System>,
+    // collateral program (fee vault, LP mint)
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>,
+    pub associated_token_program : Program<'info,AssociatedToken>,
+}
+
+pub fn handler(ctx:Context<InitializePool>,fee_bps:u16)->Result<()>{
+    require!(fee_bps <= MAX_FEE_BPS,EscrowVaultError::FeeTooHigh);
+
+    let pool_key = ctx.accounts.pool.key();
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.market = ctx.accounts.vault.market;
+    pool.vault = ctx.accounts.vault.key();
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.yes_reserve = ctx.accounts.yes_reserve.key();
+    pool.no_reserve = ctx.accounts.no_reserve.key();
+    pool.fee_vault = ctx.accounts.fee_vault.key();
+    pool.fee_bps = fee_bps;
+    pool.bump = ctx.bumps.pool;
+
+    msg!("AMM pool initialized");
+    msg!("Pool: {}", pool_key);
+    msg!("Market: {}", pool.market);
+    msg!("LP fee: {} bps", fee_bps);
+
+    emit!(PoolInitialized{
+        pool : pool_key,
+        market : pool.market,
+        lp_mint : pool.lp_mint,
+        fee_bps,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
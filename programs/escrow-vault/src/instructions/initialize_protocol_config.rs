@@ -0,0 +1,65 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{constants::{DEFAULT_CLAIM_WINDOW, MAX_FEE_BPS, PROTOCOL_CONFIG_SEED}, error::EscrowVaultError, events::ProtocolConfigUpdated, state::ProtocolConfig
+};
+
+#[derive(Accounts)]
+
+pub struct InitializeProtocolConfig<'info> {
+    // must be the program's upgrade authority, otherwise the first caller would own admin and treasury
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowVaultError::Unauthorized
+    )]
+    pub program_data : Account<'info,ProgramData>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    /// CHECK: any wallet, fees go to its associated token account for the vault's collateral mint
+    pub treasury_owner : UncheckedAccount<'info>,
+
+    pub system_program : Program<'info,System>,
+}
+
+pub fn handler(ctx:Context<InitializeProtocolConfig>,mint_fee_bps:u16,redemption_fee_bps:u16)->Result<()>{
+    let config = &mut ctx.accounts.protocol_config;
+    let clock = Clock::get()?;
+
+    require!(
+        mint_fee_bps <= MAX_FEE_BPS && redemption_fee_bps <= MAX_FEE_BPS,
+        EscrowVaultError::FeeTooHigh
+    );
+
+    config.admin = ctx.accounts.admin.key();
+    config.treasury_owner = ctx.accounts.treasury_owner.key();
+    config.mint_fee_bps = mint_fee_bps;
+    config.redemption_fee_bps = redemption_fee_bps;
+    config.bump = ctx.bumps.protocol_config;
+    config.claim_window = DEFAULT_CLAIM_WINDOW;
+
+    msg!("Protocol config initialized");
+    msg!("Treasury owner: {}", config.treasury_owner);
+    msg!("Mint fee: {} bps", mint_fee_bps);
+    msg!("Redemption fee: {} bps", redemption_fee_bps);
+
+    emit!(ProtocolConfigUpdated {
+        admin: config.admin,
+        treasury_owner: config.treasury_owner,
+        mint_fee_bps,
+        redemption_fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,23 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
-use crate::{constants::{OUTCOME_TOKEN_DECIMALS, VAULT_SEED}, events::VaultInitialized, state::EscrowVault
+use crate::{constants::{OUTCOME_TOKEN_DECIMALS, VAULT_SEED}, error::EscrowVaultError, events::VaultInitialized, state::EscrowVault
     };
 
 #[derive(Accounts)]
 
 pub struct InitializeVault<'info>{
-    // for vault initialization 
+    // market creator, pays rent of the vault accounts
     #[account(mut)]
-    pub admin  : Signer<'info>,
+    pub payer  : Signer<'info>,
 
     /// Market account from MarketRegistry (for validation)
-    /// CHECK: We read this to link vault to market
+    /// CHECK: the market PDA signs, so the vault can only be created by initialize_market
+    #[account(
+        signer,
+        owner = market_registry::ID @ EscrowVaultError::MarketRegistryMismatch
+    )]
     pub market : UncheckedAccount<'info>, //it used to derive a pda for Vault 
+
     // each market has excately one vault and that vault is derived using market public key and seeds
     #[account(
         init,
-        payer = admin,
+        payer = payer,
         space = EscrowVault::LEN,
         seeds = [VAULT_SEED,market.key().as_ref()],
         bump 
@@ -26,29 +31,28 @@ pub struct InitializeVault<'info>{
 
     #[account(
         init,
-        payer = admin,
+        payer = payer,
         associated_token::mint = usdc_mint,
-        associated_token::authority = vault   // this is the token account not pda 
+        associated_token::authority = vault,   // this is the token account not pda 
+        associated_token::token_program = token_program
     )]
-    pub usdc_vault: Account<'info, TokenAccount>,
+    pub usdc_vault: InterfaceAccount<'info,TokenAccount>,
     
-    // validate this is a usdc  
-    pub usdc_mint : Account<'info,Mint>,
+    // collateral of this market (USDC, USDT, PYUSD, wrapped SOL...)
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
     #[account(
         constraint = yes_token_mint.decimals == OUTCOME_TOKEN_DECIMALS
     )]
-    pub yes_token_mint : Account<'info,Mint>,
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
     #[account(
-        constraint = no_token_mint.decimals == OUTCOME_TOKEN_DECIMALS
+        constraint = no_token_mint.decimals == OUTCOME_TOKEN_DECIMALS,
+        // both outcome mints live under the same token program
+        constraint = no_token_mint.to_account_info().owner == yes_token_mint.to_account_info().owner @ EscrowVaultError::TokenMintMismatch
     )]
-    pub no_token_mint : Account<'info,Mint>,
-     /// Market registry program (for CPI validation)
-    /// CHECK: We store this for later CPI calls
-    pub market_registery_program : UncheckedAccount<'info>,
-
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
     pub system_program : Program<'info,System>,
 
-    pub token_program : Program<'info,Token>,
+    pub token_program : Interface<'info,TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
@@ -59,7 +63,7 @@ pub fn handler(ctx:Context<InitializeVault>)->Result<()>{
     let clock = Clock::get()?;
 
     vault.market = ctx.accounts.market.key();
-    vault.mrarket_registery_program = ctx.accounts.market_registery_program.key();
+    vault.mrarket_registery_program = market_registry::ID;
     vault.usdc_vault = ctx.accounts.usdc_vault.key();
     vault.yes_token_mint = ctx.accounts.yes_token_mint.key();
     vault.no_token_mint = ctx.accounts.no_token_mint.key();
@@ -68,8 +72,19 @@ pub fn handler(ctx:Context<InitializeVault>)->Result<()>{
     vault.total_no_minted=0;
     vault.is_settled = false;
     vault.is_minting_paused = false;
-    vault.admin = ctx.accounts.admin.key();
+    vault.payer = ctx.accounts.payer.key();
     vault.bump = ctx.bumps.vault;
+    vault.settlement_authorities = Vec::new();
+    vault.outcome_mints = vec![vault.yes_token_mint, vault.no_token_mint];
+    vault.total_sets_minted = 0;
+    vault.claim_deadline = 0;
+    vault.unclaimed_swept = 0;
+    vault.usdc_mint = ctx.accounts.usdc_mint.key();
+    // any mint can back a market, collateral per pair follows its decimals
+    vault.collateral_decimals = ctx.accounts.usdc_mint.decimals;
+    vault.collateral_per_pair = EscrowVault::collateral_unit(vault.collateral_decimals)?;
+    vault.token_program = ctx.accounts.token_program.key();
+    vault.outcome_token_program = *ctx.accounts.yes_token_mint.to_account_info().owner;
     
 
     
@@ -77,8 +92,11 @@ pub fn handler(ctx:Context<InitializeVault>)->Result<()>{
     msg!("Vault: {}", vault_key);
     msg!("Market: {}", vault.market);
     msg!("USDC Vault: {}", vault.usdc_vault);
+    msg!("Collateral mint: {} ({} decimals)", vault.usdc_mint, vault.collateral_decimals);
     msg!("YES Mint: {}", vault.yes_token_mint);
     msg!("NO Mint: {}", vault.no_token_mint);
+    msg!("Collateral token program: {}", vault.token_program);
+    msg!("Outcome token program: {}", vault.outcome_token_program);
 
     emit!(VaultInitialized{
         vault : vault_key,
@@ -86,6 +104,8 @@ pub fn handler(ctx:Context<InitializeVault>)->Result<()>{
         usdc_vault: vault.usdc_vault,
         yes_token_mint: vault.yes_token_mint,
         no_token_mint: vault.no_token_mint,
+        collateral_mint: vault.usdc_mint,
+        collateral_decimals: vault.collateral_decimals,
         timestamp: clock.unix_timestamp,
     });
     Ok(())
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::PairsMerged, state::EscrowVault, utils::{log_vault_state, verify_vault_invariant}
+};
+// burns complete YES + NO pairs and releases the locked collateral before settlement
+#[derive(Accounts)]
+pub struct MergePairs<'info>{
+    #[account(mut)]
+    pub user : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == usdc_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_usdc.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut ,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut ,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = user_yes_account.mint == yes_token_mint.key(),
+        constraint = user_yes_account.owner == user.key()
+    )]
+    pub user_yes_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_no_account.mint == no_token_mint.key(),
+        constraint = user_no_account.owner == user.key()
+    )]
+    pub user_no_account : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<MergePairs>,pairs:u64)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(pairs>0,EscrowVaultError::InvalidPairCount);
+    // once the vault is settled holders go through claim_payout instead
+    require!(!vault.is_settled,EscrowVaultError::AlreadySettled);
+
+    // user must hold both legs of every pair
+    require!(
+        ctx.accounts.user_yes_account.amount >= pairs && ctx.accounts.user_no_account.amount >= pairs,
+        EscrowVaultError::InsufficientOutcomeTokens
+    );
+
+    let collateral_to_release = vault.collateral_for(pairs)?;
+
+    msg!("Merging {} pairs", pairs);
+    msg!("Collateral to release: {} collateral", format_amount(collateral_to_release, vault.collateral_decimals));
+
+    // Burn YES and NO token (user is the owner so user signs)
+    token_interface::burn(
+        CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+            Burn{
+                mint : ctx.accounts.yes_token_mint.to_account_info(),
+                from : ctx.accounts.user_yes_account.to_account_info(),
+                authority : ctx.accounts.user.to_account_info()
+            }
+        ),
+        pairs)?;
+
+    token_interface::burn(
+        CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+            Burn{
+                mint : ctx.accounts.no_token_mint.to_account_info(),
+                from : ctx.accounts.user_no_account.to_account_info(),
+                authority : ctx.accounts.user.to_account_info()
+            }
+        ),
+        pairs)?;
+
+    // release collateral from vault -> vault is pda so it signs with seeds
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump]
+    ];
+    let vault_signer= &[&vault_seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.usdc_vault.to_account_info(),
+                mint : ctx.accounts.usdc_mint.to_account_info(),
+                to : ctx.accounts.user_usdc.to_account_info(),
+                authority : vault.to_account_info()
+            },
+            vault_signer
+        ),
+        collateral_to_release, ctx.accounts.usdc_mint.decimals)?;
+
+    // update the vault state
+    vault.total_locked_collateral = vault.total_locked_collateral
+        .checked_sub(collateral_to_release)
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    vault.total_yes_minted = vault.total_yes_minted
+        .checked_sub(pairs)
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    vault.total_no_minted = vault.total_no_minted
+        .checked_sub(pairs)
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    // Yes == No == Collateral must still hold
+    verify_vault_invariant(vault)?;
+
+    log_vault_state(vault, "merge_pairs");
+
+    emit!(PairsMerged {
+        vault: vault_key,
+        market: vault.market,
+        user: ctx.accounts.user.key(),
+        pairs,
+        collateral_released: collateral_to_release,
+        total_locked: vault.total_locked_collateral,
+        total_yes_minted: vault.total_yes_minted,
+        total_no_minted: vault.total_no_minted,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::CompleteSetsMinted, state::{EscrowVault, ProtocolConfig}, utils::{collateral_transfer_amount, log_vault_state, split_outcome_accounts, verify_collateral_recived, verify_vault_invariant}
+};
+
+// user locks 1 USDC per set and receives one token of every outcome
+// remaining accounts: [outcome mints..., user outcome token accounts...] in vault.outcome_mints order
+#[derive(Accounts)]
+pub struct MintCompleteSets<'info>{
+    pub user : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program: Program<'info, MarketRegistry>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch,
+        constraint = usdc_vault.mint == usdc_mint.key(),
+        constraint = usdc_vault.owner == vault.key()
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == usdc_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_usdc.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount,
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>,
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, MintCompleteSets<'info>>, sets:u64)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    require!(sets>0,EscrowVaultError::InvalidPairCount);
+    require!(ctx.accounts.vault.can_mint(),EscrowVaultError::MintingPaused);
+
+    let (mint_infos, account_infos, _) = split_outcome_accounts(
+        &ctx.accounts.vault,
+        ctx.remaining_accounts,
+        &ctx.accounts.user.key()
+    )?;
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    );
+
+    market_registry::cpi::assert_market_open(cpi_ctx)?;
+
+    let required_collateral = ctx.accounts.vault.collateral_for(sets)?;
+    let fee_amount = ctx.accounts.protocol_config.mint_fee(required_collateral)?;
+
+    msg!("Minting {} complete sets ({} outcomes)", sets, mint_infos.len());
+    msg!("Required collateral: {} collateral", format_amount(required_collateral, ctx.accounts.vault.collateral_decimals));
+    msg!("Mint fee: {} collateral", format_amount(fee_amount, ctx.accounts.vault.collateral_decimals));
+
+    let vault_balance_before = ctx.accounts.usdc_vault.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.user_usdc.to_account_info(),
+                mint : ctx.accounts.usdc_mint.to_account_info(),
+                to : ctx.accounts.usdc_vault.to_account_info(),
+                authority : ctx.accounts.user.to_account_info()
+            }
+        ),
+        collateral_transfer_amount(&ctx.accounts.usdc_mint, required_collateral)?, ctx.accounts.usdc_mint.decimals)?;
+    ctx.accounts.usdc_vault.reload()?;
+
+    verify_collateral_recived(vault_balance_before, ctx.accounts.usdc_vault.amount, required_collateral)?;
+
+    if fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.user_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : ctx.accounts.user.to_account_info()
+                }
+            ),
+            fee_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Mint fee sent to treasury");
+    }
+
+    let market_key = ctx.accounts.vault.market.key();
+    let vault_bump = ctx.accounts.vault.bump;
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault_bump],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    let vault_info = ctx.accounts.vault.to_account_info();
+
+    // one token of every outcome per set
+    for (mint_info, account_info) in mint_infos.iter().zip(account_infos.iter()) {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                MintTo{
+                    mint : mint_info.clone(),
+                    to : account_info.clone(),
+                    authority : vault_info.clone()
+                },
+                vault_signer
+            ),
+            sets)?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.total_locked_collateral = vault.total_locked_collateral
+        .checked_add(required_collateral)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    vault.record_sets_minted(sets)?;
+
+    verify_vault_invariant(vault)?;
+
+    log_vault_state(vault, "mint_complete_sets");
+
+    emit!(CompleteSetsMinted {
+        vault: vault_key,
+        market: vault.market,
+        user: ctx.accounts.user.key(),
+        sets,
+        collateral_locked: required_collateral,
+        fee_amount,
+        total_locked: vault.total_locked_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
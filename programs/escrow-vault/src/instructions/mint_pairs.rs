@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount, TransferChecked};
 use market_registry::cpi::accounts::AssertMarketOpen;
 use market_registry::program::MarketRegistry;
-use crate::{constants::{COLLATERAL_PER_PAIR, USDC_DECIMALS, USDC_UNIT, VAULT_SEED}, error::EscrowVaultError, events::PairsMinted, state::EscrowVault, utils::{log_vault_state, verify_collateral_recived, verify_vault_invariant}
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::PairsMinted, state::{EscrowVault, ProtocolConfig}, utils::{collateral_transfer_amount, log_vault_state, verify_collateral_recived, verify_vault_invariant}
 };
 // locks colateral usdc and mint Yes and No token 
 #[derive(Accounts)]
@@ -13,10 +14,17 @@ pub struct MintPairs <'info>{
     #[account(
         mut,
         seeds = [VAULT_SEED,vault.market.as_ref()],
-        bump = vault.bump
+        bump = vault.bump,
+        constraint = vault.is_settlement_authority(&authority.key()) @ EscrowVaultError::UnauthorizedSettlementAuthority,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
     )]
     pub vault : Account<'info,EscrowVault>,
-        /// CHECK: validated via CPI
+    /// CHECK: bound to the vault, open state validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
     pub market : UncheckedAccount<'info>,
 
     pub market_registry_program: Program<'info, MarketRegistry>,
@@ -26,46 +34,57 @@ pub struct MintPairs <'info>{
         constraint = usdc_vault.mint == usdc_mint.key(),
         constraint = usdc_vault.owner == vault.key()
     )]
-    pub usdc_vault : Account<'info,TokenAccount>,
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
 
-    #[account(
-        constraint = usdc_mint.decimals == USDC_DECIMALS
-    )]
-    pub usdc_mint : Account<'info,Mint>,
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
 
     #[account(
         mut,
         constraint = hot_wallet_usdc.mint == usdc_mint.key(),
     )]
-    pub hot_wallet_usdc : Account<'info,TokenAccount>,
+    pub hot_wallet_usdc : InterfaceAccount<'info,TokenAccount>,
 
     #[account(
         mut ,
         constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch,
     )]
-    pub yes_token_mint : Account<'info,Mint>,
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
 
     #[account(
         mut,
         constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch, 
     )]
-    pub no_token_mint : Account<'info,Mint>,
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
 
 
     #[account(
         mut,
         constraint = yes_recipient.mint == yes_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
     )]
-    pub yes_recipient: Account<'info, TokenAccount>,
+    pub yes_recipient: InterfaceAccount<'info,TokenAccount>,
 
     /// NO token recipient account (user who buys NO)
     #[account(
         mut,
         constraint = no_recipient.mint == no_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
     )]
-    pub no_recipient: Account<'info, TokenAccount>,
+    pub no_recipient: InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub token_program: Interface<'info,TokenInterface>,
+
+    pub outcome_token_program: Interface<'info,TokenInterface>,
 }
 
 pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
@@ -90,31 +109,36 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
 
     // check how much collateral is required ?
 
-    let required_collateral = pairs.checked_mul(COLLATERAL_PER_PAIR).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let required_collateral = vault.collateral_for(pairs)?;
+    // protocol fee is charged on top of the collateral and never enters the vault
+    let fee_amount = ctx.accounts.protocol_config.mint_fee(required_collateral)?;
 
     msg!("Minting {} pairs", pairs);
-    msg!("Required collateral: {} USDC", required_collateral as f64 / USDC_UNIT as f64);
+    msg!("Required collateral: {} collateral", format_amount(required_collateral, vault.collateral_decimals));
+    msg!("Mint fee: {} collateral", format_amount(fee_amount, vault.collateral_decimals));
 
 
     // check vault balance before
     let vault_balance_before = ctx.accounts.usdc_vault.amount;
 
-    msg!("Transferring {} USDC from Hot Wallet to Vault", 
-        required_collateral as f64 / USDC_UNIT as f64);
+    msg!("Transferring {} collateral from Hot Wallet to Vault", 
+        format_amount(required_collateral, vault.collateral_decimals));
 
     // transfer the token 
     // let me explain this 
     // 1---->COLD Wallet (main walet multi sig) hold all 90 % of total asset
     // 2---->HOT Wallet (contain 9% of the fund ) 
-    let transfet_account = Transfer{
+    let transfet_account = TransferChecked{
         from : ctx.accounts.hot_wallet_usdc.to_account_info(),
+        mint : ctx.accounts.usdc_mint.to_account_info(),
         to : ctx.accounts.usdc_vault.to_account_info(),
         authority : ctx.accounts.authority.to_account_info()
     };
 
     let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(),transfet_account );
 
-    token::transfer(cpi_ctx, required_collateral)?;
+    let transfer_amount = collateral_transfer_amount(&ctx.accounts.usdc_mint, required_collateral)?;
+    token_interface::transfer_checked(cpi_ctx, transfer_amount, ctx.accounts.usdc_mint.decimals)?;
     ctx.accounts.usdc_vault.reload()?;
 
     // check the balance after the transfer 
@@ -122,6 +146,20 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
 
     verify_collateral_recived(vault_balance_before, vault_balance_after, required_collateral)?;
 
+    if fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.hot_wallet_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : ctx.accounts.authority.to_account_info()
+                }
+            ),
+            fee_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Mint fee sent to treasury");
+    }
+
     //  mint the pairs to the respective recipients
 
     // vault will mint the yes and not token and vault is Pda so we need to derive From the seeds
@@ -141,10 +179,10 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
         authority : vault.to_account_info()
     };
 
-    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
                                                      mint_account, vault_signer);
 
-    token::mint_to(cpi_ctx, pairs)?;
+    token_interface::mint_to(cpi_ctx, pairs)?;
 
     msg!("Minting {} NO tokens to {}", pairs, ctx.accounts.no_recipient.key());
 
@@ -154,10 +192,10 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
         authority : vault.to_account_info()
     };
 
-    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
                                          no_mint_account, vault_signer);
 
-    token::mint_to(cpi_ctx, pairs)?;
+    token_interface::mint_to(cpi_ctx, pairs)?;
 
     // update the vault state 
     vault.total_locked_collateral = vault.total_locked_collateral
@@ -182,8 +220,8 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
     msg!("New totals:");
     msg!("  YES minted: {}", vault.total_yes_minted);
     msg!("  NO minted: {}", vault.total_no_minted);
-    msg!("  Locked collateral: {} USDC", 
-        vault.total_locked_collateral as f64 / USDC_UNIT as f64);
+    msg!("  Locked collateral: {} collateral", 
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
 
         emit!(PairsMinted {
             vault: vault_key,
@@ -192,6 +230,7 @@ pub fn handler (ctx:Context<MintPairs> , pairs:u64)->Result<()>{
             no_recipient: ctx.accounts.no_recipient.key(),
             pairs,
             collateral_locked: required_collateral,
+            fee_amount,
             total_locked: vault.total_locked_collateral,
             total_yes_minted: vault.total_yes_minted,
             total_no_minted: vault.total_no_minted,
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::PairsMinted, state::{EscrowVault, ProtocolConfig}, utils::{collateral_transfer_amount, log_vault_state, verify_collateral_recived, verify_vault_invariant}
+};
+// self-custodial minting -> user locks own usdc and receives both YES and NO
+#[derive(Accounts)]
+pub struct MintPairsSelf <'info>{
+    pub user : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+        /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program: Program<'info, MarketRegistry>,
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch,
+        constraint = usdc_vault.mint == usdc_mint.key(),
+        constraint = usdc_vault.owner == vault.key()
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == usdc_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_usdc.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount,
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut ,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch,
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch,
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = user_yes_account.mint == yes_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_yes_account.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount,
+    )]
+    pub user_yes_account: InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_no_account.mint == no_token_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_no_account.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount,
+    )]
+    pub user_no_account: InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub token_program: Interface<'info,TokenInterface>,
+
+    pub outcome_token_program: Interface<'info,TokenInterface>,
+}
+
+pub fn handler (ctx:Context<MintPairsSelf> , pairs:u64)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let vault  = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(pairs>0,EscrowVaultError::InvalidPairCount);
+    require!(vault.can_mint(),EscrowVaultError::MintingPaused);
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    );
+
+    market_registry::cpi::assert_market_open(cpi_ctx)?;
+
+    let required_collateral = vault.collateral_for(pairs)?;
+    // protocol fee is charged on top of the collateral and never enters the vault
+    let fee_amount = ctx.accounts.protocol_config.mint_fee(required_collateral)?;
+
+    msg!("Self-minting {} pairs", pairs);
+    msg!("Required collateral: {} collateral", format_amount(required_collateral, vault.collateral_decimals));
+    msg!("Mint fee: {} collateral", format_amount(fee_amount, vault.collateral_decimals));
+
+    let vault_balance_before = ctx.accounts.usdc_vault.amount;
+
+    // user funds the collateral from their own usdc account
+    let transfer_account = TransferChecked{
+        from : ctx.accounts.user_usdc.to_account_info(),
+        mint : ctx.accounts.usdc_mint.to_account_info(),
+        to : ctx.accounts.usdc_vault.to_account_info(),
+        authority : ctx.accounts.user.to_account_info()
+    };
+
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(),transfer_account );
+
+    let transfer_amount = collateral_transfer_amount(&ctx.accounts.usdc_mint, required_collateral)?;
+    token_interface::transfer_checked(cpi_ctx, transfer_amount, ctx.accounts.usdc_mint.decimals)?;
+    ctx.accounts.usdc_vault.reload()?;
+
+    let vault_balance_after = ctx.accounts.usdc_vault.amount;
+
+    verify_collateral_recived(vault_balance_before, vault_balance_after, required_collateral)?;
+
+    if fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.user_usdc.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to : ctx.accounts.treasury.to_account_info(),
+                    authority : ctx.accounts.user.to_account_info()
+                }
+            ),
+            fee_amount, ctx.accounts.usdc_mint.decimals)?;
+        msg!("Mint fee sent to treasury");
+    }
+
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    // both legs go to the user
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+        MintTo{
+            mint : ctx.accounts.yes_token_mint.to_account_info(),
+            to : ctx.accounts.user_yes_account.to_account_info(),
+            authority : vault.to_account_info()
+        },
+        vault_signer);
+
+    token_interface::mint_to(cpi_ctx, pairs)?;
+
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+        MintTo{
+            mint : ctx.accounts.no_token_mint.to_account_info(),
+            to : ctx.accounts.user_no_account.to_account_info(),
+            authority : vault.to_account_info()
+        },
+        vault_signer);
+
+    token_interface::mint_to(cpi_ctx, pairs)?;
+
+    // update the vault state
+    vault.total_locked_collateral = vault.total_locked_collateral
+        .checked_add(required_collateral)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+    vault.total_yes_minted = vault.total_yes_minted
+        .checked_add(pairs)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+    vault.total_no_minted = vault.total_no_minted
+        .checked_add(pairs)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+    verify_vault_invariant(vault)?;
+
+    log_vault_state(vault, "mint_pairs_self");
+
+    emit!(PairsMinted {
+        vault: vault_key,
+        market: vault.market,
+        yes_recipient: ctx.accounts.user_yes_account.key(),
+        no_recipient: ctx.accounts.user_no_account.key(),
+        pairs,
+        collateral_locked: required_collateral,
+        fee_amount,
+        total_locked: vault.total_locked_collateral,
+        total_yes_minted: vault.total_yes_minted,
+        total_no_minted: vault.total_no_minted,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,13 +1,113 @@
 pub mod initialize_vault;
 pub mod mint_pairs;
+pub mod merge_pairs;
+pub mod mint_pairs_self;
 pub mod settle;
 pub mod claim_payout;
 pub mod pause_minting;
 pub mod resume_minting;
+pub mod batch_settle;
+pub mod add_settlement_authority;
+pub mod remove_settlement_authority;
+pub mod initialize_protocol_config;
+pub mod update_protocol_config;
+pub mod initialize_categorical_vault;
+pub mod mint_complete_sets;
+pub mod redeem_complete_sets;
+pub mod claim_categorical_payout;
+pub mod initialize_pool;
+pub mod add_liquidity;
+pub mod remove_liquidity;
+pub mod buy;
+pub mod sell;
+pub mod close_vault;
+pub mod set_claim_window;
+pub mod sweep_unclaimed;
+pub mod claim_late;
 
-pub use initialize_vault::*;
-pub use mint_pairs::*;
-pub use settle::*;
-pub use claim_payout::*;
-pub use pause_minting::*;
-pub use resume_minting::*;
\ No newline at end of file
+pub use initialize_vault::InitializeVault;
+pub use mint_pairs::MintPairs;
+pub use merge_pairs::MergePairs;
+pub use mint_pairs_self::MintPairsSelf;
+pub use settle::Settle;
+pub use claim_payout::ClaimPayouts;
+pub use pause_minting::PauseMinting;
+pub use resume_minting::ResumeMinting;
+pub use batch_settle::{ACCOUNTS_PER_HOLDER, BatchSettle};
+pub use add_settlement_authority::AddSettlementAuthority;
+pub use remove_settlement_authority::RemoveSettlementAuthority;
+pub use initialize_protocol_config::InitializeProtocolConfig;
+pub use update_protocol_config::UpdateProtocolConfig;
+pub use initialize_categorical_vault::InitializeCategoricalVault;
+pub use mint_complete_sets::MintCompleteSets;
+pub use redeem_complete_sets::RedeemCompleteSets;
+pub use claim_categorical_payout::ClaimCategoricalPayout;
+pub use initialize_pool::InitializePool;
+pub use add_liquidity::AddLiquidity;
+pub use remove_liquidity::RemoveLiquidity;
+pub use buy::Buy;
+pub use sell::Sell;
+pub use close_vault::CloseVault;
+pub use set_claim_window::SetClaimWindow;
+pub use sweep_unclaimed::SweepUnclaimed;
+pub use claim_late::ClaimLate;
+
+// client account modules generated by #[derive(Accounts)], the #[program] macro resolves them from the crate root
+pub(crate) use {
+    initialize_vault::__client_accounts_initialize_vault,
+    mint_pairs::__client_accounts_mint_pairs,
+    merge_pairs::__client_accounts_merge_pairs,
+    mint_pairs_self::__client_accounts_mint_pairs_self,
+    settle::__client_accounts_settle,
+    claim_payout::__client_accounts_claim_payouts,
+    pause_minting::__client_accounts_pause_minting,
+    resume_minting::__client_accounts_resume_minting,
+    batch_settle::__client_accounts_batch_settle,
+    add_settlement_authority::__client_accounts_add_settlement_authority,
+    remove_settlement_authority::__client_accounts_remove_settlement_authority,
+    initialize_protocol_config::__client_accounts_initialize_protocol_config,
+    update_protocol_config::__client_accounts_update_protocol_config,
+    initialize_categorical_vault::__client_accounts_initialize_categorical_vault,
+    mint_complete_sets::__client_accounts_mint_complete_sets,
+    redeem_complete_sets::__client_accounts_redeem_complete_sets,
+    claim_categorical_payout::__client_accounts_claim_categorical_payout,
+    initialize_pool::__client_accounts_initialize_pool,
+    add_liquidity::__client_accounts_add_liquidity,
+    remove_liquidity::__client_accounts_remove_liquidity,
+    buy::__client_accounts_buy,
+    sell::__client_accounts_sell,
+    close_vault::__client_accounts_close_vault,
+    set_claim_window::__client_accounts_set_claim_window,
+    sweep_unclaimed::__client_accounts_sweep_unclaimed,
+    claim_late::__client_accounts_claim_late,
+};
+
+#[cfg(feature = "cpi")]
+pub(crate) use {
+    initialize_vault::__cpi_client_accounts_initialize_vault,
+    mint_pairs::__cpi_client_accounts_mint_pairs,
+    merge_pairs::__cpi_client_accounts_merge_pairs,
+    mint_pairs_self::__cpi_client_accounts_mint_pairs_self,
+    settle::__cpi_client_accounts_settle,
+    claim_payout::__cpi_client_accounts_claim_payouts,
+    pause_minting::__cpi_client_accounts_pause_minting,
+    resume_minting::__cpi_client_accounts_resume_minting,
+    batch_settle::__cpi_client_accounts_batch_settle,
+    add_settlement_authority::__cpi_client_accounts_add_settlement_authority,
+    remove_settlement_authority::__cpi_client_accounts_remove_settlement_authority,
+    initialize_protocol_config::__cpi_client_accounts_initialize_protocol_config,
+    update_protocol_config::__cpi_client_accounts_update_protocol_config,
+    initialize_categorical_vault::__cpi_client_accounts_initialize_categorical_vault,
+    mint_complete_sets::__cpi_client_accounts_mint_complete_sets,
+    redeem_complete_sets::__cpi_client_accounts_redeem_complete_sets,
+    claim_categorical_payout::__cpi_client_accounts_claim_categorical_payout,
+    initialize_pool::__cpi_client_accounts_initialize_pool,
+    add_liquidity::__cpi_client_accounts_add_liquidity,
+    remove_liquidity::__cpi_client_accounts_remove_liquidity,
+    buy::__cpi_client_accounts_buy,
+    sell::__cpi_client_accounts_sell,
+    close_vault::__cpi_client_accounts_close_vault,
+    set_claim_window::__cpi_client_accounts_set_claim_window,
+    sweep_unclaimed::__cpi_client_accounts_sweep_unclaimed,
+    claim_late::__cpi_client_accounts_claim_late,
+};
\ No newline at end of file
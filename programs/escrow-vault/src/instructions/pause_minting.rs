@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
 
-use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::{MintingPaused, VaultInitialized}, state::EscrowVault
+use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::MintingPaused, state::EscrowVault
 };
 
 #[derive(Accounts)]
@@ -9,11 +10,19 @@ pub struct PauseMinting<'info> {
     #[account(mut)]
     pub admin : Signer<'info>,
 
+    // admin paths follow the registry's current protocol admin, so an admin handover moves every vault with it
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
     #[account(
         mut,
         seeds = [VAULT_SEED,vault.market.as_ref()],
         bump = vault.bump,
-        constraint = vault.admin == admin.key() @ EscrowVaultError::Unauthorized,
     )]
     pub vault : Account<'info,EscrowVault>
 
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, Burn, TokenInterface, TokenAccount, TransferChecked};
+use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::CompleteSetsRedeemed, state::EscrowVault, utils::{log_vault_state, split_outcome_accounts, verify_vault_invariant}
+};
+
+// burns one token of every outcome per set and releases the collateral before settlement
+// remaining accounts: [outcome mints..., user outcome token accounts...] in vault.outcome_mints order
+#[derive(Accounts)]
+pub struct RedeemCompleteSets<'info>{
+    #[account(mut)]
+    pub user : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == usdc_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = user_usdc.owner == user.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub user_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, RedeemCompleteSets<'info>>,sets:u64)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    require!(sets>0,EscrowVaultError::InvalidPairCount);
+    // once the vault is settled holders go through claim_categorical_payout instead
+    require!(!ctx.accounts.vault.is_settled,EscrowVaultError::AlreadySettled);
+
+    let (mint_infos, account_infos, balances) = split_outcome_accounts(
+        &ctx.accounts.vault,
+        ctx.remaining_accounts,
+        &ctx.accounts.user.key()
+    )?;
+
+    // user must hold every leg of every set
+    require!(
+        balances.iter().all(|balance| *balance >= sets),
+        EscrowVaultError::InsufficientOutcomeTokens
+    );
+
+    let collateral_to_release = ctx.accounts.vault.collateral_for(sets)?;
+
+    msg!("Redeeming {} complete sets", sets);
+    msg!("Collateral to release: {} collateral", format_amount(collateral_to_release, ctx.accounts.vault.collateral_decimals));
+
+    for (mint_info, account_info) in mint_infos.iter().zip(account_infos.iter()) {
+        token_interface::burn(
+            CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+                Burn{
+                    mint : mint_info.clone(),
+                    from : account_info.clone(),
+                    authority : ctx.accounts.user.to_account_info()
+                }
+            ),
+            sets)?;
+    }
+
+    let market_key = ctx.accounts.vault.market.key();
+    let vault_bump = ctx.accounts.vault.bump;
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault_bump]
+    ];
+    let vault_signer= &[&vault_seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.usdc_vault.to_account_info(),
+                mint : ctx.accounts.usdc_mint.to_account_info(),
+                to : ctx.accounts.user_usdc.to_account_info(),
+                authority : ctx.accounts.vault.to_account_info()
+            },
+            vault_signer
+        ),
+        collateral_to_release, ctx.accounts.usdc_mint.decimals)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.total_locked_collateral = vault.total_locked_collateral
+        .checked_sub(collateral_to_release)
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+    vault.record_sets_redeemed(sets)?;
+
+    verify_vault_invariant(vault)?;
+
+    log_vault_state(vault, "redeem_complete_sets");
+
+    emit!(CompleteSetsRedeemed {
+        vault: vault_key,
+        market: vault.market,
+        user: ctx.accounts.user.key(),
+        sets,
+        collateral_released: collateral_to_release,
+        total_locked: vault.total_locked_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
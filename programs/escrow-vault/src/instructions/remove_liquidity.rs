@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::{constants::AMM_POOL_SEED, error::EscrowVaultError, events::LiquidityRemoved, state::{AmmPool, EscrowVault}, utils::calculate_pool_share
+};
+
+// burns LP shares for a pro-rata slice of the YES/NO reserves and accrued usdc fees
+// not gated on the market state -> after settlement the returned tokens go through claim_payout
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info>{
+    pub provider : Signer<'info>,
+
+    #[account(
+        seeds = [AMM_POOL_SEED,pool.market.as_ref()],
+        bump = pool.bump,
+        has_one = vault @ EscrowVaultError::PoolMismatch
+    )]
+    pub pool : Account<'info,AmmPool>,
+
+    #[account(
+        has_one = yes_token_mint @ EscrowVaultError::TokenMintMismatch,
+        has_one = no_token_mint @ EscrowVaultError::TokenMintMismatch,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Box<Account<'info,EscrowVault>>,
+
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == pool.lp_mint @ EscrowVaultError::PoolMismatch
+    )]
+    pub lp_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = yes_reserve.key() == pool.yes_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub yes_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = no_reserve.key() == pool.no_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub no_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.key() == pool.fee_vault @ EscrowVaultError::PoolMismatch
+    )]
+    pub fee_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_lp_account.mint == lp_mint.key() @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_lp_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_lp_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_yes_account.mint == yes_reserve.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_yes_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_yes_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_no_account.mint == no_reserve.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_no_account.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_no_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = provider_usdc.mint == fee_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = provider_usdc.owner == provider.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub provider_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<RemoveLiquidity>,lp_shares:u64)->Result<()>{
+    let pool_key = ctx.accounts.pool.key();
+    let clock = Clock::get()?;
+
+    require!(
+        lp_shares>0 && ctx.accounts.provider_lp_account.amount >= lp_shares,
+        EscrowVaultError::InvalidLpAmount
+    );
+
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let yes_out = calculate_pool_share(ctx.accounts.yes_reserve.amount, lp_shares, lp_supply)?;
+    let no_out = calculate_pool_share(ctx.accounts.no_reserve.amount, lp_shares, lp_supply)?;
+    let usdc_out = calculate_pool_share(ctx.accounts.fee_vault.amount, lp_shares, lp_supply)?;
+
+    msg!("Removing liquidity: {} LP shares of {}", lp_shares, lp_supply);
+    msg!("  YES out: {} / NO out: {}", yes_out, no_out);
+    msg!("  Fees out: {} collateral", format_amount(usdc_out, ctx.accounts.vault.collateral_decimals));
+
+    token_interface::burn(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            Burn{
+                mint : ctx.accounts.lp_mint.to_account_info(),
+                from : ctx.accounts.provider_lp_account.to_account_info(),
+                authority : ctx.accounts.provider.to_account_info()
+            }
+        ),
+        lp_shares)?;
+
+    let pool_market = ctx.accounts.pool.market;
+    let pool_seeds = &[
+        AMM_POOL_SEED,
+        pool_market.as_ref(),
+        &[ctx.accounts.pool.bump]
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+
+    // (program, mint, from, to, amount) -> reserves are outcome tokens, fees are collateral
+    let payouts = [
+        (&ctx.accounts.outcome_token_program, &ctx.accounts.yes_token_mint, ctx.accounts.yes_reserve.to_account_info(), ctx.accounts.provider_yes_account.to_account_info(), yes_out),
+        (&ctx.accounts.outcome_token_program, &ctx.accounts.no_token_mint, ctx.accounts.no_reserve.to_account_info(), ctx.accounts.provider_no_account.to_account_info(), no_out),
+        (&ctx.accounts.token_program, &ctx.accounts.usdc_mint, ctx.accounts.fee_vault.to_account_info(), ctx.accounts.provider_usdc.to_account_info(), usdc_out),
+    ];
+    for (program, mint, from, to, amount) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(program.to_account_info(),
+                TransferChecked{
+                    from,
+                    mint : mint.to_account_info(),
+                    to,
+                    authority : ctx.accounts.pool.to_account_info()
+                },
+                pool_signer
+            ),
+            amount, mint.decimals)?;
+    }
+
+    emit!(LiquidityRemoved{
+        pool : pool_key,
+        provider : ctx.accounts.provider.key(),
+        lp_shares,
+        yes_out,
+        no_out,
+        usdc_out,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
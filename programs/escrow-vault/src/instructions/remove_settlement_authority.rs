@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+
+use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::SettlementAuthorityRemoved, state::EscrowVault
+};
+
+#[derive(Accounts)]
+
+pub struct RemoveSettlementAuthority<'info> {
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    // protocol admin of the registry
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault : Account<'info,EscrowVault>
+}
+
+pub fn handler(ctx:Context<RemoveSettlementAuthority>,authority:Pubkey)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    let index = vault.settlement_authorities
+        .iter()
+        .position(|a| *a == authority)
+        .ok_or(EscrowVaultError::SettlementAuthorityNotFound)?;
+
+    vault.settlement_authorities.remove(index);
+
+    msg!("Settlement authority removed for vault: {}", vault_key);
+    msg!("Authority: {}", authority);
+
+    emit!(SettlementAuthorityRemoved {
+        vault: vault_key,
+        authority,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
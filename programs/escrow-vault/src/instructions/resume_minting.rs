@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
 
 use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::{ MintingResumed }, state::EscrowVault
 };
@@ -9,11 +10,19 @@ pub struct ResumeMinting<'info> {
     #[account(mut)]
     pub admin : Signer<'info>,
 
+    // protocol admin of the registry
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ EscrowVaultError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
     #[account(
         mut,
         seeds = [VAULT_SEED,vault.market.as_ref()],
         bump = vault.bump,
-        constraint = vault.admin == admin.key() @ EscrowVaultError::Unauthorized,
     )]
     pub vault : Account<'info,EscrowVault>
 
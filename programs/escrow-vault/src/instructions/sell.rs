@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount, TransferChecked};
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{AMM_POOL_SEED, VAULT_SEED}, error::EscrowVaultError, events::OutcomeSold, state::{AmmPool, EscrowVault, OutcomeSide}, utils::{calculate_fee, calculate_sell_pairs, log_vault_state, verify_vault_invariant}
+};
+
+// sells one side into the pool: the pool merges complete pairs exactly like merge_pairs
+// and the released collateral (minus the LP fee) goes to the seller
+#[derive(Accounts)]
+pub struct Sell<'info>{
+    pub seller : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch,
+        has_one = outcome_token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == vault.market @ EscrowVaultError::MarketRegistryMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    #[account(
+        seeds = [AMM_POOL_SEED,vault.market.as_ref()],
+        bump = pool.bump,
+        constraint = pool.vault == vault.key() @ EscrowVaultError::PoolMismatch
+    )]
+    pub pool : Account<'info,AmmPool>,
+
+    #[account(
+        mut,
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub yes_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = no_token_mint.key() == vault.no_token_mint @ EscrowVaultError::TokenMintMismatch
+    )]
+    pub no_token_mint : InterfaceAccount<'info,Mint>,
+
+    #[account(
+        mut,
+        constraint = yes_reserve.key() == pool.yes_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub yes_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = no_reserve.key() == pool.no_reserve @ EscrowVaultError::PoolMismatch
+    )]
+    pub no_reserve : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.key() == pool.fee_vault @ EscrowVaultError::PoolMismatch
+    )]
+    pub fee_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = seller_usdc.mint == usdc_vault.mint @ EscrowVaultError::InvalidRecipientAccount,
+        constraint = seller_usdc.owner == seller.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub seller_usdc : InterfaceAccount<'info,TokenAccount>,
+
+    // token account of the side being sold
+    #[account(
+        mut,
+        constraint = seller_outcome_account.owner == seller.key() @ EscrowVaultError::InvalidRecipientAccount
+    )]
+    pub seller_outcome_account : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub outcome_token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<Sell>,side:OutcomeSide,tokens_in:u64,min_usdc_out:u64)->Result<()>{
+    let pool_key = ctx.accounts.pool.key();
+    let clock = Clock::get()?;
+
+    require!(tokens_in>0,EscrowVaultError::InvalidPairCount);
+    require!(!ctx.accounts.vault.is_settled,EscrowVaultError::AlreadySettled);
+
+    market_registry::cpi::assert_market_open(CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    ))?;
+
+    let (sell_mint, sell_reserve, other_reserve) = match side {
+        OutcomeSide::Yes => (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_reserve, &ctx.accounts.no_reserve),
+        OutcomeSide::No => (&ctx.accounts.no_token_mint, &ctx.accounts.no_reserve, &ctx.accounts.yes_reserve),
+    };
+    require!(
+        ctx.accounts.seller_outcome_account.mint == sell_mint.key(),
+        EscrowVaultError::TokenMintMismatch
+    );
+    require!(
+        ctx.accounts.seller_outcome_account.amount >= tokens_in,
+        EscrowVaultError::InsufficientOutcomeTokens
+    );
+
+    let pairs = calculate_sell_pairs(sell_reserve.amount, other_reserve.amount, tokens_in)?;
+    require!(pairs>0,EscrowVaultError::InsufficientLiquidity);
+
+    let vault = &mut ctx.accounts.vault;
+    let collateral = vault.release_pairs(pairs)?;
+    let lp_fee = calculate_fee(collateral, ctx.accounts.pool.fee_bps)?;
+    let usdc_out = collateral.checked_sub(lp_fee).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+    require!(usdc_out >= min_usdc_out,EscrowVaultError::SlippageExceeded);
+
+    msg!("Selling {:?}: {} tokens -> {} collateral", side, tokens_in, format_amount(usdc_out, vault.collateral_decimals));
+    msg!("  Pairs merged: {}", pairs);
+    msg!("  LP fee: {} collateral", format_amount(lp_fee, vault.collateral_decimals));
+
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.outcome_token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.seller_outcome_account.to_account_info(),
+                mint : sell_mint.to_account_info(),
+                to : sell_reserve.to_account_info(),
+                authority : ctx.accounts.seller.to_account_info()
+            }
+        ),
+        tokens_in, sell_mint.decimals)?;
+
+    // pool merges complete pairs out of its reserves
+    let pool_market = ctx.accounts.pool.market;
+    let pool_seeds = &[
+        AMM_POOL_SEED,
+        pool_market.as_ref(),
+        &[ctx.accounts.pool.bump]
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+    for (mint, reserve) in [
+        (ctx.accounts.yes_token_mint.to_account_info(), ctx.accounts.yes_reserve.to_account_info()),
+        (ctx.accounts.no_token_mint.to_account_info(), ctx.accounts.no_reserve.to_account_info()),
+    ] {
+        token_interface::burn(
+            CpiContext::new_with_signer(ctx.accounts.outcome_token_program.to_account_info(),
+                Burn{
+                    mint,
+                    from : reserve,
+                    authority : ctx.accounts.pool.to_account_info()
+                },
+                pool_signer
+            ),
+            pairs)?;
+    }
+
+    let market_key = vault.market.key();
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault.bump]
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    for (to, amount) in [
+        (ctx.accounts.seller_usdc.to_account_info(), usdc_out),
+        (ctx.accounts.fee_vault.to_account_info(), lp_fee),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                TransferChecked{
+                    from : ctx.accounts.usdc_vault.to_account_info(),
+                    mint : ctx.accounts.usdc_mint.to_account_info(),
+                    to,
+                    authority : vault.to_account_info()
+                },
+                vault_signer
+            ),
+            amount, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    verify_vault_invariant(vault)?;
+    log_vault_state(vault, "sell");
+
+    ctx.accounts.yes_reserve.reload()?;
+    ctx.accounts.no_reserve.reload()?;
+
+    emit!(OutcomeSold{
+        pool : pool_key,
+        seller : ctx.accounts.seller.key(),
+        side,
+        tokens_in,
+        lp_fee,
+        usdc_out,
+        yes_reserve : ctx.accounts.yes_reserve.amount,
+        no_reserve : ctx.accounts.no_reserve.amount,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
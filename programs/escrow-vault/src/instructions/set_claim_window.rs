@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::{MIN_CLAIM_WINDOW, PROTOCOL_CONFIG_SEED}, error::EscrowVaultError, events::ClaimWindowUpdated, state::ProtocolConfig
+};
+
+// only affects vaults settled after the change, existing deadlines stay as they are
+#[derive(Accounts)]
+
+pub struct SetClaimWindow<'info> {
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ EscrowVaultError::Unauthorized,
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+}
+
+pub fn handler(ctx:Context<SetClaimWindow>,claim_window:i64)->Result<()>{
+    let config = &mut ctx.accounts.protocol_config;
+    let clock = Clock::get()?;
+
+    require!(claim_window >= MIN_CLAIM_WINDOW,EscrowVaultError::InvalidClaimWindow);
+
+    config.claim_window = claim_window;
+
+    msg!("Claim window set to {} seconds", claim_window);
+
+    emit!(ClaimWindowUpdated {
+        admin: config.admin,
+        claim_window,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
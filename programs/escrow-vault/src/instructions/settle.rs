@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
+use market_registry::format_amount;
 use market_registry::cpi::accounts::AssertMarketResolved;
 use market_registry::program::MarketRegistry;
 
-use crate::{constants::VAULT_SEED, error::EscrowVaultError, events::SettlementInitialized, state::EscrowVault
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::SettlementInitialized, state::{EscrowVault, ProtocolConfig}
 };
 
 #[derive(Accounts)]
@@ -23,6 +24,12 @@ pub struct Settle<'info>{
     )]
     pub market: UncheckedAccount<'info>,
 
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
     pub market_registry_program: Program<'info, MarketRegistry>,
 }
 
@@ -45,18 +52,23 @@ pub fn handler(ctx:Context<Settle>)->Result<()>{
 
 
     vault.is_settled = true;
+    vault.claim_deadline = clock.unix_timestamp
+        .checked_add(ctx.accounts.protocol_config.claim_window)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
 
     msg!("Settlement initiated for vault: {}", vault_key);
-    msg!("Total collateral available: {} USDC", 
-        vault.total_locked_collateral as f64 / crate::constants::USDC_UNIT as f64);
+    msg!("Total collateral available: {} collateral", 
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
     msg!("Total YES tokens: {}", vault.total_yes_minted);
     msg!("Total NO tokens: {}", vault.total_no_minted);
+    msg!("Claims open until: {}", vault.claim_deadline);
 
     // Emit event
     emit!(SettlementInitialized {
         vault: vault_key,
         market: vault.market,
         total_collateral: vault.total_locked_collateral,
+        claim_deadline: vault.claim_deadline,
         timestamp: clock.unix_timestamp,
     });
     Ok(())
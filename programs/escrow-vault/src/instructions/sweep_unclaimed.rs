@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::{constants::{PROTOCOL_CONFIG_SEED, VAULT_SEED}, error::EscrowVaultError, events::UnclaimedCollateralSwept, state::{EscrowVault, ProtocolConfig}
+};
+
+// after the claim deadline the protocol admin moves whatever is left in the vault to the treasury
+// remaining accounts: outcome mints in vault.outcome_mints order (binary = [yes, no]) to report residual supply
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info>{
+    pub admin : Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ EscrowVaultError::Unauthorized,
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        has_one = usdc_mint @ EscrowVaultError::CollateralMintMismatch,
+        has_one = token_program @ EscrowVaultError::TokenProgramMismatch
+    )]
+    pub vault : Account<'info,EscrowVault>,
+
+    #[account(
+        mut,
+        constraint = usdc_vault.key() == vault.usdc_vault @ EscrowVaultError::UsdcVaultMismatch
+    )]
+    pub usdc_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury_for(&vault) @ EscrowVaultError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    pub usdc_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, SweepUnclaimed<'info>>)->Result<()>{
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.vault.is_ready_for_claims(),EscrowVaultError::NotSettled);
+    require!(!ctx.accounts.vault.is_claim_window_open(clock.unix_timestamp),EscrowVaultError::ClaimWindowOpen);
+
+    let amount = ctx.accounts.usdc_vault.amount;
+    require!(amount > 0,EscrowVaultError::NothingToSweep);
+
+    let outcome_mints = &ctx.accounts.vault.outcome_mints;
+    require!(
+        ctx.remaining_accounts.len() == outcome_mints.len(),
+        EscrowVaultError::InvalidOutcomeAccounts
+    );
+    let mut residual_supply = Vec::with_capacity(outcome_mints.len());
+    for (mint_info, expected_mint) in ctx.remaining_accounts.iter().zip(outcome_mints.iter()) {
+        require!(mint_info.key() == *expected_mint,EscrowVaultError::TokenMintMismatch);
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        residual_supply.push(mint.supply);
+    }
+
+    let market_key = ctx.accounts.vault.market.key();
+    let vault_bump = ctx.accounts.vault.bump;
+    let vault_seeds = &[
+        VAULT_SEED,
+        market_key.as_ref(),
+        &[vault_bump]
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            TransferChecked{
+                from : ctx.accounts.usdc_vault.to_account_info(),
+                mint : ctx.accounts.usdc_mint.to_account_info(),
+                to : ctx.accounts.treasury.to_account_info(),
+                authority : ctx.accounts.vault.to_account_info()
+            },
+            vault_signer
+        ),
+        amount, ctx.accounts.usdc_mint.decimals)?;
+
+    // the locked collateral is still owed to holders, claim_late pays it back out of the treasury
+    let vault = &mut ctx.accounts.vault;
+    vault.unclaimed_swept = vault.unclaimed_swept
+        .checked_add(vault.total_locked_collateral)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    vault.total_locked_collateral = 0;
+
+    msg!("Swept {} unclaimed collateral to treasury", format_amount(amount, vault.collateral_decimals));
+    msg!("Residual outcome supply: {:?}", residual_supply);
+
+    emit!(UnclaimedCollateralSwept{
+        vault : vault_key,
+        market : vault.market,
+        treasury : ctx.accounts.treasury.key(),
+        amount,
+        residual_supply,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
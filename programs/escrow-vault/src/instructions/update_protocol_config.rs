@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::{MAX_FEE_BPS, PROTOCOL_CONFIG_SEED}, error::EscrowVaultError, events::ProtocolConfigUpdated, state::ProtocolConfig
+};
+
+#[derive(Accounts)]
+
+pub struct UpdateProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ EscrowVaultError::Unauthorized,
+    )]
+    pub protocol_config : Account<'info,ProtocolConfig>,
+
+    /// CHECK: new (or unchanged) treasury owner, any wallet
+    pub treasury_owner : UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx:Context<UpdateProtocolConfig>,mint_fee_bps:u16,redemption_fee_bps:u16)->Result<()>{
+    let config = &mut ctx.accounts.protocol_config;
+    let clock = Clock::get()?;
+
+    require!(
+        mint_fee_bps <= MAX_FEE_BPS && redemption_fee_bps <= MAX_FEE_BPS,
+        EscrowVaultError::FeeTooHigh
+    );
+
+    config.treasury_owner = ctx.accounts.treasury_owner.key();
+    config.mint_fee_bps = mint_fee_bps;
+    config.redemption_fee_bps = redemption_fee_bps;
+
+    msg!("Protocol config updated");
+    msg!("Treasury owner: {}", config.treasury_owner);
+    msg!("Mint fee: {} bps", mint_fee_bps);
+    msg!("Redemption fee: {} bps", redemption_fee_bps);
+
+    emit!(ProtocolConfigUpdated {
+        admin: config.admin,
+        treasury_owner: config.treasury_owner,
+        mint_fee_bps,
+        redemption_fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
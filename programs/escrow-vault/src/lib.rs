@@ -21,6 +21,18 @@ declare_id!("7naTChgbgNS8cUoMbfKoYA8qomHJVUf5oeVjv9iSmiyf");
 pub mod escrow_vault {
     use super::*;
 
+    pub fn initialize_protocol_config(ctx:Context<InitializeProtocolConfig>,mint_fee_bps:u16,redemption_fee_bps:u16)->Result<()>{
+        instructions::initialize_protocol_config::handler(ctx, mint_fee_bps, redemption_fee_bps)
+    }
+
+    pub fn update_protocol_config(ctx:Context<UpdateProtocolConfig>,mint_fee_bps:u16,redemption_fee_bps:u16)->Result<()>{
+        instructions::update_protocol_config::handler(ctx, mint_fee_bps, redemption_fee_bps)
+    }
+
+    pub fn set_claim_window(ctx:Context<SetClaimWindow>,claim_window:i64)->Result<()>{
+        instructions::set_claim_window::handler(ctx, claim_window)
+    }
+
     pub fn initialize_vault(ctx:Context<InitializeVault>)->Result<()>{
         instructions::initialize_vault::handler(ctx)
     }
@@ -29,6 +41,14 @@ pub mod escrow_vault {
         instructions::mint_pairs::handler(ctx, pairs)
     }
 
+    pub fn mint_pairs_self(ctx:Context<MintPairsSelf>,pairs:u64)->Result<()>{
+        instructions::mint_pairs_self::handler(ctx, pairs)
+    }
+
+    pub fn merge_pairs(ctx:Context<MergePairs>,pairs:u64)->Result<()>{
+        instructions::merge_pairs::handler(ctx, pairs)
+    }
+
     pub fn settle(ctx:Context<Settle>)->Result<()>{
         instructions::settle::handler(ctx)
     }
@@ -38,6 +58,46 @@ pub mod escrow_vault {
         instructions::claim_payout::handler(ctx)
     }
 
+    pub fn batch_settle<'info>(ctx:Context<'_, '_, 'info, 'info, BatchSettle<'info>>)->Result<()>{
+        instructions::batch_settle::handler(ctx)
+    }
+
+    pub fn initialize_categorical_vault(ctx:Context<InitializeCategoricalVault>)->Result<()>{
+        instructions::initialize_categorical_vault::handler(ctx)
+    }
+
+    pub fn mint_complete_sets<'info>(ctx:Context<'_, '_, 'info, 'info, MintCompleteSets<'info>>,sets:u64)->Result<()>{
+        instructions::mint_complete_sets::handler(ctx, sets)
+    }
+
+    pub fn redeem_complete_sets<'info>(ctx:Context<'_, '_, 'info, 'info, RedeemCompleteSets<'info>>,sets:u64)->Result<()>{
+        instructions::redeem_complete_sets::handler(ctx, sets)
+    }
+
+    pub fn claim_categorical_payout<'info>(ctx:Context<'_, '_, 'info, 'info, ClaimCategoricalPayout<'info>>)->Result<()>{
+        instructions::claim_categorical_payout::handler(ctx)
+    }
+
+    pub fn initialize_pool(ctx:Context<InitializePool>,fee_bps:u16)->Result<()>{
+        instructions::initialize_pool::handler(ctx, fee_bps)
+    }
+
+    pub fn add_liquidity(ctx:Context<AddLiquidity>,pairs:u64)->Result<()>{
+        instructions::add_liquidity::handler(ctx, pairs)
+    }
+
+    pub fn remove_liquidity(ctx:Context<RemoveLiquidity>,lp_shares:u64)->Result<()>{
+        instructions::remove_liquidity::handler(ctx, lp_shares)
+    }
+
+    pub fn buy(ctx:Context<Buy>,side:OutcomeSide,usdc_in:u64,min_out:u64)->Result<()>{
+        instructions::buy::handler(ctx, side, usdc_in, min_out)
+    }
+
+    pub fn sell(ctx:Context<Sell>,side:OutcomeSide,tokens_in:u64,min_usdc_out:u64)->Result<()>{
+        instructions::sell::handler(ctx, side, tokens_in, min_usdc_out)
+    }
+
     pub fn pause_minting(ctx:Context<PauseMinting>)->Result<()>{
         instructions::pause_minting::handler(ctx)
     }
@@ -45,5 +105,25 @@ pub mod escrow_vault {
     pub fn resume_minting(ctx:Context<ResumeMinting>)->Result<()>{
         instructions::resume_minting::handler(ctx)
     }
+
+    pub fn add_settlement_authority(ctx:Context<AddSettlementAuthority>,authority:Pubkey)->Result<()>{
+        instructions::add_settlement_authority::handler(ctx, authority)
+    }
+
+    pub fn remove_settlement_authority(ctx:Context<RemoveSettlementAuthority>,authority:Pubkey)->Result<()>{
+        instructions::remove_settlement_authority::handler(ctx, authority)
+    }
+
+    pub fn close_vault(ctx:Context<CloseVault>)->Result<()>{
+        instructions::close_vault::handler(ctx)
+    }
+
+    pub fn sweep_unclaimed<'info>(ctx:Context<'_, '_, 'info, 'info, SweepUnclaimed<'info>>)->Result<()>{
+        instructions::sweep_unclaimed::handler(ctx)
+    }
+
+    pub fn claim_late<'info>(ctx:Context<'_, '_, 'info, 'info, ClaimLate<'info>>)->Result<()>{
+        instructions::claim_late::handler(ctx)
+    }
     
 }
\ No newline at end of file
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use market_registry::{ResultOutcome, ScalarBounds};
 
 use crate::{
-    constants::{COLLATERAL_PER_PAIR, USDC_UNIT},
+    constants::{MAX_COLLATERAL_DECIMALS, MAX_SETTLEMENT_AUTHORITIES, MAX_VAULT_OUTCOMES},
     error::EscrowVaultError,
+    utils::calculate_fee,
 };
 
 #[account]
@@ -27,10 +31,30 @@ pub struct EscrowVault {
     pub is_settled: bool,
 
     pub is_minting_paused: bool,
-    // admin authority for emergency stop
-    pub admin: Pubkey,
 
     pub bump: u8,
+    // off-chain settlement workers allowed to mint from the hot wallet
+    pub settlement_authorities: Vec<Pubkey>,
+    // one mint per outcome, binary vaults store [yes_token_mint, no_token_mint]
+    pub outcome_mints: Vec<Pubkey>,
+    // complete sets outstanding (categorical vaults only, binary vaults use total_yes/no_minted)
+    pub total_sets_minted: u64,
+    // set by settle, claims close after this and the rest can be swept
+    pub claim_deadline: i64,
+    // collateral moved to the treasury by sweep_unclaimed, still owed to late holders
+    pub unclaimed_swept: u64,
+    // collateral mint and its token program (SPL Token or Token-2022)
+    pub usdc_mint: Pubkey,
+
+    pub token_program: Pubkey,
+    // program owning the outcome mints, may differ from the collateral program
+    pub outcome_token_program: Pubkey,
+    // decimals of usdc_mint, one pair locks 10^collateral_decimals base units
+    pub collateral_decimals: u8,
+
+    pub collateral_per_pair: u64,
+    // funded the vault and its collateral account, receives the rent on close_vault
+    pub payer: Pubkey,
 }
 
 impl EscrowVault {
@@ -45,17 +69,39 @@ impl EscrowVault {
         8 + // total_no_minted
         1 + // is_settled
         1 + // is_minting_paused
-        32 + // admin
-        1; // bump
-           // Yes == No == Collateral
+        1 + // bump
+        4 + (MAX_SETTLEMENT_AUTHORITIES * 32) + // settlement_authorities
+        4 + (MAX_VAULT_OUTCOMES * 32) + // outcome_mints
+        8 + // total_sets_minted
+        8 + // claim_deadline
+        8 + // unclaimed_swept
+        32 + // usdc_mint
+        32 + // token_program
+        32 + // outcome_token_program
+        1 + // collateral_decimals
+        8 + // collateral_per_pair
+        32; // payer
+           // Yes == No == Collateral (categorical: Sets == Collateral)
     pub fn verify_invariant(&self) -> Result<()> {
+        if self.is_categorical() {
+            let expected_collateral = self
+                .total_sets_minted
+                .checked_mul(self.collateral_per_pair)
+                .ok_or(EscrowVaultError::InvariantViolationCollateralMismatch)?;
+
+            require!(
+                self.total_locked_collateral == expected_collateral,
+                EscrowVaultError::InvariantViolationCollateralMismatch
+            );
+            return Ok(());
+        }
         require!(
             self.total_yes_minted == self.total_no_minted,
             EscrowVaultError::InvariantViolationCollateralMismatch
         );
         let expected_collateral = self
             .total_yes_minted
-            .checked_mul(COLLATERAL_PER_PAIR)
+            .checked_mul(self.collateral_per_pair)
             .ok_or(EscrowVaultError::InvariantViolationCollateralMismatch)?;
 
         require!(
@@ -65,18 +111,186 @@ impl EscrowVault {
         Ok(())
     }
 
+    // one whole token of the collateral mint, e.g. 1 USDC (6 decimals) or 1 SOL (9 decimals)
+    pub fn collateral_unit(decimals: u8) -> Result<u64> {
+        require!(
+            decimals <= MAX_COLLATERAL_DECIMALS,
+            EscrowVaultError::UnsupportedCollateralDecimals
+        );
+        Ok(10u64.pow(decimals as u32))
+    }
+
+    pub fn collateral_for(&self, pairs: u64) -> Result<u64> {
+        Ok(pairs
+            .checked_mul(self.collateral_per_pair)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?)
+    }
+
+    pub fn is_categorical(&self) -> bool {
+        self.outcome_mints.len() > 2
+    }
+
+    // a complete set is one token of every outcome backed by collateral_per_pair
+    pub fn record_sets_minted(&mut self, sets: u64) -> Result<()> {
+        if self.is_categorical() {
+            self.total_sets_minted = self
+                .total_sets_minted
+                .checked_add(sets)
+                .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+        } else {
+            self.total_yes_minted = self
+                .total_yes_minted
+                .checked_add(sets)
+                .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+            self.total_no_minted = self
+                .total_no_minted
+                .checked_add(sets)
+                .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn record_sets_redeemed(&mut self, sets: u64) -> Result<()> {
+        if self.is_categorical() {
+            self.total_sets_minted = self
+                .total_sets_minted
+                .checked_sub(sets)
+                .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        } else {
+            self.total_yes_minted = self
+                .total_yes_minted
+                .checked_sub(sets)
+                .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+            self.total_no_minted = self
+                .total_no_minted
+                .checked_sub(sets)
+                .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        }
+        Ok(())
+    }
+
+    // same accounting as mint_pairs -> returns the collateral that must enter the usdc vault
+    pub fn lock_pairs(&mut self, pairs: u64) -> Result<u64> {
+        let collateral = self.collateral_for(pairs)?;
+        self.total_locked_collateral = self
+            .total_locked_collateral
+            .checked_add(collateral)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+        self.record_sets_minted(pairs)?;
+        Ok(collateral)
+    }
+
+    // same accounting as merge_pairs -> returns the collateral released from the usdc vault
+    pub fn release_pairs(&mut self, pairs: u64) -> Result<u64> {
+        let collateral = self.collateral_for(pairs)?;
+        self.total_locked_collateral = self
+            .total_locked_collateral
+            .checked_sub(collateral)
+            .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        self.record_sets_redeemed(pairs)?;
+        Ok(collateral)
+    }
+
     pub fn can_mint(&self) -> bool {
         !self.is_minting_paused && !self.is_settled
     }
+    pub fn is_settlement_authority(&self, authority: &Pubkey) -> bool {
+        self.settlement_authorities.contains(authority)
+    }
     //   if market is settled then ready for claim
     pub fn is_ready_for_claims(&self) -> bool {
         self.is_settled
     }
+
+    pub fn is_claim_window_open(&self, current_timestamp: i64) -> bool {
+        self.is_settled && current_timestamp < self.claim_deadline
+    }
+}
+// protocol wide fee settings (single PDA)
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    // wallet receiving the protocol fees, in its associated token account for each collateral mint
+    pub treasury_owner: Pubkey,
+    // fee charged on collateral locked in mint_pairs
+    pub mint_fee_bps: u16,
+    // fee charged on payouts in claim_payout
+    pub redemption_fee_bps: u16,
+
+    pub bump: u8,
+    // seconds holders get to claim after settle
+    pub claim_window: i64,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        32 + // treasury_owner
+        2 + // mint_fee_bps
+        2 + // redemption_fee_bps
+        1 + // bump
+        8; // claim_window
+
+    pub fn mint_fee(&self, collateral: u64) -> Result<u64> {
+        calculate_fee(collateral, self.mint_fee_bps)
+    }
+
+    // fee account for the vault's collateral, markets in different mints pay into different accounts
+    pub fn treasury_for(&self, vault: &EscrowVault) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            &self.treasury_owner,
+            &vault.usdc_mint,
+            &vault.token_program,
+        )
+    }
+}
+
+// constant-product pool for the YES/NO tokens of one binary market
+// reserves live in pool-owned token accounts, LP fees accrue in usdc
+#[account]
+pub struct AmmPool {
+    pub market: Pubkey,
+
+    pub vault: Pubkey,
+
+    pub lp_mint: Pubkey,
+
+    pub yes_reserve: Pubkey,
+
+    pub no_reserve: Pubkey,
+    // usdc account holding accrued trading fees (paid out on remove_liquidity)
+    pub fee_vault: Pubkey,
+    // fee charged on every buy/sell, kept by liquidity providers
+    pub fee_bps: u16,
+
+    pub bump: u8,
 }
+
+impl AmmPool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        32 + // vault
+        32 + // lp_mint
+        32 + // yes_reserve
+        32 + // no_reserve
+        32 + // fee_vault
+        2 + // fee_bps
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OutcomeSide {
+    Yes,
+    No,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
 
 pub struct PayoutCalculation {
+    // net amount paid to the holder
     pub payout_amount: u64,
+    // protocol fee taken out of the gross payout
+    pub fee_amount: u64,
 
     pub yes_token_to_burn: u64,
 
@@ -84,41 +298,117 @@ pub struct PayoutCalculation {
 }
 
 impl PayoutCalculation {
-    pub fn for_yes_outcome(yes_balance: u64, no_balance: u64) -> Result<Self> {
-        // let total yes balance (yes token) * 1 USDC
+    pub fn for_outcome(
+        outcome: ResultOutcome,
+        scalar_bounds: Option<ScalarBounds>,
+        yes_balance: u64,
+        no_balance: u64,
+        collateral_per_pair: u64,
+    ) -> Result<Self> {
+        match outcome {
+            ResultOutcome::Scalar(price) => {
+                let bounds = scalar_bounds.ok_or(EscrowVaultError::InvalidOutcome)?;
+                let long_payout = bounds.long_payout(price, collateral_per_pair)?;
+                Self::for_scalar_outcome(yes_balance, no_balance, long_payout, collateral_per_pair)
+            }
+            ResultOutcome::Yes => Self::for_yes_outcome(yes_balance, no_balance, collateral_per_pair),
+            ResultOutcome::No => Self::for_no_outcome(yes_balance, no_balance, collateral_per_pair),
+            ResultOutcome::Invalid => Self::for_invalid_outcome(yes_balance, no_balance, collateral_per_pair),
+            // categorical outcomes are claimed through claim_categorical_payout
+            ResultOutcome::Index(_) => err!(EscrowVaultError::InvalidOutcome),
+        }
+    }
+
+    // splits the gross payout into holder payout + protocol fee
+    pub fn with_redemption_fee(self, fee_bps: u16) -> Result<Self> {
+        let fee_amount = calculate_fee(self.payout_amount, fee_bps)?;
+        let payout_amount = self
+            .payout_amount
+            .checked_sub(fee_amount)
+            .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        Ok(Self {
+            payout_amount,
+            fee_amount,
+            ..self
+        })
+    }
+
+    // total collateral that leaves the vault
+    pub fn total_released(&self) -> Result<u64> {
+        Ok(self
+            .payout_amount
+            .checked_add(self.fee_amount)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?)
+    }
+
+    pub fn for_yes_outcome(yes_balance: u64, no_balance: u64, collateral_per_pair: u64) -> Result<Self> {
+        // let total yes balance (yes token) * 1 unit of collateral
         let payout_amount = yes_balance
-            .checked_mul(USDC_UNIT)
+            .checked_mul(collateral_per_pair)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?;
         // after payout we need to burn that token
         Ok(Self {
             payout_amount,
+            fee_amount: 0,
             yes_token_to_burn: yes_balance,
             no_token_to_burn: no_balance,
         })
     }
 
-    pub fn for_no_outcome(yes_balance: u64, no_balance: u64) -> Result<Self> {
-        // let total No balance (no token) * 1 USDC
+    pub fn for_no_outcome(yes_balance: u64, no_balance: u64, collateral_per_pair: u64) -> Result<Self> {
+        // let total No balance (no token) * 1 unit of collateral
         let payout_amount = no_balance
-            .checked_mul(USDC_UNIT)
+            .checked_mul(collateral_per_pair)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?;
         // after payout we need to burn that token
         Ok(Self {
             payout_amount,
+            fee_amount: 0,
             yes_token_to_burn: yes_balance,
             no_token_to_burn: no_balance,
         })
     }
 
-    pub fn for_invalid_outcome(yes_balance: u64, no_balance: u64) -> Result<Self> {
+    // fractional payout -> every LONG (YES) token gets long_payout, every SHORT (NO) token the rest of a pair
+    pub fn for_scalar_outcome(
+        yes_balance: u64,
+        no_balance: u64,
+        long_payout: u64,
+        collateral_per_pair: u64,
+    ) -> Result<Self> {
+        let short_payout = collateral_per_pair
+            .checked_sub(long_payout)
+            .ok_or(EscrowVaultError::PayoutCalculationFailed)?;
+
+        let long_total = yes_balance
+            .checked_mul(long_payout)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+        let short_total = no_balance
+            .checked_mul(short_payout)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+        let total_payout = long_total
+            .checked_add(short_total)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+        Ok(Self {
+            payout_amount: total_payout,
+            fee_amount: 0,
+            yes_token_to_burn: yes_balance,
+            no_token_to_burn: no_balance,
+        })
+    }
+
+    pub fn for_invalid_outcome(yes_balance: u64, no_balance: u64, collateral_per_pair: u64) -> Result<Self> {
         let yes_payout = yes_balance
-            .checked_mul(USDC_UNIT)
+            .checked_mul(collateral_per_pair)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?
             .checked_div(2)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?;
 
         let no_payout = no_balance
-            .checked_mul(USDC_UNIT)
+            .checked_mul(collateral_per_pair)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?
             .checked_div(2)
             .ok_or(EscrowVaultError::ArithmeticOverflow)?;
@@ -129,8 +419,84 @@ impl PayoutCalculation {
 
         Ok(Self {
             payout_amount: total_payout,
+            fee_amount: 0,
             yes_token_to_burn: yes_balance,
             no_token_to_burn: no_balance,
         })
     }
 }
+
+// payout for a holder of N outcome tokens (categorical markets, binary is the N=2 case)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CategoricalPayout {
+    // net amount paid to the holder
+    pub payout_amount: u64,
+    // protocol fee taken out of the gross payout
+    pub fee_amount: u64,
+    // balance to burn per outcome, in outcome order
+    pub tokens_to_burn: Vec<u64>,
+}
+
+impl CategoricalPayout {
+    pub fn for_outcome(outcome: ResultOutcome, balances: &[u64], collateral_per_pair: u64) -> Result<Self> {
+        // scalar markets are binary vaults and settle through claim_payout
+        require!(
+            !matches!(outcome, ResultOutcome::Scalar(_)),
+            EscrowVaultError::InvalidOutcome
+        );
+
+        let payout_amount = match outcome.outcome_index() {
+            // winning outcome token -> 1 unit of collateral, every other token -> 0
+            Some(index) => {
+                let winning_balance = balances
+                    .get(index as usize)
+                    .ok_or(EscrowVaultError::InvalidOutcome)?;
+                winning_balance
+                    .checked_mul(collateral_per_pair)
+                    .ok_or(EscrowVaultError::ArithmeticOverflow)?
+            }
+            // invalid market -> every token is worth 1/N of a unit
+            None => {
+                let outcome_count = balances.len() as u64;
+                let mut total_payout: u64 = 0;
+                for balance in balances {
+                    let share = balance
+                        .checked_mul(collateral_per_pair)
+                        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+                        .checked_div(outcome_count)
+                        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+                    total_payout = total_payout
+                        .checked_add(share)
+                        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+                }
+                total_payout
+            }
+        };
+
+        Ok(Self {
+            payout_amount,
+            fee_amount: 0,
+            tokens_to_burn: balances.to_vec(),
+        })
+    }
+
+    pub fn with_redemption_fee(self, fee_bps: u16) -> Result<Self> {
+        let fee_amount = calculate_fee(self.payout_amount, fee_bps)?;
+        let payout_amount = self
+            .payout_amount
+            .checked_sub(fee_amount)
+            .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+        Ok(Self {
+            payout_amount,
+            fee_amount,
+            ..self
+        })
+    }
+
+    pub fn total_released(&self) -> Result<u64> {
+        Ok(self
+            .payout_amount
+            .checked_add(self.fee_amount)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?)
+    }
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EscrowVaultError;
+
+// fixed product market maker math (YES reserve * NO reserve stays constant on trades)
+// all amounts are raw outcome tokens, 1 token = 1 pair = vault.collateral_per_pair collateral
+
+// buying: `pairs` are minted into both reserves, then the bought side is drained back to k
+pub fn calculate_buy_amount(buy_reserve : u64, other_reserve : u64, pairs : u64) -> Result<u64>{
+    require!(buy_reserve > 0 && other_reserve > 0,EscrowVaultError::InsufficientLiquidity);
+
+    let invariant = (buy_reserve as u128)
+        .checked_mul(other_reserve as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let new_other = (other_reserve as u128)
+        .checked_add(pairs as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    // round the remaining reserve up so the pool never loses on rounding
+    let new_buy = invariant.div_ceil(new_other);
+
+    let tokens_out = (buy_reserve as u128)
+        .checked_add(pairs as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        .checked_sub(new_buy)
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    u64::try_from(tokens_out).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+}
+
+// selling: tokens_in join the sold side, then the pool merges x pairs so that
+// (sell_reserve + tokens_in - x) * (other_reserve - x) >= k
+pub fn calculate_sell_pairs(sell_reserve : u64, other_reserve : u64, tokens_in : u64) -> Result<u64>{
+    require!(sell_reserve > 0 && other_reserve > 0,EscrowVaultError::InsufficientLiquidity);
+
+    let invariant = (sell_reserve as u128)
+        .checked_mul(other_reserve as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let a = (sell_reserve as u128)
+        .checked_add(tokens_in as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let b = other_reserve as u128;
+
+    // smaller root of x^2 - (a + b)x + (ab - k) = 0
+    let sum = a.checked_add(b).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let product = a.checked_mul(b).ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    let discriminant = sum
+        .checked_mul(sum)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        .checked_sub(
+            product
+                .checked_sub(invariant)
+                .ok_or(EscrowVaultError::ArithmeticUnderflow)?
+                .checked_mul(4)
+                .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        )
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?;
+
+    let mut pairs = sum
+        .checked_sub(integer_sqrt_ceil(discriminant))
+        .ok_or(EscrowVaultError::ArithmeticUnderflow)?
+        / 2;
+
+    // integer rounding must never leave the pool below k
+    while pairs > 0 && (a - pairs) * (b - pairs) < invariant {
+        pairs -= 1;
+    }
+
+    u64::try_from(pairs).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+}
+
+// depositing `pairs` complete sets -> the heavier side keeps everything, the lighter side
+// keeps a proportional amount and the surplus is returned to the provider (price unchanged)
+// returns (lp_shares, yes_kept, no_kept)
+pub fn calculate_liquidity_deposit(
+    yes_reserve : u64,
+    no_reserve : u64,
+    lp_supply : u64,
+    pairs : u64
+) -> Result<(u64, u64, u64)>{
+    if lp_supply == 0 {
+        return Ok((pairs, pairs, pairs));
+    }
+
+    let heavier = yes_reserve.max(no_reserve);
+    require!(heavier > 0,EscrowVaultError::InsufficientLiquidity);
+
+    let scale = |value : u64| -> Result<u64> {
+        let scaled = (pairs as u128)
+            .checked_mul(value as u128)
+            .ok_or(EscrowVaultError::ArithmeticOverflow)?
+            / heavier as u128;
+        u64::try_from(scaled).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+    };
+
+    Ok((scale(lp_supply)?, scale(yes_reserve)?, scale(no_reserve)?))
+}
+
+// usdc a provider pays into the fee vault with `shares` new LP shares, rounded up -> the fees
+// already earned stay with the providers that earned them instead of being diluted
+pub fn calculate_fee_buy_in(fee_balance : u64, shares : u64, lp_supply : u64) -> Result<u64>{
+    if lp_supply == 0 {
+        return Ok(0);
+    }
+    let buy_in = (fee_balance as u128)
+        .checked_mul(shares as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        .div_ceil(lp_supply as u128);
+    u64::try_from(buy_in).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+}
+
+// pro-rata share of a pool balance for `shares` out of `lp_supply`
+pub fn calculate_pool_share(balance : u64, shares : u64, lp_supply : u64) -> Result<u64>{
+    require!(lp_supply > 0,EscrowVaultError::InsufficientLiquidity);
+    let share = (balance as u128)
+        .checked_mul(shares as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        / lp_supply as u128;
+    u64::try_from(share).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+}
+
+fn integer_sqrt_ceil(value : u128) -> u128{
+    if value < 2 {
+        return value;
+    }
+    // newton iteration for floor(sqrt(value))
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    if x * x == value { x } else { x + 1 }
+}
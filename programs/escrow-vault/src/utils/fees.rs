@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BPS_DENOMINATOR, error::EscrowVaultError};
+
+// fee = amount * bps / 10_000 (rounded down in favour of the user)
+pub fn calculate_fee(amount : u64, fee_bps : u16) -> Result<u64>{
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+
+    u64::try_from(fee).map_err(|_| EscrowVaultError::ArithmeticOverflow.into())
+}
@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{
+    self, Mint,
+    spl_token_2022::{extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions}, state::Mint as MintState},
+};
 
 use crate::{error::EscrowVaultError, state::EscrowVault};
 
@@ -15,21 +20,48 @@ pub fn log_vault_state(vault:&EscrowVault, operation : &str){
     msg!("=== Vault State After: {} ===", operation);
     msg!("Total YES minted: {}", vault.total_yes_minted);
     msg!("Total NO minted: {}", vault.total_no_minted);
-    msg!("Total locked collateral: {} USDC", 
-        vault.total_locked_collateral as f64 / 1_000_000.0);
+    if vault.is_categorical() {
+        msg!("Total complete sets minted: {}", vault.total_sets_minted);
+    }
+    msg!("Total locked collateral: {}", 
+        format_amount(vault.total_locked_collateral, vault.collateral_decimals));
     msg!("Is settled: {}", vault.is_settled);
     msg!("Is minting paused: {}", vault.is_minting_paused);
     msg!("=================================");
 }
 
 
+// amount the sender has to move so that `net_amount` lands in the vault
+// (Token-2022 collateral with a transfer fee withholds the fee from the receiver)
+pub fn collateral_transfer_amount(usdc_mint : &InterfaceAccount<Mint>, net_amount : u64) -> Result<u64>{
+    let mint_info = usdc_mint.to_account_info();
+    if *mint_info.owner != token_interface::ID {
+        return Ok(net_amount);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let Ok(fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(net_amount);
+    };
+
+    let transfer_fee = fee_config
+        .calculate_inverse_epoch_fee(Clock::get()?.epoch, net_amount)
+        .ok_or(EscrowVaultError::ArithmeticOverflow)?;
+    msg!("Collateral transfer fee: {}", transfer_fee);
+
+    net_amount.checked_add(transfer_fee).ok_or(EscrowVaultError::ArithmeticOverflow.into())
+}
+
+// the vault balance is the source of truth -> with a transfer fee the sender grossed up the
+// transfer (collateral_transfer_amount), rounding of the fee may leave the vault a little over
 pub fn verify_collateral_recived(
     vault_balance_before:u64,
     vault_balance_after : u64,
     expected_increase:u64
 )->Result<()>{
     let actual_increase = vault_balance_after.checked_sub(vault_balance_before).ok_or(EscrowVaultError::ArithmeticUnderflow)?;
-    require!(actual_increase == expected_increase,EscrowVaultError::CollateralNotReceived);
+    require!(actual_increase >= expected_increase,EscrowVaultError::CollateralNotReceived);
     require!(
         vault_balance_after >= vault_balance_before,
         EscrowVaultError::InvalidVaultState
@@ -1,3 +1,9 @@
 pub mod invariants;
+pub mod fees;
+pub mod outcomes;
+pub mod amm;
 
-pub use invariants::*;
\ No newline at end of file
+pub use invariants::*;
+pub use fees::*;
+pub use outcomes::*;
+pub use amm::*;
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{error::EscrowVaultError, state::EscrowVault};
+
+// remaining accounts for complete-set instructions:
+// [outcome_mint_0 .. outcome_mint_n, user_outcome_account_0 .. user_outcome_account_n]
+// both halves follow the order of vault.outcome_mints
+pub fn split_outcome_accounts<'info>(
+    vault : &EscrowVault,
+    remaining : &'info [AccountInfo<'info>],
+    owner : &Pubkey
+)->Result<(&'info [AccountInfo<'info>], &'info [AccountInfo<'info>], Vec<u64>)>{
+    let outcome_count = vault.outcome_mints.len();
+    require!(
+        outcome_count > 0 && remaining.len() == outcome_count * 2,
+        EscrowVaultError::InvalidOutcomeAccounts
+    );
+
+    let (mint_infos, account_infos) = remaining.split_at(outcome_count);
+    let mut balances = Vec::with_capacity(outcome_count);
+
+    for (index, expected_mint) in vault.outcome_mints.iter().enumerate() {
+        require!(mint_infos[index].key() == *expected_mint,EscrowVaultError::TokenMintMismatch);
+
+        let user_account = InterfaceAccount::<TokenAccount>::try_from(&account_infos[index])?;
+        require!(user_account.mint == *expected_mint,EscrowVaultError::TokenMintMismatch);
+        require!(user_account.owner == *owner,EscrowVaultError::InvalidRecipientAccount);
+
+        balances.push(user_account.amount);
+    }
+
+    Ok((mint_infos, account_infos, balances))
+}
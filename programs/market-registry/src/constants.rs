@@ -1,6 +1,6 @@
 
-
-// use anchor_lang::prelude::*;
+use anchor_lang::prelude::*;
+use std::ops::RangeInclusive;
 
 /// Seed for Market PDA derivation
 pub const MARKET_SEED: &[u8] = b"market";
@@ -8,6 +8,36 @@ pub const MARKET_SEED: &[u8] = b"market";
 /// Seed for Admin authority PDA
 pub const ADMIN_SEED: &[u8] = b"admin";
 
+/// Seed for per-creator stats PDA (creation rate limit)
+pub const CREATOR_SEED: &[u8] = b"creator";
+
+/// Seed for the token account holding every posted creator bond
+pub const CREATOR_BOND_VAULT_SEED: &[u8] = b"creator_bond_vault";
+
+/// Escrow vault program, its vault is created by CPI during market creation
+pub const ESCROW_VAULT_PROGRAM_ID: Pubkey = pubkey!("7naTChgbgNS8cUoMbfKoYA8qomHJVUf5oeVjv9iSmiyf");
+
+/// Resolution adapter program, its proposal is created by CPI during market creation
+pub const RESOLUTION_ADAPTER_PROGRAM_ID: Pubkey = pubkey!("8BPYHejifTVauQkWqKhMzA3uJxDr3U8mKH9NSfbtaAa5");
+
+/// Seed of the escrow vault PDA (escrow vault program)
+pub const ESCROW_VAULT_SEED: &[u8] = b"escrow_vault";
+
+/// Seed of the resolution proposal PDA (resolution adapter program)
+pub const RESOLUTION_SEED: &[u8] = b"resolution";
+
+/// Seed of the oracle bond vault PDA (resolution adapter program)
+pub const RESOLUTION_BOND_VAULT_SEED: &[u8] = b"bond_vault";
+
+/// Anchor discriminator of escrow_vault::initialize_vault (sha256("global:initialize_vault")[..8])
+pub const INITIALIZE_VAULT_DISCRIMINATOR: [u8; 8] = [48, 191, 163, 44, 71, 129, 63, 164];
+
+/// Anchor discriminator of escrow_vault::initialize_categorical_vault
+pub const INITIALIZE_CATEGORICAL_VAULT_DISCRIMINATOR: [u8; 8] = [30, 130, 238, 142, 137, 67, 92, 59];
+
+/// Anchor discriminator of resolution_adapter::initialize_resolution
+pub const INITIALIZE_RESOLUTION_DISCRIMINATOR: [u8; 8] = [114, 234, 73, 65, 38, 194, 180, 145];
+
 /// Maximum length for market question
 pub const MAX_QUESTION_LENGTH: usize = 200;
 /// Maximum length for market description
@@ -19,6 +49,21 @@ pub const MAX_CATEGORY_LENGTH: usize = 50;
 /// Maximum length for resolution source identifier
 pub const MAX_RESOLUTION_SOURCE_LENGTH: usize = 100;
 
+/// Maximum number of outcomes for a categorical market
+pub const MAX_OUTCOMES: usize = 8;
+
+/// Minimum number of outcomes (binary market is the N=2 case)
+pub const MIN_OUTCOMES: usize = 2;
+
+/// Minimum number of outcomes of a categorical market, two outcomes are created as a binary market
+pub const MIN_CATEGORICAL_OUTCOMES: usize = MIN_OUTCOMES + 1;
+
+/// Maximum length for a single outcome label
+pub const MAX_OUTCOME_LABEL_LENGTH: usize = 32;
+
+/// State transitions kept on the market (oldest entries are overwritten)
+pub const MAX_STATE_HISTORY: usize = 16;
+
 /// Minimum time until market expiry (1 hour in seconds)
 // In programs/market-registry/src/constants.rs
 
@@ -33,6 +78,104 @@ pub const MAX_EXPIRY_DURATION: i64 = 31_536_000;
 /// Time window for market resolution after expiry (7 days)
 pub const RESOLUTION_WINDOW: i64 = 604_800;
 
+#[cfg(not(feature = "testing"))]
+pub const DISPUTE_WINDOW_SECONDS: i64 = 86400; // 24 hours (production)
+
+#[cfg(feature = "testing")]
+pub const DISPUTE_WINDOW_SECONDS: i64 = 10; // 10 seconds (testing)
+
+/// Extended dispute window if challenged (additional 24 hours)
+pub const DISPUTE_EXTENSION_SECONDS: i64 = 24 * 60 * 60;
+
+/// Minimum bond required to propose outcome, in whole tokens of the collateral (1000)
+pub const MIN_PROPOSAL_BOND: u64 = 1_000;
+
+/// Maximum price deviation allowed between sources (5%)
+pub const MAX_PRICE_DEVIATION_BPS: u16 = 500; // 5% in basis points
+
+/// Maximum staleness for oracle data (5 minutes)
+pub const MAX_ORACLE_STALENESS_SECONDS: i64 = 5 * 60;
+
+/// Disputes before a proposal goes to the staker vote
+pub const ESCALATION_ROUNDS: u8 = 3;
+
+/// Length of the commit phase and of the reveal phase of a staker vote
+#[cfg(not(feature = "testing"))]
+pub const VOTE_DURATION: i64 = 86400; // 24 hours (production)
+
+#[cfg(feature = "testing")]
+pub const VOTE_DURATION: i64 = 5; // 5 seconds (testing)
+
+/// Time after expiry (or after a round reopened) for a proposal to arrive before the round counts as empty
+#[cfg(not(feature = "testing"))]
+pub const PROPOSAL_WINDOW: i64 = 21_600; // 6 hours (production)
+
+#[cfg(feature = "testing")]
+pub const PROPOSAL_WINDOW: i64 = 5; // 5 seconds (testing)
+
+/// Protocol cut of slashed resolution bonds (10%)
+pub const SLASH_FEE_BPS: u16 = 1_000;
+
+// Protocol bounds every per-market ResolutionConfig is validated against
+
+/// 1 hour to 30 days
+pub const RESOLUTION_WINDOW_RANGE: RangeInclusive<i64> = 3_600..=2_592_000;
+
+/// 10 minutes to 7 days
+#[cfg(not(feature = "testing"))]
+pub const DISPUTE_WINDOW_RANGE: RangeInclusive<i64> = 600..=604_800;
+
+#[cfg(feature = "testing")]
+pub const DISPUTE_WINDOW_RANGE: RangeInclusive<i64> = 1..=604_800;
+
+/// 10 minutes to 7 days
+#[cfg(not(feature = "testing"))]
+pub const DISPUTE_EXTENSION_RANGE: RangeInclusive<i64> = 600..=604_800;
+
+#[cfg(feature = "testing")]
+pub const DISPUTE_EXTENSION_RANGE: RangeInclusive<i64> = 1..=604_800;
+
+/// 1 to 1,000,000 whole tokens of the collateral, scaled by its decimals when validated
+pub const PROPOSAL_BOND_RANGE: RangeInclusive<u64> = 1..=1_000_000;
+
+/// 0.01% to 20%
+pub const PRICE_DEVIATION_BPS_RANGE: RangeInclusive<u16> = 1..=2_000;
+
+/// 10 seconds to 1 hour
+pub const ORACLE_STALENESS_RANGE: RangeInclusive<i64> = 10..=3_600;
+
+/// Upper bound of escalation rounds (sizes the disputes of a resolution proposal)
+pub const MAX_ESCALATION_ROUNDS: u8 = 5;
+
+pub const ESCALATION_ROUNDS_RANGE: RangeInclusive<u8> = 1..=MAX_ESCALATION_ROUNDS;
+
+/// 10 minutes to 7 days per phase
+#[cfg(not(feature = "testing"))]
+pub const VOTE_DURATION_RANGE: RangeInclusive<i64> = 600..=604_800;
+
+#[cfg(feature = "testing")]
+pub const VOTE_DURATION_RANGE: RangeInclusive<i64> = 1..=604_800;
+
+/// 10 minutes to 7 days
+#[cfg(not(feature = "testing"))]
+pub const PROPOSAL_WINDOW_RANGE: RangeInclusive<i64> = 600..=604_800;
+
+#[cfg(feature = "testing")]
+pub const PROPOSAL_WINDOW_RANGE: RangeInclusive<i64> = 1..=604_800;
+
+/// 0% to 50%
+pub const SLASH_FEE_BPS_RANGE: RangeInclusive<u16> = 0..=5_000;
+
+/// Extra resolution time of a market held for a postponed event (14 days)
+pub const POSTPONEMENT_HOLD_EXTENSION: i64 = 1_209_600;
+
+/// Wait after resolution before market, vault and resolution accounts can be closed (30 days)
+#[cfg(not(feature = "testing"))]
+pub const CLOSE_GRACE_PERIOD: i64 = 2_592_000;
+
+#[cfg(feature = "testing")]
+pub const CLOSE_GRACE_PERIOD: i64 = 10; // 10 seconds for tests
+
 /// Number of decimals for outcome tokens (same as USDC)
 pub const TOKEN_DECIMALS: u8 = 6;
 
@@ -78,5 +78,68 @@ pub enum MarketRegistryError {
     #[msg("Invalid escrow vault provided")]
     InvalidEscrowVault,
     #[msg("Invalid Input parameter")]
-    InvalidInput
+    InvalidInput,
+
+    #[msg("Invalid number of outcomes")]
+    InvalidOutcomeCount,
+
+    #[msg("Outcome label is empty or too long")]
+    InvalidOutcomeLabel,
+
+    #[msg("Scalar lower bound must be below the upper bound")]
+    InvalidScalarBounds,
+
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+
+    #[msg("Market creation is restricted to the protocol admin")]
+    CreationRestricted,
+
+    #[msg("Creator must wait before creating another market")]
+    CreationRateLimited,
+
+    #[msg("Creator bond accounts are missing or invalid")]
+    InvalidBondAccount,
+
+    #[msg("Market has no creator bond to release")]
+    NoCreatorBond,
+
+    #[msg("Creator bond is refundable only after the market resolved without being cancelled")]
+    BondNotRefundable,
+
+    #[msg("Creator bond is slashable only for cancelled markets")]
+    BondNotSlashable,
+
+    #[msg("Market is not resolved or the close grace period has not passed")]
+    MarketNotClosable,
+
+    #[msg("Escrow vault must be closed before the market")]
+    EscrowVaultNotClosed,
+
+    #[msg("Creator bond must be refunded or slashed before closing")]
+    CreatorBondOutstanding,
+
+    #[msg("Escrow vault program does not match the protocol deployment")]
+    InvalidEscrowProgram,
+
+    #[msg("Resolution adapter program does not match the protocol deployment")]
+    InvalidResolutionProgram,
+
+    #[msg("Open time must be in the future and before the trading close")]
+    InvalidOpenTime,
+
+    #[msg("Trading close must be after the open time and no later than expiry")]
+    InvalidTradingCloseTime,
+
+    #[msg("Market is scheduled to open later")]
+    MarketNotYetOpen,
+
+    #[msg("Trading window of this market has closed")]
+    TradingClosed,
+
+    #[msg("Resolution config is outside the protocol bounds")]
+    InvalidResolutionConfig,
+
+    #[msg("Resolution window can only be extended")]
+    InvalidResolutionWindowExtension
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{MarketState, ResultOutcome};
+use crate::state::{CreationMode, MarketState, ResolutionConfig, ResultOutcome};
 
 #[event]
 pub struct MarketCreated{
@@ -12,7 +12,36 @@ pub struct MarketCreated{
     pub escrow_vault:Pubkey,
     pub resolution_adapter:Pubkey,
     pub created_at:i64,
-    pub expire_at:i64
+    pub expire_at:i64,
+    pub open_at:Option<i64>,
+    pub trading_close_at:Option<i64>,
+    pub resolution_config:ResolutionConfig
+}
+
+#[event]
+pub struct ScalarMarketCreated{
+    pub market_address:Pubkey,
+    pub long_token_mint:Pubkey,
+    pub short_token_mint:Pubkey,
+    pub lower_bound:i64,
+    pub upper_bound:i64,
+    pub created_at:i64
+}
+
+#[event]
+pub struct CategoricalMarketCreated{
+    pub market_id : [u8;32],
+    pub market_address:Pubkey,
+    pub question : String,
+    pub outcome_mints : Vec<Pubkey>,
+    pub outcome_labels : Vec<String>,
+    pub escrow_vault:Pubkey,
+    pub resolution_adapter:Pubkey,
+    pub created_at:i64,
+    pub expire_at:i64,
+    pub open_at:Option<i64>,
+    pub trading_close_at:Option<i64>,
+    pub resolution_config:ResolutionConfig
 }
 
 #[event]
@@ -48,6 +77,33 @@ pub struct MarketMetaDataUpdated{
     pub timestamp : i64
 }
 
+#[event]
+pub struct ResolutionWindowExtended{
+    pub market_id : [u8;32],
+    pub market_address:Pubkey,
+
+    pub old_resolution_window_end : i64,
+    pub new_resolution_window_end : i64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct MarketExpiryUpdated{
+    pub market_id : [u8;32],
+    pub market_address:Pubkey,
+
+    pub old_expire_at : i64,
+    pub new_expire_at : i64,
+    // shifted by the same delta as the expiry
+    pub trading_close_at : Option<i64>,
+
+    pub postponement_hold : bool,
+    pub resolution_window_end : i64,
+
+    pub timestamp : i64
+}
+
 #[event]
 pub struct MarketCancelled {
     /// Market ID
@@ -58,4 +114,87 @@ pub struct MarketCancelled {
     
     /// Cancellation timestamp
     pub cancelled_at: i64,
-}
\ No newline at end of file
+}
+#[event]
+pub struct AdminConfigInitialized {
+    pub admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferStarted {
+    pub current_admin: Pubkey,
+
+    pub pending_admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+
+    pub new_admin: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ArbitratorUpdated {
+    pub admin: Pubkey,
+
+    pub previous_arbitrator: Pubkey,
+
+    pub new_arbitrator: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreationPolicyUpdated {
+    pub admin: Pubkey,
+
+    pub creation_mode: CreationMode,
+
+    pub creation_bond: u64,
+
+    pub creation_cooldown: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorBondPosted {
+    pub market_address: Pubkey,
+
+    pub creator: Pubkey,
+
+    pub amount: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorBondReleased {
+    pub market_address: Pubkey,
+
+    pub creator: Pubkey,
+
+    pub amount: u64,
+    // true -> sent to the treasury, false -> refunded to the creator
+    pub slashed: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketClosed {
+    pub market_id: [u8; 32],
+
+    pub market_address: Pubkey,
+    // rent receiver (original payer)
+    pub creator: Pubkey,
+
+    pub timestamp: i64,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::ADMIN_SEED, error::MarketRegistryError, event::AdminTransferred, state::AdminAuthority};
+
+// step 2 of the admin handover: the nominee signs to prove it controls the key
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info>{
+    pub new_admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_SEED],
+        bump = config.bump
+    )]
+    pub config : Account<'info,AdminAuthority>
+}
+
+pub fn handler(ctx:Context<AcceptAdmin>)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    let pending_admin = config.pending_admin.ok_or(MarketRegistryError::NoPendingAdmin)?;
+    require!(pending_admin == ctx.accounts.new_admin.key(),MarketRegistryError::Unauthorized);
+
+    let previous_admin = config.admin;
+    config.admin = pending_admin;
+    config.pending_admin = None;
+
+    msg!("Admin transferred");
+    msg!("{} -> {}", previous_admin, config.admin);
+
+    emit!(AdminTransferred{
+        previous_admin,
+        new_admin : config.admin,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
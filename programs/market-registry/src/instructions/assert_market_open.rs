@@ -11,8 +11,11 @@ pub struct AssertMarketOpen<'info>{
 }
 
 pub fn handler(ctx:Context<AssertMarketOpen>) ->Result<()>{
-    let market = &mut ctx.accounts.market;
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
 
     require!(market.state == MarketState::Open,MarketRegistryError::MarketNotOpen);
+    require!(!market.is_experied(clock.unix_timestamp),MarketRegistryError::MarketExpired);
+    require!(market.can_trade(clock.unix_timestamp),MarketRegistryError::TradingClosed);
     Ok(())
 }
\ No newline at end of file
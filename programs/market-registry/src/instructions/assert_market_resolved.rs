@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{Market, MarketState,
+use crate::{Market,
     error::MarketRegistryError
 };
 
@@ -13,6 +13,6 @@ pub struct AssertMarketResolved<'info>{
 pub fn handler(ctx:Context<AssertMarketResolved>) ->Result<()>{
     let market = &mut ctx.accounts.market;
 
-    require!(market.state == MarketState::Resolved,MarketRegistryError::MarketNotOpen);
+    require!(market.is_resolved(),MarketRegistryError::MarketNotOpen);
     Ok(())
 }
\ No newline at end of file
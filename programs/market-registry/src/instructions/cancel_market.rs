@@ -1,17 +1,24 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketCancelled, state::{Market, MarketState, ResultOutcome}};
+use crate::{constants::{ADMIN_SEED, MARKET_SEED}, error::MarketRegistryError, event::MarketCancelled, state::{AdminAuthority, Market, MarketState, ResultOutcome}};
 #[derive(Accounts)]
 
 pub struct  CancelMarket<'info>{
     #[account(mut)]
     pub admin : Signer<'info>,
+    // protocol admin, creators cannot pause, resume or cancel markets
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
     #[account(
          mut,
          seeds = [MARKET_SEED,market.market_id.as_ref()],
-         bump = market.bump,
-         constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+         bump = market.bump
     )]
     pub market : Account<'info,Market>
 }
@@ -29,10 +36,10 @@ pub fn handler(ctx :Context<CancelMarket>)->Result<()>{
     require!(!market.is_resolved(),MarketRegistryError::MarketAlreadyResolved);
     // set the resolution outcome to invalid 
     // and update the market resolve time 
-    // and update the state from current state to Resolved
+    // and update the state from current state to Cancelled
     market.resolution_outcome = Some(ResultOutcome::Invalid);
     market.resolved_at  = Some(current_timestamp);
-    market.state = MarketState::Resolved;
+    market.transition(market_address,MarketState::Cancelled,ctx.accounts.admin.key(),current_timestamp)?;
 
     msg!("Market cancelled: {:?}", market.market_id);
     msg!("All users will receive full refunds");
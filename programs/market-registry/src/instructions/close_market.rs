@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketClosed, state::Market};
+
+// permissionless clean-up of a resolved market once the grace period passed
+// the escrow vault (which reads this account) has to be closed first, rent goes back to the creator
+#[derive(Accounts)]
+pub struct CloseMarket<'info>{
+    pub closer : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump,
+        close = creator
+    )]
+    pub market : Account<'info,Market>,
+
+    /// CHECK: original payer of the market account, receives the rent
+    #[account(
+        mut,
+        address = market.creator @ MarketRegistryError::Unauthorized
+    )]
+    pub creator : UncheckedAccount<'info>,
+
+    /// CHECK: only checked to be closed (no data left)
+    #[account(
+        address = market.escrow_vault @ MarketRegistryError::InvalidEscrowVault
+    )]
+    pub escrow_vault : UncheckedAccount<'info>
+}
+
+pub fn handler(ctx:Context<CloseMarket>)->Result<()>{
+    let market = &ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(market.is_closable(clock.unix_timestamp),MarketRegistryError::MarketNotClosable);
+    require!(market.creator_bond == 0,MarketRegistryError::CreatorBondOutstanding);
+    require!(ctx.accounts.escrow_vault.data_is_empty(),MarketRegistryError::EscrowVaultNotClosed);
+
+    msg!("Market closed: {:?}", market.market_id);
+    msg!("Rent returned to: {}", market.creator);
+
+    emit!(MarketClosed{
+        market_id : market.market_id,
+        market_address : market.key(),
+        creator : market.creator,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{constants::{ADMIN_SEED, CREATOR_BOND_VAULT_SEED}, error::MarketRegistryError, event::CreationPolicyUpdated, state::AdminAuthority};
+
+// sets the bond every creator posts per market (0 disables it)
+// bonds sit in a single vault owned by the config PDA, the bond mint is fixed once the vault exists
+#[derive(Accounts)]
+pub struct ConfigureCreatorBond<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    pub bond_mint : Account<'info,Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [CREATOR_BOND_VAULT_SEED],
+        bump,
+        token::mint = bond_mint,
+        token::authority = config
+    )]
+    pub bond_vault : Account<'info,TokenAccount>,
+
+    // receives slashed bonds
+    #[account(
+        constraint = bond_treasury.mint == bond_mint.key() @ MarketRegistryError::InvalidBondAccount
+    )]
+    pub bond_treasury : Account<'info,TokenAccount>,
+
+    pub system_program : Program<'info,System>,
+    pub token_program : Program<'info,Token>
+}
+
+pub fn handler(ctx:Context<ConfigureCreatorBond>,creation_bond:u64)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    config.creation_bond = creation_bond;
+    config.bond_vault = ctx.accounts.bond_vault.key();
+    config.bond_treasury = ctx.accounts.bond_treasury.key();
+
+    msg!("Creator bond configured");
+    msg!("Bond: {}", creation_bond);
+    msg!("Bond vault: {}", config.bond_vault);
+    msg!("Treasury: {}", config.bond_treasury);
+
+    emit!(CreationPolicyUpdated{
+        admin : config.admin,
+        creation_mode : config.creation_mode,
+        creation_bond,
+        creation_cooldown : config.creation_cooldown,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
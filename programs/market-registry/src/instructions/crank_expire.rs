@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MARKET_SEED, error::MarketRegistryError, state::{Market, MarketState}};
+
+// permissionless: anyone can move an OPEN/PAUSED market past its trading close (or expiry) to CLOSED
+#[derive(Accounts)]
+pub struct CrankExpire<'info>{
+    pub cranker : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump
+    )]
+    pub market : Account<'info,Market>
+}
+
+pub fn handler(ctx:Context<CrankExpire>)->Result<()>{
+    let market_address = ctx.accounts.market.key();
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    // only OPEN/PAUSED -> CLOSED is a legal edge
+    require!(
+        market.state.can_transition_to(MarketState::Close),
+        MarketRegistryError::InvalidMarketState
+    );
+    require!(market.is_trading_closed(current_timestamp),MarketRegistryError::MarketNotExpired);
+
+    msg!("Market expired: {:?}", market.market_id);
+    msg!("Cranked by: {}", ctx.accounts.cranker.key());
+    market.transition(market_address,MarketState::Close,ctx.accounts.cranker.key(),current_timestamp)?;
+    Ok(())
+}
@@ -2,22 +2,29 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{MARKET_SEED, Market, MarketRegistryError, MarketResolved, MarketState, ResultOutcome};
+use crate::{ADMIN_SEED, AdminAuthority, MARKET_SEED, Market, MarketRegistryError, MarketResolved, ResultOutcome};
 
 
 
 #[derive(Accounts)]
 pub struct EmergencyFinalizeMarket<'info> {
-    /// Admin authority - the one who created the market or protocol admin
+    /// Protocol admin (market creators cannot force an outcome)
     #[account(mut)]
     pub admin: Signer<'info>,
+
+    /// Protocol admin config
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config: Account<'info, AdminAuthority>,
     
     /// Market to finalize
     #[account(
         mut,
         seeds = [MARKET_SEED, market.market_id.as_ref()],
-        bump = market.bump,
-        constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+        bump = market.bump
     )]
     pub market: Account<'info, Market>
 }
@@ -27,6 +34,7 @@ pub fn handler(
     outcome: ResultOutcome,
     reason: String
 ) -> Result<()> {
+    let market_address = ctx.accounts.market.key();
     let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
@@ -50,14 +58,18 @@ pub fn handler(
         MarketRegistryError::MarketAlreadyResolved
     );
 
+    // Outcome must exist for this market type
+    require!(
+        market.is_valid_outcome(outcome),
+        MarketRegistryError::InvalidOutcome
+    );
+
     // Emergency override - skip normal validation
     // (no expiry check, no resolution window check, no state check)
     msg!("⚠️ Bypassing normal resolution checks (emergency mode)");
 
     // Set outcome and mark as resolved
-    market.resolution_outcome = Some(outcome);
-    market.resolved_at = Some(current_timestamp);
-    market.state = MarketState::Resolved;
+    market.resolve(market_address,outcome,ctx.accounts.admin.key(),current_timestamp)?;
 
     msg!("✅ Market finalized via emergency procedure");
     msg!("   Outcome: {:?}", outcome);
@@ -66,7 +78,7 @@ pub fn handler(
     // Emit event
     emit!(MarketResolved {
         market_id: market.market_id,
-        market_address: ctx.accounts.market.key(),
+        market_address,
         market_outcome: outcome,
         resolved_at: current_timestamp,
     });
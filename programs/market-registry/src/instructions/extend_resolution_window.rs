@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::ResolutionWindowExtended, state::Market};
+
+// the resolution adapter pushes the window out when a reopened proposal round could not settle inside it
+#[derive(Accounts)]
+pub struct ExtendResolutionWindow<'info>{
+    #[account(mut)]
+    pub resolution_adapter : Signer<'info>,
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump,
+        constraint = market.resolution_adapter == resolution_adapter.key() @ MarketRegistryError::InvalidResolutionAdapter
+    )]
+    pub market : Account<'info,Market>
+}
+
+pub fn handler(ctx:Context<ExtendResolutionWindow>,new_end:i64)->Result<()>{
+    let market_address = ctx.accounts.market.key();
+
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    require!(!market.is_resolved(),MarketRegistryError::MarketAlreadyResolved);
+    require!(market.in_resolution_window(current_timestamp),MarketRegistryError::ResolutionWindowClosed);
+
+    let old_end = market.resolution_window_end();
+    require!(new_end > old_end,MarketRegistryError::InvalidResolutionWindowExtension);
+
+    let added = new_end.checked_sub(old_end).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+    market.resolution_window_extension = market.resolution_window_extension
+        .checked_add(added)
+        .ok_or(MarketRegistryError::ArithmeticOverflow)?;
+
+    msg!("Resolution window extended: {:?}", market.market_id);
+    msg!("Old end: {}", old_end);
+    msg!("New end: {}", new_end);
+
+    emit!(ResolutionWindowExtended {
+        market_id: market.market_id,
+        market_address,
+        old_resolution_window_end: old_end,
+        new_resolution_window_end: new_end,
+        timestamp: current_timestamp,
+    });
+    Ok(())
+}
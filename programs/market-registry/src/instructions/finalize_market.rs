@@ -29,18 +29,17 @@ pub fn handler(ctx:Context<FinalizeMarket>,outcome:ResultOutcome)->Result<()>{
     // 1) market state is reolving and market is expired
     // 2) market is not resolved 
     // 3) amrket is in resolution phase 
-    require!(market.state == MarketState::Resolving || market.is_experied(current_timestamp)
+    require!(matches!(market.state,MarketState::Resolving | MarketState::Close) || market.is_experied(current_timestamp)
         ,MarketRegistryError::InvalidMarketState);
 
     require!(!market.is_resolved(),MarketRegistryError::MarketAlreadyResolved);
+    require!(market.is_valid_outcome(outcome),MarketRegistryError::InvalidOutcome);
     require!(market.in_resolution_window(current_timestamp),MarketRegistryError::ResolutionWindowClosed);
     // 1)set market Outcome
     // 2) Update makrket resolution time 
     // 3)change market state
     
-    market.resolution_outcome = Some(outcome);
-    market.resolved_at = Some(current_timestamp);
-    market.state = MarketState::Resolved;
+    market.resolve(market_address,outcome,ctx.accounts.resolution_adapter.key(),current_timestamp)?;
     
     msg!("Market finalized: {:?}", market.market_id);
     msg!("Outcome: {:?}", outcome);
@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{
+    constants::*,
+    error::MarketRegistryError,
+    event::{CategoricalMarketCreated, CreatorBondPosted},
+    instructions::initialize_market::{enforce_creation_policy, MarketBootstrap},
+    state::{validate_outcome_labels, AdminAuthority, CreatorStats, InitializeMarketParams, Market}
+};
+
+// creates a market with N mutually exclusive outcomes
+// outcome mints are created by the client beforehand (decimals = TOKEN_DECIMALS, mint authority = escrow vault),
+// either all SPL Token or all Token-2022
+// and passed as remaining accounts in the same order as the labels
+#[derive(Accounts)]
+#[instruction(params:InitializeMarketParams)]
+pub struct CategoricalMarketInitialize<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Market::LEN,
+        seeds=[MARKET_SEED,params.market_id.as_ref()],
+        bump
+    )]
+    pub market : Account<'info,Market>,
+
+    /// Escrow vault PDA, created by the escrow vault program in the handler
+    /// CHECK: This is validated by seeds constraint
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED,market.key().as_ref()],
+        bump,
+        seeds::program = ESCROW_VAULT_PROGRAM_ID
+    )]
+    pub escrow_vault : UncheckedAccount<'info>,
+
+    /// CHECK: pinned to the deployed escrow vault program
+    #[account(address = ESCROW_VAULT_PROGRAM_ID @ MarketRegistryError::InvalidEscrowProgram)]
+    pub escrow_program:UncheckedAccount<'info>,
+
+    // collateral mint of the market, its decimals scale the bond limits of the resolution config
+    pub usdc_mint : Box<InterfaceAccount<'info,Mint>>,
+
+    /// CHECK: collateral account of the escrow vault, created by the escrow vault program
+    #[account(mut)]
+    pub usdc_vault : UncheckedAccount<'info>,
+
+    /// Resolution proposal PDA, created by the resolution adapter program in the handler
+    /// CHECK: This is validated by seeds constraint
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED,market.key().as_ref()],
+        bump,
+        seeds::program = RESOLUTION_ADAPTER_PROGRAM_ID
+    )]
+    pub resolution_adapter : UncheckedAccount<'info>,
+
+    /// CHECK: oracle bond vault of the proposal, created by the resolution adapter program
+    #[account(
+        mut,
+        seeds = [RESOLUTION_BOND_VAULT_SEED,market.key().as_ref()],
+        bump,
+        seeds::program = RESOLUTION_ADAPTER_PROGRAM_ID
+    )]
+    pub resolution_bond_vault : UncheckedAccount<'info>,
+
+    /// CHECK: pinned to the deployed resolution adapter program
+    #[account(address = RESOLUTION_ADAPTER_PROGRAM_ID @ MarketRegistryError::InvalidResolutionProgram)]
+    pub resolution_program : UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump
+    )]
+    pub config : Box<Account<'info,AdminAuthority>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreatorStats::LEN,
+        seeds = [CREATOR_SEED,admin.key().as_ref()],
+        bump
+    )]
+    pub creator_stats : Box<Account<'info,CreatorStats>>,
+
+    // only required while the protocol charges a creation bond
+    #[account(mut)]
+    pub creator_bond_account : Option<Box<Account<'info,TokenAccount>>>,
+
+    #[account(mut)]
+    pub bond_vault : Option<Box<Account<'info,TokenAccount>>>,
+
+    pub system_program : Program<'info,System>,
+    pub token_program : Program<'info,Token>,
+    // program of the collateral mint (SPL Token or Token-2022)
+    pub collateral_token_program : Interface<'info,TokenInterface>,
+    pub associated_token_program : Program<'info,AssociatedToken>,
+    pub rent : Sysvar<'info,Rent>
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, CategoricalMarketInitialize<'info>>,params : InitializeMarketParams,outcome_labels:Vec<String>)->Result<()>{
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    params.validate(current_timestamp,ctx.accounts.usdc_mint.decimals)?;
+    validate_outcome_labels(&outcome_labels)?;
+
+    require!(
+        ctx.remaining_accounts.len() == outcome_labels.len(),
+        MarketRegistryError::InvalidOutcomeCount
+    );
+
+    let creator_bond = enforce_creation_policy(
+        &ctx.accounts.config,
+        &mut ctx.accounts.creator_stats,
+        ctx.bumps.creator_stats,
+        &ctx.accounts.admin,
+        ctx.accounts.creator_bond_account.as_deref(),
+        ctx.accounts.bond_vault.as_deref(),
+        &ctx.accounts.token_program,
+        current_timestamp
+    )?;
+
+    let escrow_vault = ctx.accounts.escrow_vault.key();
+    let mut outcome_mints = Vec::with_capacity(outcome_labels.len());
+    let outcome_token_program = *ctx.remaining_accounts[0].owner;
+
+    for mint_info in ctx.remaining_accounts.iter() {
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        require!(*mint_info.owner == outcome_token_program,MarketRegistryError::InvalidTokenMint);
+
+        // fresh mint controlled by the escrow vault, no pre-minted supply
+        require!(
+            mint.mint_authority == COption::Some(escrow_vault)
+                && mint.decimals == TOKEN_DECIMALS
+                && mint.supply == 0,
+            MarketRegistryError::InvalidTokenMint
+        );
+        require!(!outcome_mints.contains(&mint_info.key()),MarketRegistryError::InvalidTokenMint);
+
+        outcome_mints.push(mint_info.key());
+    }
+
+    let market_key = ctx.accounts.market.key();
+    let market = &mut ctx.accounts.market;
+
+    market.market_id = params.market_id;
+    market.question = params.question.clone();
+    market.description = params.description.clone();
+    market.creator = ctx.accounts.admin.key();
+    market.category = params.category.clone();
+    market.created_at = current_timestamp;
+    market.expire_at = params.expire_at;
+    market.open_at = params.open_at;
+    market.trading_close_at = params.trading_close_at;
+    market.postponement_hold = false;
+    market.resolution_window_extension = 0;
+    market.resolution_config = params.resolution_config(ctx.accounts.usdc_mint.decimals)?;
+    market.init_state_history();
+    // binary-only instructions (mint_pairs, claim_payout) do not apply to categorical markets
+    market.yes_token_mint = Pubkey::default();
+    market.no_token_mint = Pubkey::default();
+    market.escrow_vault = escrow_vault;
+    market.resolution_adapter = ctx.accounts.resolution_adapter.key();
+    market.resolution_source = params.resolution_source.clone();
+    market.resolution_outcome = None;
+    market.resolved_at = None;
+    market.bump = ctx.bumps.market;
+    market.outcome_count = outcome_mints.len() as u8;
+    market.outcome_mints = outcome_mints;
+    market.outcome_labels = outcome_labels;
+    market.scalar_bounds = None;
+    market.creator_bond = creator_bond;
+    market.outcome_token_program = outcome_token_program;
+
+    msg!("Categorical market created successfully");
+    msg!("Market ID: {:?}", market.market_id);
+    msg!("Outcomes: {}", market.outcome_count);
+    for (label, mint) in market.outcome_labels.iter().zip(market.outcome_mints.iter()) {
+        msg!("  {} -> {}", label, mint);
+    }
+    msg!("Escrow vault: {}", market.escrow_vault);
+
+    if creator_bond > 0 {
+        emit!(CreatorBondPosted {
+            market_address: market_key,
+            creator: market.creator,
+            amount: creator_bond,
+            timestamp: current_timestamp,
+        });
+    }
+
+    emit!(CategoricalMarketCreated {
+        market_id: market.market_id,
+        market_address: market_key,
+        question: params.question,
+        outcome_mints: market.outcome_mints.clone(),
+        outcome_labels: market.outcome_labels.clone(),
+        escrow_vault: market.escrow_vault,
+        resolution_adapter: market.resolution_adapter,
+        created_at: current_timestamp,
+        expire_at: market.expire_at,
+        open_at: market.open_at,
+        trading_close_at: market.trading_close_at,
+        resolution_config: market.resolution_config,
+    });
+
+    // vault and resolution proposal are created in the same transaction, bound to this market
+    let market_id = params.market_id;
+    let market_bump = ctx.bumps.market;
+    let market_seeds : &[&[u8]] = &[MARKET_SEED, market_id.as_ref(), &[market_bump]];
+    // the categorical vault copies the outcome mints from the market account
+    ctx.accounts.market.exit(&crate::ID)?;
+    MarketBootstrap {
+        creator : ctx.accounts.admin.to_account_info(),
+        market : ctx.accounts.market.to_account_info(),
+        escrow_vault : ctx.accounts.escrow_vault.to_account_info(),
+        escrow_program : ctx.accounts.escrow_program.to_account_info(),
+        usdc_mint : ctx.accounts.usdc_mint.to_account_info(),
+        usdc_vault : ctx.accounts.usdc_vault.to_account_info(),
+        resolution_adapter : ctx.accounts.resolution_adapter.to_account_info(),
+        resolution_bond_vault : ctx.accounts.resolution_bond_vault.to_account_info(),
+        resolution_program : ctx.accounts.resolution_program.to_account_info(),
+        collateral_token_program : ctx.accounts.collateral_token_program.to_account_info(),
+        associated_token_program : ctx.accounts.associated_token_program.to_account_info(),
+        system_program : ctx.accounts.system_program.to_account_info(),
+        rent : ctx.accounts.rent.to_account_info(),
+    }.run(&INITIALIZE_CATEGORICAL_VAULT_DISCRIMINATOR, &[], params.resolution_category, ctx.accounts.market.resolution_config, market_seeds)?;
+    Ok(())
+}
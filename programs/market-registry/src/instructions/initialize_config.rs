@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{constants::ADMIN_SEED, error::MarketRegistryError, event::AdminConfigInitialized, state::{AdminAuthority, CreationMode}};
+
+// one-time setup of the protocol admin PDA, the signer becomes the first admin
+#[derive(Accounts)]
+pub struct InitializeConfig<'info>{
+    // must be the program's upgrade authority, otherwise the first caller would own admin and arbitration
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ MarketRegistryError::Unauthorized
+    )]
+    pub program_data : Account<'info,ProgramData>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAuthority::LEN,
+        seeds = [ADMIN_SEED],
+        bump
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    pub system_program : Program<'info,System>
+}
+
+pub fn handler(ctx:Context<InitializeConfig>)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = None;
+    config.bump = ctx.bumps.config;
+    // only the admin creates markets until it opens creation (and sets a bond) with update_creation_policy
+    config.creation_mode = CreationMode::AdminOnly;
+    config.creation_bond = 0;
+    config.creation_cooldown = 0;
+    config.bond_vault = Pubkey::default();
+    config.bond_treasury = Pubkey::default();
+    // the first admin arbitrates until set_arbitrator hands it to e.g. a council multisig
+    config.arbitrator = ctx.accounts.admin.key();
+
+    msg!("Protocol admin config initialized");
+    msg!("Admin: {}", config.admin);
+
+    emit!(AdminConfigInitialized{
+        admin : config.admin,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -1,10 +1,22 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{ token::{Mint, Token}};
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{ associated_token::AssociatedToken, token::{self, Token, TokenAccount, Transfer}};
+use anchor_spl::token_interface::{
+    self, find_mint_account_size, InitializeMint2, Mint, MetadataPointerInitialize, SetAuthority, TokenInterface, TokenMetadataInitialize,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_2022::{extension::ExtensionType, instruction::AuthorityType},
+    spl_token_metadata_interface::state::TokenMetadata,
+};
 
 use crate::{
     constants::*,
-    event::MarketCreated, 
-    state::{Market,InitializeMarketParams}
+    error::MarketRegistryError,
+    event::{CreatorBondPosted, MarketCreated, ScalarMarketCreated},
+    state::{AdminAuthority, CreatorStats, Market, InitializeMarketParams, ResolutionCategory, ResolutionConfig, ScalarBounds}
 };
 
 #[derive(Accounts)]
@@ -22,60 +34,138 @@ pub struct MarketInitialize<'info>{
     )]
     pub market : Account<'info,Market>,
 
-    #[account(
-        init,
-        payer = admin,
-        mint::decimals = TOKEN_DECIMALS,
-        mint::authority = escrow_vault,
-    )]
-    pub yes_token_mint : Account<'info,Mint>,
-
+    // created in the handler (decimals = TOKEN_DECIMALS, mint authority = escrow vault)
+    #[account(mut)]
+    pub yes_token_mint : Signer<'info>,
 
-    #[account(
-        init,
-        payer = admin,
-        mint::decimals = TOKEN_DECIMALS,
-        mint::authority = escrow_vault,
-    )]
-    pub no_token_mint : Account<'info,Mint>,
+    #[account(mut)]
+    pub no_token_mint : Signer<'info>,
     
-    /// Escrow vault PDA (will be initialized by escrow program)
-    /// We just validate it matches expected PDA
+    /// Escrow vault PDA, created by the escrow vault program in the handler
     /// CHECK: This is validated by seeds constraint
     #[account(
-        seeds = [b"escrow_vault",market.key().as_ref()],
+        mut,
+        seeds = [ESCROW_VAULT_SEED,market.key().as_ref()],
         bump,
-        // comment this for now 
-        seeds::program = escrow_program.key()
+        seeds::program = ESCROW_VAULT_PROGRAM_ID
     )]
     pub escrow_vault : UncheckedAccount<'info>,
-    // used unchecked account when it is used by another account 
 
-    /// Escrow program (for CPI to initialize vault)
-    /// CHECK: We verify this is the correct program via constraint
+    /// CHECK: pinned to the deployed escrow vault program
+    #[account(address = ESCROW_VAULT_PROGRAM_ID @ MarketRegistryError::InvalidEscrowProgram)]
     pub escrow_program:UncheckedAccount<'info>,
 
-    /// Resolution adapter account (will be initialized by resolution program)
-    /// CHECK: This is just storage for the address
-    /// The resolution program will initialize this account
+    // collateral mint of the market, its decimals scale the bond limits of the resolution config
+    pub usdc_mint : Box<InterfaceAccount<'info,Mint>>,
+
+    /// CHECK: collateral account of the escrow vault, created by the escrow vault program
+    #[account(mut)]
+    pub usdc_vault : UncheckedAccount<'info>,
+
+    /// Resolution proposal PDA, created by the resolution adapter program in the handler
+    /// CHECK: This is validated by seeds constraint
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED,market.key().as_ref()],
+        bump,
+        seeds::program = RESOLUTION_ADAPTER_PROGRAM_ID
+    )]
     pub resolution_adapter : UncheckedAccount<'info>,
+
+    /// CHECK: oracle bond vault of the proposal, created by the resolution adapter program
+    #[account(
+        mut,
+        seeds = [RESOLUTION_BOND_VAULT_SEED,market.key().as_ref()],
+        bump,
+        seeds::program = RESOLUTION_ADAPTER_PROGRAM_ID
+    )]
+    pub resolution_bond_vault : UncheckedAccount<'info>,
+
+    /// CHECK: pinned to the deployed resolution adapter program
+    #[account(address = RESOLUTION_ADAPTER_PROGRAM_ID @ MarketRegistryError::InvalidResolutionProgram)]
+    pub resolution_program : UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump
+    )]
+    pub config : Box<Account<'info,AdminAuthority>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CreatorStats::LEN,
+        seeds = [CREATOR_SEED,admin.key().as_ref()],
+        bump
+    )]
+    pub creator_stats : Box<Account<'info,CreatorStats>>,
+
+    // only required while the protocol charges a creation bond
+    #[account(mut)]
+    pub creator_bond_account : Option<Box<Account<'info,TokenAccount>>>,
+
+    #[account(mut)]
+    pub bond_vault : Option<Box<Account<'info,TokenAccount>>>,
+
     pub system_program : Program<'info,System>,
     pub token_program : Program<'info,Token>,
+    // Token-2022 -> outcome mints carry metadata (label + question), defaults to token_program
+    pub outcome_token_program : Option<Interface<'info,TokenInterface>>,
+    // program of the collateral mint (SPL Token or Token-2022)
+    pub collateral_token_program : Interface<'info,TokenInterface>,
+    pub associated_token_program : Program<'info,AssociatedToken>,
     pub rent : Sysvar<'info,Rent>
 }
 
 
 pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->Result<()>{
-    
+    create_market(ctx, params, None)
+}
+
+// shared by initialize_market and initialize_scalar_market (YES = LONG, NO = SHORT)
+pub fn create_market(ctx:Context<MarketInitialize>,params : InitializeMarketParams,scalar_bounds:Option<ScalarBounds>)->Result<()>{
+
     let clock = Clock::get()?;
 
     let current_timestamp = clock.unix_timestamp;
 
-    params.validate(current_timestamp)?;
+    params.validate(current_timestamp,ctx.accounts.usdc_mint.decimals)?;
+
+    let creator_bond = enforce_creation_policy(
+        &ctx.accounts.config,
+        &mut ctx.accounts.creator_stats,
+        ctx.bumps.creator_stats,
+        &ctx.accounts.admin,
+        ctx.accounts.creator_bond_account.as_deref(),
+        ctx.accounts.bond_vault.as_deref(),
+        &ctx.accounts.token_program,
+        current_timestamp
+    )?;
 
     // initialized market 
     let market_key = ctx.accounts.market.key();
 
+    let outcome_token_program = match &ctx.accounts.outcome_token_program {
+        Some(program) => program.to_account_info(),
+        None => ctx.accounts.token_program.to_account_info()
+    };
+    let (yes_label, no_label) = match scalar_bounds {
+        Some(_) => ("Long", "Short"),
+        None => ("Yes", "No")
+    };
+    for (mint, label) in [(&ctx.accounts.yes_token_mint, yes_label), (&ctx.accounts.no_token_mint, no_label)] {
+        create_outcome_mint(
+            &ctx.accounts.admin,
+            mint,
+            &ctx.accounts.market.to_account_info(),
+            &outcome_token_program,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.key(),
+            label,
+            &params.question
+        )?;
+    }
+
     let market =  &mut ctx.accounts.market;
 
     market.market_id = params.market_id;
@@ -85,7 +175,12 @@ pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->R
     market.category = params.category.clone();
     market.created_at = current_timestamp;
     market.expire_at = params.expire_at;
-    market.state = crate::state::MarketState::Created;
+    market.open_at = params.open_at;
+    market.trading_close_at = params.trading_close_at;
+    market.postponement_hold = false;
+    market.resolution_window_extension = 0;
+    market.resolution_config = params.resolution_config(ctx.accounts.usdc_mint.decimals)?;
+    market.init_state_history();
     market.yes_token_mint = ctx.accounts.yes_token_mint.key();
     market.no_token_mint=ctx.accounts.no_token_mint.key();
     market.escrow_vault = ctx.accounts.escrow_vault.key();
@@ -94,6 +189,12 @@ pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->R
     market.resolution_outcome = None;
     market.resolved_at = None;
     market.bump = ctx.bumps.market;
+    market.outcome_count = 2;
+    market.outcome_mints = vec![market.yes_token_mint, market.no_token_mint];
+    market.outcome_labels = vec![String::from(yes_label), String::from(no_label)];
+    market.scalar_bounds = scalar_bounds;
+    market.creator_bond = creator_bond;
+    market.outcome_token_program = outcome_token_program.key();
 
     
 
@@ -101,6 +202,7 @@ pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->R
     msg!("Market ID: {:?}", market.market_id);
     msg!("YES token mint: {}", market.yes_token_mint);
     msg!("NO token mint: {}", market.no_token_mint);
+    msg!("Outcome token program: {}", market.outcome_token_program);
     msg!("Escrow vault: {}", market.escrow_vault);
 
     // Emit event
@@ -113,7 +215,299 @@ pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->R
         escrow_vault: market.escrow_vault,
         resolution_adapter: market.resolution_adapter,
         expire_at: market.expire_at,
+        open_at: market.open_at,
+        trading_close_at: market.trading_close_at,
+        resolution_config: market.resolution_config,
         created_at: current_timestamp,
     });
+
+    if creator_bond > 0 {
+        emit!(CreatorBondPosted {
+            market_address: market_key,
+            creator: market.creator,
+            amount: creator_bond,
+            timestamp: current_timestamp,
+        });
+    }
+
+    if let Some(bounds) = scalar_bounds {
+        msg!("Scalar range: {} -> {}", bounds.lower, bounds.upper);
+
+        emit!(ScalarMarketCreated {
+            market_address: market_key,
+            long_token_mint: market.yes_token_mint,
+            short_token_mint: market.no_token_mint,
+            lower_bound: bounds.lower,
+            upper_bound: bounds.upper,
+            created_at: current_timestamp,
+        });
+    }
+
+    // vault and resolution proposal are created in the same transaction, bound to this market
+    let market_id = params.market_id;
+    let market_bump = ctx.bumps.market;
+    let market_seeds : &[&[u8]] = &[MARKET_SEED, market_id.as_ref(), &[market_bump]];
+    ctx.accounts.market.exit(&crate::ID)?;
+    MarketBootstrap {
+        creator : ctx.accounts.admin.to_account_info(),
+        market : ctx.accounts.market.to_account_info(),
+        escrow_vault : ctx.accounts.escrow_vault.to_account_info(),
+        escrow_program : ctx.accounts.escrow_program.to_account_info(),
+        usdc_mint : ctx.accounts.usdc_mint.to_account_info(),
+        usdc_vault : ctx.accounts.usdc_vault.to_account_info(),
+        resolution_adapter : ctx.accounts.resolution_adapter.to_account_info(),
+        resolution_bond_vault : ctx.accounts.resolution_bond_vault.to_account_info(),
+        resolution_program : ctx.accounts.resolution_program.to_account_info(),
+        collateral_token_program : ctx.accounts.collateral_token_program.to_account_info(),
+        associated_token_program : ctx.accounts.associated_token_program.to_account_info(),
+        system_program : ctx.accounts.system_program.to_account_info(),
+        rent : ctx.accounts.rent.to_account_info(),
+    }.run(
+        &INITIALIZE_VAULT_DISCRIMINATOR,
+        &[ctx.accounts.yes_token_mint.to_account_info(), ctx.accounts.no_token_mint.to_account_info()],
+        params.resolution_category,
+        ctx.accounts.market.resolution_config,
+        market_seeds
+    )?;
     Ok(())
-}
\ No newline at end of file
+}
+
+// creates one outcome mint owned by the escrow vault
+// under Token-2022 the mint also gets a metadata pointer to itself and "<LABEL> – <question>" metadata,
+// the creator holds the mint authority only long enough to write it
+#[allow(clippy::too_many_arguments)]
+pub fn create_outcome_mint<'info>(
+    creator:&Signer<'info>,
+    mint:&Signer<'info>,
+    market:&AccountInfo<'info>,
+    token_program:&AccountInfo<'info>,
+    system_program:&Program<'info,System>,
+    escrow_vault:Pubkey,
+    label:&str,
+    question:&str
+)->Result<()>{
+    let with_metadata = token_program.key() == token_interface::ID;
+
+    let symbol = label.to_uppercase();
+    let name = format!("{} – {}", symbol, question);
+    let metadata = TokenMetadata{
+        update_authority : OptionalNonZeroPubkey::try_from(Some(market.key()))?,
+        mint : mint.key(),
+        name : name.clone(),
+        symbol : symbol.clone(),
+        uri : String::new(),
+        additional_metadata : Vec::new()
+    };
+
+    let (space, metadata_space) = if with_metadata {
+        (find_mint_account_size(Some(&vec![ExtensionType::MetadataPointer]))?, metadata.tlv_size_of()?)
+    } else {
+        (find_mint_account_size(None)?, 0)
+    };
+    // token-2022 reallocs the mint when the metadata is written, rent for it is paid upfront
+    let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+
+    system_program::create_account(
+        CpiContext::new(system_program.to_account_info(),
+            CreateAccount{
+                from : creator.to_account_info(),
+                to : mint.to_account_info()
+            }
+        ),
+        lamports,
+        space as u64,
+        token_program.key
+    )?;
+
+    if with_metadata {
+        token_interface::metadata_pointer_initialize(
+            CpiContext::new(token_program.clone(),
+                MetadataPointerInitialize{
+                    token_program_id : token_program.clone(),
+                    mint : mint.to_account_info()
+                }
+            ),
+            Some(market.key()),
+            Some(mint.key())
+        )?;
+    }
+
+    let initial_authority = if with_metadata { creator.key() } else { escrow_vault };
+    token_interface::initialize_mint2(
+        CpiContext::new(token_program.clone(),
+            InitializeMint2{
+                mint : mint.to_account_info()
+            }
+        ),
+        TOKEN_DECIMALS,
+        &initial_authority,
+        None
+    )?;
+
+    if with_metadata {
+        token_interface::token_metadata_initialize(
+            CpiContext::new(token_program.clone(),
+                TokenMetadataInitialize{
+                    program_id : token_program.clone(),
+                    metadata : mint.to_account_info(),
+                    update_authority : market.clone(),
+                    mint_authority : creator.to_account_info(),
+                    mint : mint.to_account_info()
+                }
+            ),
+            name,
+            symbol,
+            String::new()
+        )?;
+
+        token_interface::set_authority(
+            CpiContext::new(token_program.clone(),
+                SetAuthority{
+                    current_authority : creator.to_account_info(),
+                    account_or_mint : mint.to_account_info()
+                }
+            ),
+            AuthorityType::MintTokens,
+            Some(escrow_vault)
+        )?;
+        msg!("Outcome mint {} created with metadata", mint.key());
+    }
+    Ok(())
+}
+
+// escrow vault + resolution proposal of a new market
+// both programs depend on the registry, so their instructions are built by hand (anchor discriminator + borsh args)
+// the market PDA signs every CPI -> neither account can be created for a market outside its creation
+pub struct MarketBootstrap<'info>{
+    pub creator : AccountInfo<'info>,
+    pub market : AccountInfo<'info>,
+    pub escrow_vault : AccountInfo<'info>,
+    pub escrow_program : AccountInfo<'info>,
+    pub usdc_mint : AccountInfo<'info>,
+    pub usdc_vault : AccountInfo<'info>,
+    pub resolution_adapter : AccountInfo<'info>,
+    pub resolution_bond_vault : AccountInfo<'info>,
+    pub resolution_program : AccountInfo<'info>,
+    pub collateral_token_program : AccountInfo<'info>,
+    pub associated_token_program : AccountInfo<'info>,
+    pub system_program : AccountInfo<'info>,
+    pub rent : AccountInfo<'info>,
+}
+
+impl<'info> MarketBootstrap<'info>{
+    // outcome_mints follow the collateral mint in the vault instruction (binary vaults, categorical ones read the market)
+    pub fn run(&self,vault_discriminator:&[u8; 8],outcome_mints:&[AccountInfo<'info>],category:ResolutionCategory,config:ResolutionConfig,market_seeds:&[&[u8]])->Result<()>{
+        let mut vault_accounts = vec![
+            (self.creator.clone(), true),
+            (self.market.clone(), false),
+            (self.escrow_vault.clone(), true),
+            (self.usdc_vault.clone(), true),
+            (self.usdc_mint.clone(), false),
+        ];
+        vault_accounts.extend(outcome_mints.iter().map(|mint| (mint.clone(), false)));
+        vault_accounts.extend([
+            (self.system_program.clone(), false),
+            (self.collateral_token_program.clone(), false),
+            (self.associated_token_program.clone(), false),
+        ]);
+        self.invoke(&self.escrow_program, vault_discriminator, &[], &vault_accounts, market_seeds)?;
+        msg!("Escrow vault created: {}", self.escrow_vault.key());
+
+        // oracle bonds are posted in the market collateral
+        let resolution_accounts = [
+            (self.creator.clone(), true),
+            (self.market.clone(), false),
+            (self.resolution_adapter.clone(), true),
+            (self.resolution_bond_vault.clone(), true),
+            (self.usdc_mint.clone(), false),
+            (self.system_program.clone(), false),
+            (self.collateral_token_program.clone(), false),
+            (self.rent.clone(), false),
+        ];
+        let mut resolution_args = category.try_to_vec()?;
+        resolution_args.extend(config.try_to_vec()?);
+        self.invoke(&self.resolution_program, &INITIALIZE_RESOLUTION_DISCRIMINATOR, &resolution_args, &resolution_accounts, market_seeds)?;
+        msg!("Resolution proposal created: {}", self.resolution_adapter.key());
+        Ok(())
+    }
+
+    fn invoke(&self,program:&AccountInfo<'info>,discriminator:&[u8; 8],args:&[u8],accounts:&[(AccountInfo<'info>,bool)],market_seeds:&[&[u8]])->Result<()>{
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(args);
+
+        let metas = accounts.iter().map(|(info, writable)| {
+            let is_signer = info.is_signer || info.key == self.market.key;
+            if *writable {
+                AccountMeta::new(*info.key, is_signer)
+            } else {
+                AccountMeta::new_readonly(*info.key, is_signer)
+            }
+        }).collect();
+        let mut infos : Vec<AccountInfo<'info>> = accounts.iter().map(|(info, _)| info.clone()).collect();
+        infos.push(program.clone());
+
+        invoke_signed(
+            &Instruction{ program_id : *program.key, accounts : metas, data },
+            &infos,
+            &[market_seeds]
+        )?;
+        Ok(())
+    }
+}
+
+// admin-only switch, per-creator cooldown and creation bond -> shared by every market constructor
+// returns the bond posted for the new market
+#[allow(clippy::too_many_arguments)]
+pub fn enforce_creation_policy<'info>(
+    config:&AdminAuthority,
+    creator_stats:&mut CreatorStats,
+    creator_stats_bump:u8,
+    creator:&Signer<'info>,
+    creator_bond_account:Option<&Account<'info,TokenAccount>>,
+    bond_vault:Option<&Account<'info,TokenAccount>>,
+    token_program:&Program<'info,Token>,
+    current_timestamp:i64
+)->Result<u64>{
+    require!(config.can_create_market(&creator.key()),MarketRegistryError::CreationRestricted);
+
+    if creator_stats.markets_created > 0 && config.creation_cooldown > 0 {
+        let next_allowed = creator_stats.last_created_at
+            .checked_add(config.creation_cooldown)
+            .ok_or(MarketRegistryError::ArithmeticOverflow)?;
+        require!(current_timestamp >= next_allowed,MarketRegistryError::CreationRateLimited);
+    }
+
+    creator_stats.creator = creator.key();
+    creator_stats.bump = creator_stats_bump;
+    creator_stats.markets_created = creator_stats.markets_created
+        .checked_add(1)
+        .ok_or(MarketRegistryError::ArithmeticOverflow)?;
+    creator_stats.last_created_at = current_timestamp;
+
+    if config.creation_bond == 0 {
+        return Ok(0);
+    }
+
+    let (Some(creator_bond_account),Some(bond_vault)) = (creator_bond_account,bond_vault) else {
+        return err!(MarketRegistryError::InvalidBondAccount);
+    };
+    require!(
+        bond_vault.key() == config.bond_vault
+            && creator_bond_account.owner == creator.key()
+            && creator_bond_account.mint == bond_vault.mint,
+        MarketRegistryError::InvalidBondAccount
+    );
+
+    token::transfer(
+        CpiContext::new(token_program.to_account_info(),
+            Transfer{
+                from : creator_bond_account.to_account_info(),
+                to : bond_vault.to_account_info(),
+                authority : creator.to_account_info()
+            }
+        ),
+        config.creation_bond)?;
+
+    msg!("Creator bond posted: {}", config.creation_bond);
+    Ok(config.creation_bond)
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::initialize_market::{create_market, MarketInitialize},
+    state::{InitializeMarketParams, ScalarBounds}
+};
+
+// scalar (range) market -> same accounts as a binary market, YES mint is LONG and NO mint is SHORT
+// resolves to ResultOutcome::Scalar(price) and pays out linearly between the bounds
+pub fn handler(ctx:Context<MarketInitialize>,params : InitializeMarketParams,bounds:ScalarBounds)->Result<()>{
+    bounds.validate()?;
+
+    create_market(ctx, params, Some(bounds))
+}
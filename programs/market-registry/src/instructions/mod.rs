@@ -10,17 +10,102 @@ pub mod assert_market_resolved;
 pub mod resolving_market;
 pub mod assert_market_expired;
 pub mod emergency_finalize_market;
+pub mod initialize_categorical_market;
+pub mod initialize_scalar_market;
+pub mod initialize_config;
+pub mod transfer_admin;
+pub mod accept_admin;
+pub mod update_creation_policy;
+pub mod configure_creator_bond;
+pub mod refund_creator_bond;
+pub mod slash_creator_bond;
+pub mod close_market;
+pub mod crank_expire;
+pub mod update_expiry;
+pub mod set_arbitrator;
+pub mod extend_resolution_window;
 
 
-pub use initialize_market::*;
-pub use finalize_market::*;
-pub use open_market::*;
-pub use pause_market::*;
-pub use cancel_market::*;
-pub use resume_market::*;
-pub use update_market_metadata::*;
-pub use  assert_market_open::*;
-pub use  assert_market_resolved::*;
-pub use resolving_market::*;
-pub use assert_market_expired::*;
-pub use emergency_finalize_market::*;
\ No newline at end of file
+pub use initialize_market::{MarketInitialize, create_market, create_outcome_mint, MarketBootstrap, enforce_creation_policy};
+pub use finalize_market::FinalizeMarket;
+pub use open_market::OpenMarket;
+pub use pause_market::PauseMarket;
+pub use cancel_market::CancelMarket;
+pub use resume_market::ResumeMarket;
+pub use update_market_metadata::UpdateMarketMetadata;
+pub use assert_market_open::AssertMarketOpen;
+pub use assert_market_resolved::AssertMarketResolved;
+pub use resolving_market::ResolvingMarket;
+pub use assert_market_expired::AssertMarketExpired;
+pub use emergency_finalize_market::EmergencyFinalizeMarket;
+pub use initialize_categorical_market::CategoricalMarketInitialize;
+pub use initialize_config::InitializeConfig;
+pub use transfer_admin::TransferAdmin;
+pub use accept_admin::AcceptAdmin;
+pub use update_creation_policy::UpdateCreationPolicy;
+pub use configure_creator_bond::ConfigureCreatorBond;
+pub use refund_creator_bond::RefundCreatorBond;
+pub use slash_creator_bond::SlashCreatorBond;
+pub use close_market::CloseMarket;
+pub use crank_expire::CrankExpire;
+pub use update_expiry::UpdateExpiry;
+pub use set_arbitrator::SetArbitrator;
+pub use extend_resolution_window::ExtendResolutionWindow;
+
+// client account modules generated by #[derive(Accounts)], the #[program] macro resolves them from the crate root
+pub(crate) use {
+    initialize_market::__client_accounts_market_initialize,
+    finalize_market::__client_accounts_finalize_market,
+    open_market::__client_accounts_open_market,
+    pause_market::__client_accounts_pause_market,
+    cancel_market::__client_accounts_cancel_market,
+    resume_market::__client_accounts_resume_market,
+    update_market_metadata::__client_accounts_update_market_metadata,
+    assert_market_open::__client_accounts_assert_market_open,
+    assert_market_resolved::__client_accounts_assert_market_resolved,
+    resolving_market::__client_accounts_resolving_market,
+    assert_market_expired::__client_accounts_assert_market_expired,
+    emergency_finalize_market::__client_accounts_emergency_finalize_market,
+    initialize_categorical_market::__client_accounts_categorical_market_initialize,
+    initialize_config::__client_accounts_initialize_config,
+    transfer_admin::__client_accounts_transfer_admin,
+    accept_admin::__client_accounts_accept_admin,
+    update_creation_policy::__client_accounts_update_creation_policy,
+    configure_creator_bond::__client_accounts_configure_creator_bond,
+    refund_creator_bond::__client_accounts_refund_creator_bond,
+    slash_creator_bond::__client_accounts_slash_creator_bond,
+    close_market::__client_accounts_close_market,
+    crank_expire::__client_accounts_crank_expire,
+    update_expiry::__client_accounts_update_expiry,
+    set_arbitrator::__client_accounts_set_arbitrator,
+    extend_resolution_window::__client_accounts_extend_resolution_window,
+};
+
+#[cfg(feature = "cpi")]
+pub(crate) use {
+    initialize_market::__cpi_client_accounts_market_initialize,
+    finalize_market::__cpi_client_accounts_finalize_market,
+    open_market::__cpi_client_accounts_open_market,
+    pause_market::__cpi_client_accounts_pause_market,
+    cancel_market::__cpi_client_accounts_cancel_market,
+    resume_market::__cpi_client_accounts_resume_market,
+    update_market_metadata::__cpi_client_accounts_update_market_metadata,
+    assert_market_open::__cpi_client_accounts_assert_market_open,
+    assert_market_resolved::__cpi_client_accounts_assert_market_resolved,
+    resolving_market::__cpi_client_accounts_resolving_market,
+    assert_market_expired::__cpi_client_accounts_assert_market_expired,
+    emergency_finalize_market::__cpi_client_accounts_emergency_finalize_market,
+    initialize_categorical_market::__cpi_client_accounts_categorical_market_initialize,
+    initialize_config::__cpi_client_accounts_initialize_config,
+    transfer_admin::__cpi_client_accounts_transfer_admin,
+    accept_admin::__cpi_client_accounts_accept_admin,
+    update_creation_policy::__cpi_client_accounts_update_creation_policy,
+    configure_creator_bond::__cpi_client_accounts_configure_creator_bond,
+    refund_creator_bond::__cpi_client_accounts_refund_creator_bond,
+    slash_creator_bond::__cpi_client_accounts_slash_creator_bond,
+    close_market::__cpi_client_accounts_close_market,
+    crank_expire::__cpi_client_accounts_crank_expire,
+    update_expiry::__cpi_client_accounts_update_expiry,
+    set_arbitrator::__cpi_client_accounts_set_arbitrator,
+    extend_resolution_window::__cpi_client_accounts_extend_resolution_window,
+};
\ No newline at end of file
@@ -1,18 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketStateChanged, state::{Market, MarketState}
+use crate::{constants::MARKET_SEED, error::MarketRegistryError, state::{Market, MarketState}
         };
 
 #[derive(Accounts)]
 
 pub struct OpenMarket<'info>{
+    // market creator, or anyone once a scheduled open_at has passed
     #[account(mut)]
     pub admin  : Signer<'info>,
     #[account(
         mut,
         seeds= [MARKET_SEED,market.market_id.as_ref()],
-        bump = market.bump,
-        constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+        bump = market.bump
     )]
     pub market : Account<'info,Market>,
 
@@ -25,21 +25,15 @@ pub fn handler(ctx:Context<OpenMarket>)-> Result<()>{
     let current_timestamp = clock.unix_timestamp;
 
     require!(market.state == MarketState::Created,MarketRegistryError::InvalidMarketState);
-    require!(!market.is_experied(current_timestamp),MarketRegistryError::MarketExpired);
+    require!(!market.is_trading_closed(current_timestamp),MarketRegistryError::MarketExpired);
 
-    let old_state = market.state;
-
-    market.state = MarketState::Open;
+    // scheduled markets open at open_at (permissionless), the others by their creator
+    match market.open_at {
+        Some(_) => require!(market.is_open_time_reached(current_timestamp),MarketRegistryError::MarketNotYetOpen),
+        None => require!(market.creator == ctx.accounts.admin.key(),MarketRegistryError::Unauthorized)
+    }
 
     msg!("Market opened: {:?}", market.market_id);
-    msg!("State: {:?} -> {:?}", old_state, market.state);
-
-    emit!(MarketStateChanged{
-        market_id : market.market_id,
-        market_address : market_address,
-        old_state : old_state,
-        new_state : market.state,
-        timestamp : current_timestamp
-    });
+    market.transition(market_address,MarketState::Open,ctx.accounts.admin.key(),current_timestamp)?;
     Ok(())
 }
\ No newline at end of file
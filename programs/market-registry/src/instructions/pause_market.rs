@@ -2,7 +2,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketCancelled, state::{Market,MarketState}
+use crate::{constants::{ADMIN_SEED, MARKET_SEED}, error::MarketRegistryError, state::{AdminAuthority, Market, MarketState}
 };
 
 #[derive(Accounts)]
@@ -10,12 +10,19 @@ use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketCan
 pub struct PauseMarket<'info>{
     #[account(mut)]
     pub admin : Signer<'info>,
+    // protocol admin, creators cannot pause, resume or cancel markets
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
 
     #[account(
         mut ,
         seeds = [MARKET_SEED,market.market_id.as_ref()],
-        bump = market.bump,
-        constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+        bump = market.bump
     )]
     pub market  : Account<'info,Market>
 }
@@ -28,18 +35,7 @@ pub fn handler(ctx:Context<PauseMarket>)->Result<()>{
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
 
-    require!(market.state == MarketState::Open,MarketRegistryError::InvalidMarketState);
-
-    let old_state = market.state;
-    market.state = MarketState::Paused;
-
     msg!("Market paused: {:?}", market.market_id);
-    msg!("State: {:?} -> {:?}", old_state, market.state);
-
-    emit!(MarketCancelled{
-        market_id : market.market_id,
-        market_address : market_address,
-        cancelled_at : current_timestamp
-    });
+    market.transition(market_address,MarketState::Paused,ctx.accounts.admin.key(),current_timestamp)?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{constants::{ADMIN_SEED, MARKET_SEED}, error::MarketRegistryError, event::CreatorBondReleased, state::{AdminAuthority, Market}};
+
+// returns the creation bond once the market resolved, INVALID included
+#[derive(Accounts)]
+pub struct RefundCreatorBond<'info>{
+    pub creator : Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump,
+        constraint = market.creator == creator.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == config.bond_vault @ MarketRegistryError::InvalidBondAccount
+    )]
+    pub bond_vault : Account<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = creator_bond_account.owner == creator.key() @ MarketRegistryError::InvalidBondAccount,
+        constraint = creator_bond_account.mint == bond_vault.mint @ MarketRegistryError::InvalidBondAccount
+    )]
+    pub creator_bond_account : Account<'info,TokenAccount>,
+
+    pub token_program : Program<'info,Token>
+}
+
+pub fn handler(ctx:Context<RefundCreatorBond>)->Result<()>{
+    let market_address = ctx.accounts.market.key();
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    let amount = market.creator_bond;
+    require!(amount > 0,MarketRegistryError::NoCreatorBond);
+    // cancelled markets keep the bond until the admin slashes it
+    require!(
+        market.is_resolved() && !market.is_cancelled(),
+        MarketRegistryError::BondNotRefundable
+    );
+
+    market.creator_bond = 0;
+
+    let config_seeds = &[
+        ADMIN_SEED,
+        &[ctx.accounts.config.bump]
+    ];
+    let config_signer = &[&config_seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            Transfer{
+                from : ctx.accounts.bond_vault.to_account_info(),
+                to : ctx.accounts.creator_bond_account.to_account_info(),
+                authority : ctx.accounts.config.to_account_info()
+            },
+            config_signer
+        ),
+        amount)?;
+
+    msg!("Creator bond refunded: {}", amount);
+
+    emit!(CreatorBondReleased{
+        market_address,
+        creator : market.creator,
+        amount,
+        slashed : false,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
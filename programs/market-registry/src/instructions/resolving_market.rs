@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{Market, MarketRegistryError, MarketState, MarketStateChanged, constants::MARKET_SEED};
+use crate::{Market, MarketRegistryError, MarketState, constants::MARKET_SEED};
 
 #[derive(Accounts)]
 
@@ -21,22 +21,13 @@ pub fn handler(ctx:Context<ResolvingMarket>)->Result<()>{
     let market_address = ctx.accounts.market.key();
     let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
-    require!(market.state == MarketState::Open,MarketRegistryError::InvalidMarketState);
-
-    let old_state = market.state;
-
-    market.state = MarketState::Resolving;
-
+    // OPEN or CLOSED (expired, cranked) markets move into resolution, a market the admin paused stays paused
+    require!(
+        matches!(market.state,MarketState::Open | MarketState::Close),
+        MarketRegistryError::InvalidMarketState
+    );
     msg!("Market Resolving: {:?}", market.market_id);
-    msg!("State: {:?} -> {:?}", old_state, market.state);
-
-    emit!(MarketStateChanged{
-        market_id: market.market_id,
-        market_address: market_address,
-        old_state,
-        new_state: market.state,
-        timestamp: clock.unix_timestamp,
-    });
+    market.transition(market_address,MarketState::Resolving,ctx.accounts.admin.key(),clock.unix_timestamp)?;
     
     Ok(())
 }
\ No newline at end of file
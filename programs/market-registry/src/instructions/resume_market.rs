@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketStateChanged, state::{Market,MarketState}
+use crate::{constants::{ADMIN_SEED, MARKET_SEED}, error::MarketRegistryError, state::{AdminAuthority, Market, MarketState}
 };
 
 #[derive(Accounts)]
@@ -8,11 +8,18 @@ use crate::{constants::MARKET_SEED, error::MarketRegistryError, event::MarketSta
 pub struct ResumeMarket<'info>{
     #[account(mut)]
     pub admin : Signer<'info>,
+    // protocol admin, creators cannot pause, resume or cancel markets
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
     #[account(
         mut,
         seeds = [MARKET_SEED,market.market_id.as_ref()],
-        bump = market.bump,
-        constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+        bump = market.bump
     )]
     pub market : Account<'info,Market>
 }
@@ -28,22 +35,9 @@ pub fn handler(ctx:Context<ResumeMarket>)->Result<()>{
 
     require!(market.state == MarketState::Paused,MarketRegistryError::InvalidMarketState);
 
-    require!(!market.is_experied(current_timestamp),MarketRegistryError::MarketExpired);
-
-    let old_state = market.state;
-
-    market.state = MarketState::Open;
+    require!(!market.is_trading_closed(current_timestamp),MarketRegistryError::MarketExpired);
 
     msg!("Market resumed: {:?}", market.market_id);
-    msg!("State: {:?} -> {:?}", old_state, market.state);
-
-    // Emit event
-    emit!(MarketStateChanged {
-        market_id: market.market_id,
-        market_address: market_address,
-        old_state,
-        new_state: market.state,
-        timestamp: current_timestamp,
-    });
+    market.transition(market_address,MarketState::Open,ctx.accounts.admin.key(),current_timestamp)?;
     Ok(())
 }
\ No newline at end of file
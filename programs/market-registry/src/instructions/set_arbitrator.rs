@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::ADMIN_SEED, error::MarketRegistryError, event::ArbitratorUpdated, state::AdminAuthority};
+
+// admin hands the arbitration of fully escalated disputes to another authority
+#[derive(Accounts)]
+pub struct SetArbitrator<'info>{
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>
+}
+
+pub fn handler(ctx:Context<SetArbitrator>,arbitrator:Pubkey)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(arbitrator != Pubkey::default(),MarketRegistryError::InvalidInput);
+
+    let previous_arbitrator = config.arbitrator;
+    config.arbitrator = arbitrator;
+
+    msg!("Arbitrator updated");
+    msg!("Previous: {}", previous_arbitrator);
+    msg!("New: {}", arbitrator);
+
+    emit!(ArbitratorUpdated{
+        admin : config.admin,
+        previous_arbitrator,
+        new_arbitrator : arbitrator,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{constants::{ADMIN_SEED, MARKET_SEED}, error::MarketRegistryError, event::CreatorBondReleased, state::{AdminAuthority, Market}};
+
+// sends the creation bond of a cancelled (malformed) market to the treasury
+#[derive(Accounts)]
+pub struct SlashCreatorBond<'info>{
+    pub admin : Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump
+    )]
+    pub market : Account<'info,Market>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == config.bond_vault @ MarketRegistryError::InvalidBondAccount
+    )]
+    pub bond_vault : Account<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = bond_treasury.key() == config.bond_treasury @ MarketRegistryError::InvalidBondAccount
+    )]
+    pub bond_treasury : Account<'info,TokenAccount>,
+
+    pub token_program : Program<'info,Token>
+}
+
+pub fn handler(ctx:Context<SlashCreatorBond>)->Result<()>{
+    let market_address = ctx.accounts.market.key();
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    let amount = market.creator_bond;
+    require!(amount > 0,MarketRegistryError::NoCreatorBond);
+    // an ordinary INVALID resolution is not the creator's fault, only markets the admin cancelled are slashed
+    require!(market.is_cancelled(),MarketRegistryError::BondNotSlashable);
+
+    market.creator_bond = 0;
+
+    let config_seeds = &[
+        ADMIN_SEED,
+        &[ctx.accounts.config.bump]
+    ];
+    let config_signer = &[&config_seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            Transfer{
+                from : ctx.accounts.bond_vault.to_account_info(),
+                to : ctx.accounts.bond_treasury.to_account_info(),
+                authority : ctx.accounts.config.to_account_info()
+            },
+            config_signer
+        ),
+        amount)?;
+
+    msg!("Creator bond slashed: {}", amount);
+
+    emit!(CreatorBondReleased{
+        market_address,
+        creator : market.creator,
+        amount,
+        slashed : true,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::ADMIN_SEED, error::MarketRegistryError, event::AdminTransferStarted, state::AdminAuthority};
+
+// step 1 of the admin handover: current admin nominates the next one
+// nothing changes until the nominee calls accept_admin, calling again replaces the nominee
+#[derive(Accounts)]
+pub struct TransferAdmin<'info>{
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>
+}
+
+pub fn handler(ctx:Context<TransferAdmin>,new_admin:Pubkey)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    config.pending_admin = Some(new_admin);
+
+    msg!("Admin transfer started");
+    msg!("Current admin: {}", config.admin);
+    msg!("Pending admin: {}", new_admin);
+
+    emit!(AdminTransferStarted{
+        current_admin : config.admin,
+        pending_admin : new_admin,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
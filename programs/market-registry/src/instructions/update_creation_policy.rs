@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::ADMIN_SEED, error::MarketRegistryError, event::CreationPolicyUpdated, state::{AdminAuthority, CreationMode}};
+
+// switches between admin-only and permissionless creation and sets the per-creator cooldown
+#[derive(Accounts)]
+pub struct UpdateCreationPolicy<'info>{
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>
+}
+
+pub fn handler(ctx:Context<UpdateCreationPolicy>,creation_mode:CreationMode,creation_cooldown:i64)->Result<()>{
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(creation_cooldown >= 0,MarketRegistryError::InvalidInput);
+
+    config.creation_mode = creation_mode;
+    config.creation_cooldown = creation_cooldown;
+
+    msg!("Creation policy updated");
+    msg!("Mode: {:?}", creation_mode);
+    msg!("Cooldown: {} seconds", creation_cooldown);
+
+    emit!(CreationPolicyUpdated{
+        admin : config.admin,
+        creation_mode,
+        creation_bond : config.creation_bond,
+        creation_cooldown,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
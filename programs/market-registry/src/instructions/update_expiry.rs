@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{Market, MarketExpiryUpdated, MarketRegistryError, MarketState, UpdateExpiryParams, constants::MARKET_SEED, validate_expiry};
+
+// creator moves the expiry of a postponed (or brought forward) event
+// a market closed at its trading close but not expired yet reopens for trading
+#[derive(Accounts)]
+pub struct UpdateExpiry<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED,market.market_id.as_ref()],
+        bump = market.bump,
+        constraint = market.creator == admin.key() @ MarketRegistryError::Unauthorized
+    )]
+    pub market : Account<'info,Market>
+}
+
+pub fn handler(ctx:Context<UpdateExpiry>,params:UpdateExpiryParams)->Result<()>{
+    let market_address = ctx.accounts.market.key();
+    let market = &mut ctx.accounts.market;
+
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    require!(
+        matches!(market.state,MarketState::Open | MarketState::Paused | MarketState::Close),
+        MarketRegistryError::InvalidMarketState
+    );
+    require!(!market.is_experied(current_timestamp),MarketRegistryError::MarketExpired);
+
+    validate_expiry(params.expire_at,current_timestamp)?;
+
+    let old_expire_at = market.expire_at;
+    let delta = params.expire_at.checked_sub(old_expire_at).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+
+    // trading close keeps its distance to expiry (kickoff moves with the event)
+    if let Some(trading_close_at) = market.trading_close_at {
+        let new_trading_close_at = trading_close_at.checked_add(delta).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+        require!(new_trading_close_at>current_timestamp,MarketRegistryError::InvalidTradingCloseTime);
+        market.trading_close_at = Some(new_trading_close_at);
+    }
+
+    market.expire_at = params.expire_at;
+    market.postponement_hold = params.hold_for_postponement;
+
+    if market.state == MarketState::Close {
+        market.transition(market_address,MarketState::Open,ctx.accounts.admin.key(),current_timestamp)?;
+    }
+
+    let resolution_window_end = market.resolution_window_end();
+
+    msg!("Market expiry updated: {:?}", market.market_id);
+    msg!("Expiry: {} -> {}", old_expire_at, market.expire_at);
+    msg!("Postponement hold: {}", market.postponement_hold);
+    msg!("Resolution window ends at: {}", resolution_window_end);
+
+    emit!(MarketExpiryUpdated {
+        market_id: market.market_id,
+        market_address,
+        old_expire_at,
+        new_expire_at: market.expire_at,
+        trading_close_at: market.trading_close_at,
+        postponement_hold: market.postponement_hold,
+        resolution_window_end,
+        timestamp: current_timestamp,
+    });
+    Ok(())
+}
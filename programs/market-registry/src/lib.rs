@@ -6,12 +6,14 @@ pub mod instructions;
 pub mod constants;
 pub mod error;
 pub mod event;
+pub mod utils;
 
 pub use instructions::*;
 pub use state::*;
 pub use constants::*;
 pub use error::*;
 pub use event::*;
+pub use utils::*;
 
 declare_id!("2bRruB58Pk39PRz2wpAhSjURJMKpWoZdpiozzRLruqyU");
 #[program]
@@ -21,10 +23,50 @@ pub mod market_registry{
 
     use super::*;
 
+    pub fn initialize_config(ctx:Context<InitializeConfig>)->Result<()>{
+        instructions::initialize_config::handler(ctx)
+    }
+
+    pub fn transfer_admin(ctx:Context<TransferAdmin>,new_admin:Pubkey)->Result<()>{
+        instructions::transfer_admin::handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx:Context<AcceptAdmin>)->Result<()>{
+        instructions::accept_admin::handler(ctx)
+    }
+
+    pub fn set_arbitrator(ctx:Context<SetArbitrator>,arbitrator:Pubkey)->Result<()>{
+        instructions::set_arbitrator::handler(ctx, arbitrator)
+    }
+
+    pub fn update_creation_policy(ctx:Context<UpdateCreationPolicy>,creation_mode:CreationMode,creation_cooldown:i64)->Result<()>{
+        instructions::update_creation_policy::handler(ctx, creation_mode, creation_cooldown)
+    }
+
+    pub fn configure_creator_bond(ctx:Context<ConfigureCreatorBond>,creation_bond:u64)->Result<()>{
+        instructions::configure_creator_bond::handler(ctx, creation_bond)
+    }
+
+    pub fn refund_creator_bond(ctx:Context<RefundCreatorBond>)->Result<()>{
+        instructions::refund_creator_bond::handler(ctx)
+    }
+
+    pub fn slash_creator_bond(ctx:Context<SlashCreatorBond>)->Result<()>{
+        instructions::slash_creator_bond::handler(ctx)
+    }
+
     pub fn initialize_market(ctx:Context<MarketInitialize>,params : InitializeMarketParams)->Result<()>{
         instructions::initialize_market::handler(ctx, params)
     }
     
+    pub fn initialize_scalar_market(ctx:Context<MarketInitialize>,params : InitializeMarketParams,bounds:ScalarBounds)->Result<()>{
+        instructions::initialize_scalar_market::handler(ctx, params, bounds)
+    }
+
+    pub fn initialize_categorical_market<'info>(ctx:Context<'_, '_, 'info, 'info, CategoricalMarketInitialize<'info>>,params : InitializeMarketParams,outcome_labels:Vec<String>)->Result<()>{
+        instructions::initialize_categorical_market::handler(ctx, params, outcome_labels)
+    }
+
     pub fn open_market(ctx:Context<OpenMarket>)->Result<()>{
         instructions::open_market::handler(ctx)
     }
@@ -41,6 +83,10 @@ pub mod market_registry{
         instructions::finalize_market::handler(ctx, outcome)
     }
 
+    pub fn extend_resolution_window(ctx:Context<ExtendResolutionWindow>, new_end : i64)->Result<()>{
+        instructions::extend_resolution_window::handler(ctx, new_end)
+    }
+
     pub fn cancel_market(ctx:Context<CancelMarket>)->Result<()>{
         instructions::cancel_market::handler(ctx)
     }
@@ -53,6 +99,10 @@ pub mod market_registry{
         instructions::update_market_metadata::handler(ctx, params)
     }
 
+    pub fn update_expiry(ctx:Context<UpdateExpiry>,params:UpdateExpiryParams)->Result<()>{
+        instructions::update_expiry::handler(ctx, params)
+    }
+
     pub fn assert_market_open(ctx:Context<AssertMarketOpen>)->Result<()>{
         instructions::assert_market_open::handler(ctx)
     }
@@ -65,6 +115,14 @@ pub mod market_registry{
         instructions::assert_market_expired::handler(ctx)
     }
 
+    pub fn close_market(ctx:Context<CloseMarket>)->Result<()>{
+        instructions::close_market::handler(ctx)
+    }
+
+    pub fn crank_expire(ctx:Context<CrankExpire>)->Result<()>{
+        instructions::crank_expire::handler(ctx)
+    }
+
     pub fn emergency_finalize_market(
         ctx: Context<EmergencyFinalizeMarket>,
         outcome: ResultOutcome,
@@ -1,7 +1,9 @@
 
 use anchor_lang::prelude::*;
 use crate::error::MarketRegistryError;
+use crate::event::MarketStateChanged;
 use crate::constants::*;
+use crate::utils::whole_tokens;
 
 #[account]
 pub struct Market{
@@ -31,7 +33,33 @@ pub struct Market{
     pub resolution_outcome:Option<ResultOutcome>,
     pub resolved_at : Option<i64>,
 
-    pub bump : u8
+    pub bump : u8,
+    // 2 for binary (YES/NO) markets, 3..=MAX_OUTCOMES for categorical markets
+    pub outcome_count : u8,
+    // one mint per outcome, binary markets store [yes_token_mint, no_token_mint]
+    pub outcome_mints : Vec<Pubkey>,
+
+    pub outcome_labels : Vec<String>,
+    // set for scalar markets -> YES mint is LONG, NO mint is SHORT
+    pub scalar_bounds : Option<ScalarBounds>,
+    // bond posted at creation (bond mint units), zeroed once refunded or slashed
+    pub creator_bond : u64,
+    // SPL Token or Token-2022, shared by every outcome mint of the market
+    pub outcome_token_program : Pubkey,
+    // ring buffer of the last MAX_STATE_HISTORY transitions, slot = state_transition_count % MAX_STATE_HISTORY
+    pub state_history : Vec<StateTransition>,
+    // every transition since creation (also the ring buffer write cursor)
+    pub state_transition_count : u32,
+    // scheduled opening, anyone may call open_market from then on (None = creator opens manually)
+    pub open_at : Option<i64>,
+    // end of trading before expiry, e.g. kickoff of a sports event (None = trade until expire_at)
+    pub trading_close_at : Option<i64>,
+    // postponed event -> resolution window is extended by POSTPONEMENT_HOLD_EXTENSION
+    pub postponement_hold : bool,
+    // added by the resolution adapter when a reopened proposal round needs time past the configured window
+    pub resolution_window_extension : i64,
+    // fixed at creation, copied into the resolution proposal
+    pub resolution_config : ResolutionConfig
 }
 
 impl Market {
@@ -49,26 +77,149 @@ impl Market {
         32 + // escrow_vault
         32 + // resolution_adapter
         4 + MAX_RESOLUTION_SOURCE_LENGTH + // resolution_source
-        1 + 1 + // resolution_outcome (Option<enum>)
+        1 + 1 + 8 + // resolution_outcome (Option<enum>, largest variant carries an i64)
         1 + 8 + // resolved_at (Option<i64>)
-        1; // bum
+        1 + // bum
+        1 + // outcome_count
+        4 + (MAX_OUTCOMES * 32) + // outcome_mints
+        4 + (MAX_OUTCOMES * (4 + MAX_OUTCOME_LABEL_LENGTH)) + // outcome_labels
+        1 + ScalarBounds::LEN + // scalar_bounds (Option)
+        8 + // creator_bond
+        32 + // outcome_token_program
+        4 + (MAX_STATE_HISTORY * StateTransition::LEN) + // state_history
+        4 + // state_transition_count
+        1 + 8 + // open_at (Option<i64>)
+        1 + 8 + // trading_close_at (Option<i64>)
+        1 + // postponement_hold
+        8 + // resolution_window_extension
+        ResolutionConfig::LEN; // resolution_config
 
 
         pub fn is_experied(&self,current_timestamp:i64)->bool{
             current_timestamp>=self.expire_at
         }
 
-        pub fn can_trade(&self)->bool{
-            matches!(self.state,MarketState::Open)         
+        // last second of trading is trading_ends_at - 1
+        pub fn trading_ends_at(&self)->i64{
+            self.trading_close_at.unwrap_or(self.expire_at)
+        }
+
+        pub fn is_trading_closed(&self,current_timestamp:i64)->bool{
+            current_timestamp >= self.trading_ends_at()
+        }
+
+        pub fn is_open_time_reached(&self,current_timestamp:i64)->bool{
+            match self.open_at {
+                Some(open_at) => current_timestamp >= open_at,
+                None => true
+            }
+        }
+
+        // an OPEN market only trades inside [open_at, trading_ends_at), even before crank_expire moved it to CLOSED
+        pub fn can_trade(&self,current_timestamp:i64)->bool{
+            matches!(self.state,MarketState::Open)
+                && self.is_open_time_reached(current_timestamp)
+                && !self.is_trading_closed(current_timestamp)
         }
 
+        // final outcome is set, a cancelled market settles as Invalid
         pub fn is_resolved(&self)->bool{
-            matches!(self.state,MarketState::Resolved)
+            matches!(self.state,MarketState::Resolved | MarketState::Cancelled)
+        }
+
+        pub fn is_cancelled(&self)->bool{
+            matches!(self.state,MarketState::Cancelled)
+        }
+
+        // a new market starts in Created with an empty history, the first entry is its first real transition
+        pub fn init_state_history(&mut self){
+            self.state = MarketState::Created;
+            self.state_history = Vec::new();
+            self.state_transition_count = 0;
+        }
+
+        // Resolved is only reachable from Resolving, finalization from an open or closed market passes through it
+        pub fn resolve(&mut self,market_address:Pubkey,outcome:ResultOutcome,actor:Pubkey,timestamp:i64)->Result<()>{
+            if self.state != MarketState::Resolving {
+                self.transition(market_address,MarketState::Resolving,actor,timestamp)?;
+            }
+            self.resolution_outcome = Some(outcome);
+            self.resolved_at = Some(timestamp);
+            self.transition(market_address,MarketState::Resolved,actor,timestamp)
+        }
+
+        // single entry point for every state change: checks the edge, records it and emits MarketStateChanged
+        pub fn transition(&mut self,market_address:Pubkey,new_state:MarketState,actor:Pubkey,timestamp:i64)->Result<()>{
+            let old_state = self.state;
+            require!(old_state.can_transition_to(new_state),MarketRegistryError::InvalidMarketState);
+
+            let entry = StateTransition{
+                old_state,
+                new_state,
+                actor,
+                timestamp
+            };
+            if self.state_history.len() < MAX_STATE_HISTORY {
+                self.state_history.push(entry);
+            } else {
+                let slot = self.state_transition_count as usize % MAX_STATE_HISTORY;
+                self.state_history[slot] = entry;
+            }
+            self.state_transition_count = self.state_transition_count
+                .checked_add(1)
+                .ok_or(MarketRegistryError::ArithmeticOverflow)?;
+            self.state = new_state;
+
+            msg!("State: {:?} -> {:?}", old_state, new_state);
+            emit!(MarketStateChanged{
+                market_id : self.market_id,
+                market_address,
+                old_state,
+                new_state,
+                timestamp
+            });
+            Ok(())
+        }
+        pub fn is_categorical(&self)->bool{
+            self.outcome_count as usize >= MIN_CATEGORICAL_OUTCOMES
+        }
+
+        pub fn is_scalar(&self)->bool{
+            self.scalar_bounds.is_some()
+        }
+
+        // binary markets resolve to Yes/No/Invalid, categorical markets to Index(i)/Invalid,
+        // scalar markets to Scalar(price)/Invalid
+        pub fn is_valid_outcome(&self,outcome:ResultOutcome)->bool{
+            match outcome {
+                ResultOutcome::Invalid => true,
+                ResultOutcome::Yes | ResultOutcome::No => !self.is_categorical() && !self.is_scalar(),
+                ResultOutcome::Index(index) => self.is_categorical() && index < self.outcome_count,
+                ResultOutcome::Scalar(_) => self.is_scalar()
+            }
+        }
+
+        // resolved and past the grace period -> rent of market-scoped accounts can be reclaimed
+        pub fn is_closable(&self,current_timestamp:i64)->bool{
+            match (self.is_resolved(),self.resolved_at) {
+                (true,Some(resolved_at)) => current_timestamp >= resolved_at.saturating_add(CLOSE_GRACE_PERIOD),
+                _ => false
+            }
+        }
+
+        pub fn resolution_window_end(&self)->i64{
+            let window = if self.postponement_hold {
+                self.resolution_config.resolution_window.saturating_add(POSTPONEMENT_HOLD_EXTENSION)
+            } else {
+                self.resolution_config.resolution_window
+            };
+            self.expire_at.checked_add(window)
+                .and_then(|end| end.checked_add(self.resolution_window_extension))
+                .unwrap_or(i64::MAX)
         }
-        pub fn in_resolution_window(&self,current_timestamp:i64)->bool{
-            let window_end = self.expire_at.checked_add(RESOLUTION_WINDOW).unwrap_or(i64::MAX);
 
-            current_timestamp>= self.expire_at && current_timestamp<=window_end
+        pub fn in_resolution_window(&self,current_timestamp:i64)->bool{
+            current_timestamp>= self.expire_at && current_timestamp<=self.resolution_window_end()
         }
 }
 #[account]
@@ -81,11 +232,97 @@ pub struct InitializeMarketParams{
     pub expire_at:i64,
     // Source like pyth and so on 
     pub resolution_source:String,
+    // oracle flow of the resolution proposal created with the market
+    pub resolution_category:ResolutionCategory,
+    // optional schedule: permissionless opening and an earlier end of trading
+    pub open_at:Option<i64>,
+    pub trading_close_at:Option<i64>,
+    // None = protocol defaults
+    pub resolution_config:Option<ResolutionConfig>,
+}
+
+// resolution timing and oracle tolerances of one market (e.g. short dispute window for a 15-minute BTC market)
+// same layout is stored on the resolution proposal, which enforces everything but resolution_window
+#[derive(AnchorSerialize,AnchorDeserialize,Clone,Copy,PartialEq,Eq,Debug)]
+pub struct ResolutionConfig{
+    // after expiry, finalize_market is only accepted inside it
+    pub resolution_window : i64,
+    pub dispute_window : i64,
+    // added to the dispute deadline on every dispute
+    pub dispute_extension : i64,
+    // bond mint units
+    pub min_proposal_bond : u64,
+    pub max_price_deviation_bps : u16,
+    pub max_oracle_staleness : i64,
+    // disputes (each at least doubling the bond) before stakers vote
+    pub max_escalation_rounds : u8,
+    // commit phase and reveal phase of the staker vote, each this long
+    pub vote_duration : i64,
+    // protocol cut of the bonds slashed at finalization, the rest goes to the correct bonds
+    pub slash_fee_bps : u16,
+    // a proposal round without a proposal for this long can be reopened
+    pub proposal_window : i64
+}
+
+impl ResolutionConfig{
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 2 + 8 + 1 + 8 + 2 + 8;
+
+    // protocol defaults, used when a market is created without its own config
+    pub fn default_for(collateral_decimals:u8)->Result<Self>{
+        Ok(ResolutionConfig{
+            resolution_window : RESOLUTION_WINDOW,
+            dispute_window : DISPUTE_WINDOW_SECONDS,
+            dispute_extension : DISPUTE_EXTENSION_SECONDS,
+            min_proposal_bond : whole_tokens(MIN_PROPOSAL_BOND,collateral_decimals).ok_or(MarketRegistryError::ArithmeticOverflow)?,
+            max_price_deviation_bps : MAX_PRICE_DEVIATION_BPS,
+            max_oracle_staleness : MAX_ORACLE_STALENESS_SECONDS,
+            max_escalation_rounds : ESCALATION_ROUNDS,
+            vote_duration : VOTE_DURATION,
+            slash_fee_bps : SLASH_FEE_BPS,
+            proposal_window : PROPOSAL_WINDOW
+        })
+    }
 
+    // bond limits are in whole tokens of the bond mint (the market collateral)
+    pub fn validate(&self,collateral_decimals:u8)->Result<()>{
+        let min_bond = whole_tokens(*PROPOSAL_BOND_RANGE.start(),collateral_decimals).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+        let max_bond = whole_tokens(*PROPOSAL_BOND_RANGE.end(),collateral_decimals).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+
+        require!(RESOLUTION_WINDOW_RANGE.contains(&self.resolution_window),MarketRegistryError::InvalidResolutionConfig);
+        require!(DISPUTE_WINDOW_RANGE.contains(&self.dispute_window),MarketRegistryError::InvalidResolutionConfig);
+        require!(DISPUTE_EXTENSION_RANGE.contains(&self.dispute_extension),MarketRegistryError::InvalidResolutionConfig);
+        require!((min_bond..=max_bond).contains(&self.min_proposal_bond),MarketRegistryError::InvalidResolutionConfig);
+        require!(PRICE_DEVIATION_BPS_RANGE.contains(&self.max_price_deviation_bps),MarketRegistryError::InvalidResolutionConfig);
+        require!(ORACLE_STALENESS_RANGE.contains(&self.max_oracle_staleness),MarketRegistryError::InvalidResolutionConfig);
+        require!(ESCALATION_ROUNDS_RANGE.contains(&self.max_escalation_rounds),MarketRegistryError::InvalidResolutionConfig);
+        require!(VOTE_DURATION_RANGE.contains(&self.vote_duration),MarketRegistryError::InvalidResolutionConfig);
+        require!(SLASH_FEE_BPS_RANGE.contains(&self.slash_fee_bps),MarketRegistryError::InvalidResolutionConfig);
+        require!(PROPOSAL_WINDOW_RANGE.contains(&self.proposal_window),MarketRegistryError::InvalidResolutionConfig);
+
+        // a proposal right at expiry that is escalated through every round and voted on still finalizes inside the window
+        let round_duration = self.round_duration().ok_or(MarketRegistryError::ArithmeticOverflow)?;
+        require!(round_duration <= self.resolution_window,MarketRegistryError::InvalidResolutionConfig);
+        Ok(())
+    }
+
+    // longest a proposal can take to settle: dispute window, every escalation round and both vote phases
+    pub fn round_duration(&self)->Option<i64>{
+        self.dispute_extension
+            .checked_mul(self.max_escalation_rounds as i64)
+            .and_then(|extensions| extensions.checked_add(self.dispute_window))
+            .and_then(|period| period.checked_add(self.vote_duration.checked_mul(2)?))
+    }
+}
+
+// same layout as resolution_adapter::MarketCategory, forwarded to initialize_resolution
+#[derive(AnchorSerialize,AnchorDeserialize,Clone,Copy,PartialEq,Eq,Debug)]
+pub enum ResolutionCategory{
+    Crypto,
+    Sports
 }
 
 impl InitializeMarketParams{
-    pub fn validate(&self,current_timestamp:i64)->Result<()>{
+    pub fn validate(&self,current_timestamp:i64,collateral_decimals:u8)->Result<()>{
         // check for question length 0 
         require!(
             !self.question.trim().is_empty(),
@@ -100,19 +337,97 @@ impl InitializeMarketParams{
         require!(self.category.len()<=MAX_CATEGORY_LENGTH,MarketRegistryError::CategoryTooLong);
         require!(self.resolution_source.len() <= MAX_RESOLUTION_SOURCE_LENGTH,MarketRegistryError::ResolutionSourceTooLong);
         
-        // 
-        require!(self.expire_at>current_timestamp,MarketRegistryError::InvalidExpiryTimestamp);
+        validate_expiry(self.expire_at,current_timestamp)?;
+        self.resolution_config(collateral_decimals)?.validate(collateral_decimals)?;
 
-        let duration = self.expire_at.checked_sub(current_timestamp).ok_or(MarketRegistryError::ArithmeticOverflow)?;
-        require!(duration >= MIN_EXPIRY_DURATION,MarketRegistryError::ExpiryTooShort);
+        let trading_ends_at = self.trading_close_at.unwrap_or(self.expire_at);
+        if let Some(open_at) = self.open_at {
+            require!(
+                open_at > current_timestamp && open_at < trading_ends_at,
+                MarketRegistryError::InvalidOpenTime
+            );
+        }
+        if let Some(trading_close_at) = self.trading_close_at {
+            require!(
+                trading_close_at > current_timestamp && trading_close_at <= self.expire_at,
+                MarketRegistryError::InvalidTradingCloseTime
+            );
+        }
 
-        require!(
-            duration <= MAX_EXPIRY_DURATION,MarketRegistryError::ExpiryTooLong
-        );
+        Ok(())
+    }
+
+    pub fn resolution_config(&self,collateral_decimals:u8)->Result<ResolutionConfig>{
+        match self.resolution_config {
+            Some(config) => Ok(config),
+            None => ResolutionConfig::default_for(collateral_decimals)
+        }
+    }
+}
+// lower/upper price of a scalar market, in oracle price units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScalarBounds {
+    pub lower : i64,
+    pub upper : i64
+}
 
+impl ScalarBounds {
+    pub const LEN: usize = 8 + 8;
+
+    pub fn validate(&self)->Result<()>{
+        require!(self.lower < self.upper,MarketRegistryError::InvalidScalarBounds);
         Ok(())
     }
+
+    // collateral paid to one LONG token -> linear between the bounds, clamped outside them
+    // SHORT receives the remainder so LONG + SHORT always equals the collateral of a pair
+    pub fn long_payout(&self,price:i64,collateral_per_pair:u64)->Result<u64>{
+        let clamped = price.clamp(self.lower, self.upper);
+        let distance = (clamped as i128 - self.lower as i128) as u128;
+        let range = (self.upper as i128 - self.lower as i128) as u128;
+
+        let payout = distance
+            .checked_mul(collateral_per_pair as u128)
+            .ok_or(MarketRegistryError::ArithmeticOverflow)?
+            .checked_div(range)
+            .ok_or(MarketRegistryError::InvalidScalarBounds)?;
+
+        Ok(payout as u64)
+    }
+}
+
+pub fn validate_outcome_labels(labels:&[String])->Result<()>{
+    require!(
+        labels.len() >= MIN_CATEGORICAL_OUTCOMES && labels.len() <= MAX_OUTCOMES,
+        MarketRegistryError::InvalidOutcomeCount
+    );
+    for label in labels {
+        require!(!label.trim().is_empty(),MarketRegistryError::InvalidOutcomeLabel);
+        require!(label.len()<=MAX_OUTCOME_LABEL_LENGTH,MarketRegistryError::InvalidOutcomeLabel);
+    }
+    Ok(())
 }
+
+// expiry has to lie MIN_EXPIRY_DURATION..=MAX_EXPIRY_DURATION ahead, at creation and on every update
+pub fn validate_expiry(expire_at:i64,current_timestamp:i64)->Result<()>{
+    require!(expire_at>current_timestamp,MarketRegistryError::InvalidExpiryTimestamp);
+
+    let duration = expire_at.checked_sub(current_timestamp).ok_or(MarketRegistryError::ArithmeticOverflow)?;
+    require!(duration >= MIN_EXPIRY_DURATION,MarketRegistryError::ExpiryTooShort);
+
+    require!(
+        duration <= MAX_EXPIRY_DURATION,MarketRegistryError::ExpiryTooLong
+    );
+    Ok(())
+}
+
+#[derive(AnchorSerialize,AnchorDeserialize,Clone)]
+pub struct UpdateExpiryParams{
+    pub expire_at : i64,
+    // keep the market resolvable for POSTPONEMENT_HOLD_EXTENSION longer after the new expiry
+    pub hold_for_postponement : bool
+}
+
 #[derive(AnchorSerialize,AnchorDeserialize,Clone)]
 pub struct  UpdateMarketMetaDataParams{
     pub description : Option<String>,
@@ -136,14 +451,75 @@ impl UpdateMarketMetaDataParams{
 pub struct AdminAuthority {
     /// Current admin public key
     pub admin: Pubkey,
+
+    /// Admin nominated by transfer_admin, takes over once it calls accept_admin
+    pub pending_admin: Option<Pubkey>,
     
     /// PDA bump
     pub bump: u8,
+
+    /// Who may create markets
+    pub creation_mode: CreationMode,
+
+    /// Bond every creator posts per market (0 = no bond)
+    pub creation_bond: u64,
+
+    /// Minimum seconds between two markets of the same creator (0 = no limit)
+    pub creation_cooldown: i64,
+
+    /// Token account (owned by this PDA) holding posted creator bonds
+    pub bond_vault: Pubkey,
+
+    /// Receives slashed creator bonds
+    pub bond_treasury: Pubkey,
+
+    /// Rules on resolution proposals escalated through every dispute round
+    pub arbitrator: Pubkey,
 }
 
 impl AdminAuthority {
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
+        1 + 32 + // pending_admin
+        1 + // bump
+        1 + // creation_mode
+        8 + // creation_bond
+        8 + // creation_cooldown
+        32 + // bond_vault
+        32 + // bond_treasury
+        32; // arbitrator
+
+    pub fn can_create_market(&self, creator: &Pubkey) -> bool {
+        match self.creation_mode {
+            CreationMode::AdminOnly => self.admin == *creator,
+            CreationMode::Permissionless => true,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CreationMode {
+    AdminOnly,
+    Permissionless,
+}
+
+// per-creator bookkeeping for the creation rate limit
+#[account]
+pub struct CreatorStats {
+    pub creator: Pubkey,
+
+    pub markets_created: u64,
+
+    pub last_created_at: i64,
+
+    pub bump: u8,
+}
+
+impl CreatorStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 + // markets_created
+        8 + // last_created_at
         1; // bump
 }
 
@@ -151,7 +527,23 @@ impl AdminAuthority {
 pub enum  ResultOutcome {
     Yes,
     No,
-    Invalid
+    Invalid,
+    // winning option of a categorical market
+    Index(u8),
+    // resolved price of a scalar market (same units as its ScalarBounds)
+    Scalar(i64)
+}
+
+impl ResultOutcome {
+    // position of the winning outcome mint (Yes = 0, No = 1), None for Invalid and Scalar
+    pub fn outcome_index(&self)->Option<u8>{
+        match self {
+            ResultOutcome::Yes => Some(0),
+            ResultOutcome::No => Some(1),
+            ResultOutcome::Index(index) => Some(*index),
+            ResultOutcome::Invalid | ResultOutcome::Scalar(_) => None
+        }
+    }
 }
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum MarketState{
@@ -160,5 +552,38 @@ pub enum MarketState{
     Created,
     Resolved,
     Resolving,
-    Paused
+    Paused,
+    // cancelled by the admin, settles as Invalid
+    Cancelled
+}
+
+impl MarketState {
+    // transition table, Resolved and Cancelled are terminal
+    pub fn can_transition_to(self,next:MarketState)->bool{
+        use MarketState::*;
+        matches!(
+            (self,next),
+            (Created, Open) |
+            (Open, Paused) | (Paused, Open) |
+            (Open, Close) | (Paused, Close) |
+            // trading closed early, then the event was postponed (update_expiry)
+            (Close, Open) |
+            (Open | Paused | Close, Resolving) |
+            (Resolving, Resolved) |
+            (Created | Open | Paused | Close | Resolving, Cancelled)
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StateTransition{
+    pub old_state : MarketState,
+    pub new_state : MarketState,
+    // signer of the instruction that moved the market
+    pub actor : Pubkey,
+    pub timestamp : i64
+}
+
+impl StateTransition {
+    pub const LEN: usize = 1 + 1 + 32 + 8;
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+// amounts in msg! logs, shared by every program of the protocol
+
+// base units as a decimal of the mint, e.g. 1_500_000 with 6 decimals -> "1.5"
+pub fn format_amount(amount:u64,decimals:u8)->String{
+    let Some(unit) = 10u64.checked_pow(decimals as u32) else {
+        return amount.to_string();
+    };
+    let whole = amount / unit;
+    let fraction = amount % unit;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let digits = format!("{:0width$}", fraction, width = decimals as usize);
+    format!("{}.{}", whole, digits.trim_end_matches('0'))
+}
@@ -0,0 +1,5 @@
+pub mod format;
+pub mod units;
+
+pub use format::*;
+pub use units::*;
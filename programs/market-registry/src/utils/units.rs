@@ -0,0 +1,6 @@
+// protocol limits are set in whole tokens and scaled to each mint's decimals
+
+// whole tokens in base units of a mint, e.g. 1_000 with 6 decimals -> 1_000_000_000
+pub fn whole_tokens(tokens:u64,decimals:u8)->Option<u64>{
+    10u64.checked_pow(decimals as u32)?.checked_mul(tokens)
+}
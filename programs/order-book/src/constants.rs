@@ -0,0 +1,9 @@
+
+/// Seed for the per-market OrderBook PDA
+pub const ORDER_BOOK_SEED: &[u8] = b"order_book";
+
+/// Maximum resting orders per slab (YES bids, YES asks, NO bids, NO asks)
+pub const MAX_ORDERS_PER_SLAB: usize = 32;
+
+/// Minimum value of an order in pairs (price * quantity), so the slabs cannot be filled with dust
+pub const MIN_ORDER_VALUE_PAIRS: u64 = 10;
@@ -0,0 +1,51 @@
+// programs/order-book/src/error.rs
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum OrderBookError {
+    #[msg("Unauthorized: Only admin can perform this action")]
+    Unauthorized,
+
+    #[msg("Order books only support binary markets")]
+    UnsupportedMarket,
+
+    #[msg("Price must be between 1 and 999999 usdc base units")]
+    InvalidPrice,
+
+    #[msg("Quantity must be greater than 0")]
+    InvalidQuantity,
+
+    #[msg("Order value is below the minimum order size")]
+    OrderTooSmall,
+
+    #[msg("Order slab is full")]
+    SlabFull,
+
+    #[msg("Order not found")]
+    OrderNotFound,
+
+    #[msg("Orders do not cross")]
+    OrdersDoNotCross,
+
+    #[msg("No resting order crosses this immediate-or-cancel order")]
+    NoCrossingOrder,
+
+    #[msg("Only the best priced, oldest order of each side can be matched")]
+    NotBestOrder,
+
+    #[msg("Cannot match an order with itself")]
+    SameOrder,
+
+    #[msg("Token account does not belong to the order owner")]
+    InvalidTokenAccount,
+
+    #[msg("Escrow account does not match the order book")]
+    EscrowMismatch,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use escrow_vault::OutcomeSide;
+
+use crate::state::{MatchKind, OrderSide, OrderType};
+
+#[event]
+pub struct OrderBookInitialized {
+    pub order_book : Pubkey,
+
+    pub market : Pubkey,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct OrderPlaced {
+    pub order_book : Pubkey,
+
+    pub order_id : u64,
+
+    pub owner : Pubkey,
+
+    pub outcome : OutcomeSide,
+
+    pub side : OrderSide,
+
+    pub order_type : OrderType,
+
+    pub price : u64,
+
+    pub quantity : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub order_book : Pubkey,
+
+    pub order_id : u64,
+
+    pub owner : Pubkey,
+
+    pub refunded : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+pub struct OrdersMatched {
+    pub order_book : Pubkey,
+
+    pub maker_order_id : u64,
+
+    pub taker_order_id : u64,
+
+    pub kind : MatchKind,
+
+    pub quantity : u64,
+    // trade happens at the maker's price
+    pub maker_price : u64,
+
+    pub timestamp : i64
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{constants::ORDER_BOOK_SEED, error::OrderBookError, events::OrderCancelled, state::{OrderBook, OrderSide, OutcomeSide}};
+
+// removes a resting order and refunds what is still locked for it, only the owner can cancel
+#[derive(Accounts)]
+pub struct CancelOrder<'info>{
+    pub signer : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_BOOK_SEED,order_book.market.as_ref()],
+        bump = order_book.bump
+    )]
+    pub order_book : Box<Account<'info,OrderBook>>,
+
+    // refund goes back to the order owner, checked in the handler
+    #[account(mut)]
+    pub owner_token_account : Box<Account<'info,TokenAccount>>,
+
+    #[account(mut)]
+    pub escrow_token_account : Box<Account<'info,TokenAccount>>,
+
+    pub token_program : Program<'info,Token>
+}
+
+pub fn handler(ctx:Context<CancelOrder>,order_id:u64)->Result<()>{
+    let book_key = ctx.accounts.order_book.key();
+    let book = &mut ctx.accounts.order_book;
+    let clock = Clock::get()?;
+
+    let order = book.find(order_id)?;
+    require!(ctx.accounts.signer.key() == order.owner,OrderBookError::Unauthorized);
+
+    let expected_escrow = match (order.side,order.outcome) {
+        (OrderSide::Bid,_) => book.usdc_escrow,
+        (OrderSide::Ask,OutcomeSide::Yes) => book.yes_escrow,
+        (OrderSide::Ask,OutcomeSide::No) => book.no_escrow,
+    };
+    require!(ctx.accounts.escrow_token_account.key() == expected_escrow,OrderBookError::EscrowMismatch);
+    require!(
+        ctx.accounts.owner_token_account.owner == order.owner
+            && ctx.accounts.owner_token_account.mint == ctx.accounts.escrow_token_account.mint,
+        OrderBookError::InvalidTokenAccount
+    );
+
+    let refund = order.locked_amount()?;
+    book.remove(&order)?;
+
+    let market_key = book.market;
+    let book_seeds = &[
+        ORDER_BOOK_SEED,
+        market_key.as_ref(),
+        &[book.bump]
+    ];
+    let book_signer = &[&book_seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            Transfer{
+                from : ctx.accounts.escrow_token_account.to_account_info(),
+                to : ctx.accounts.owner_token_account.to_account_info(),
+                authority : book.to_account_info()
+            },
+            book_signer
+        ),
+        refund)?;
+
+    msg!("Order {} cancelled", order_id);
+    msg!("Refunded: {}", refund);
+
+    emit!(OrderCancelled{
+        order_book : book_key,
+        order_id,
+        owner : order.owner,
+        refunded : refund,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token::{Token, TokenAccount};
+use escrow_vault::program::EscrowVault as EscrowVaultProgram;
+use escrow_vault::EscrowVault;
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::ORDER_BOOK_SEED, error::OrderBookError, events::OrdersMatched, state::OrderBook, utils::MatchAccounts};
+
+// permissionless matcher for the best crossing orders, the older order is the maker and sets the price
+// settlement (direct, pair mint or merge) is shared with immediate-or-cancel orders, see utils::matching
+#[derive(Accounts)]
+pub struct CrankMatch<'info>{
+    pub cranker : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_BOOK_SEED,order_book.market.as_ref()],
+        bump = order_book.bump
+    )]
+    pub order_book : Box<Account<'info,OrderBook>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == order_book.vault @ OrderBookError::EscrowMismatch
+    )]
+    pub vault : Box<Account<'info,EscrowVault>>,
+
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == order_book.market @ OrderBookError::EscrowMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    pub escrow_vault_program : Program<'info,EscrowVaultProgram>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub usdc_vault : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    pub usdc_mint : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub yes_token_mint : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub no_token_mint : UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = usdc_escrow.key() == order_book.usdc_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub usdc_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = yes_escrow.key() == order_book.yes_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub yes_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = no_escrow.key() == order_book.no_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub no_escrow : Box<Account<'info,TokenAccount>>,
+
+    /// CHECK: validated via CPI (mint fee)
+    pub protocol_config : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI (mint fee)
+    #[account(mut)]
+    pub treasury : UncheckedAccount<'info>,
+
+    // usdc + outcome accounts of the first order's owner
+    #[account(mut)]
+    pub first_usdc : Box<Account<'info,TokenAccount>>,
+
+    #[account(mut)]
+    pub first_outcome : Box<Account<'info,TokenAccount>>,
+
+    // usdc + outcome accounts of the second order's owner
+    #[account(mut)]
+    pub second_usdc : Box<Account<'info,TokenAccount>>,
+
+    #[account(mut)]
+    pub second_outcome : Box<Account<'info,TokenAccount>>,
+
+    // escrows are classic spl accounts, so only vaults whose collateral and outcome tokens both live on spl-token can be matched
+    pub token_program : Program<'info,Token>
+}
+
+impl<'info> CrankMatch<'info> {
+    fn match_accounts(&self)->MatchAccounts<'info>{
+        MatchAccounts {
+            order_book : self.order_book.to_account_info(),
+            market_key : self.order_book.market,
+            book_bump : self.order_book.bump,
+            collateral_per_pair : self.order_book.collateral_per_pair,
+            collateral_decimals : self.vault.collateral_decimals,
+            yes_mint : self.vault.yes_token_mint,
+            no_mint : self.vault.no_token_mint,
+            vault : self.vault.to_account_info(),
+            market : self.market.to_account_info(),
+            market_registry_program : self.market_registry_program.to_account_info(),
+            escrow_vault_program : self.escrow_vault_program.to_account_info(),
+            usdc_vault : self.usdc_vault.to_account_info(),
+            usdc_mint : self.usdc_mint.to_account_info(),
+            yes_token_mint : self.yes_token_mint.to_account_info(),
+            no_token_mint : self.no_token_mint.to_account_info(),
+            usdc_escrow : self.usdc_escrow.to_account_info(),
+            yes_escrow : self.yes_escrow.to_account_info(),
+            no_escrow : self.no_escrow.to_account_info(),
+            protocol_config : self.protocol_config.to_account_info(),
+            treasury : self.treasury.to_account_info(),
+            token_program : self.token_program.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(ctx:Context<CrankMatch>,first_order_id:u64,second_order_id:u64)->Result<()>{
+    let book_key = ctx.accounts.order_book.key();
+    let clock = Clock::get()?;
+
+    require!(first_order_id != second_order_id,OrderBookError::SameOrder);
+
+    market_registry::cpi::assert_market_open(CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    ))?;
+
+    let first = ctx.accounts.order_book.find(first_order_id)?;
+    let second = ctx.accounts.order_book.find(second_order_id)?;
+
+    // price-time priority, nothing can be matched ahead of the head of its slab
+    require!(
+        ctx.accounts.order_book.is_best(&first) && ctx.accounts.order_book.is_best(&second),
+        OrderBookError::NotBestOrder
+    );
+
+    let matching = ctx.accounts.match_accounts();
+    let first_accounts = matching.owner_accounts(&first,&ctx.accounts.first_usdc.to_account_info(),&ctx.accounts.first_outcome.to_account_info())?;
+    let second_accounts = matching.owner_accounts(&second,&ctx.accounts.second_usdc.to_account_info(),&ctx.accounts.second_outcome.to_account_info())?;
+
+    // older order rests on the book first -> maker
+    let (maker,maker_accounts,taker,taker_accounts) = if first.id < second.id {
+        (first,first_accounts,second,second_accounts)
+    } else {
+        (second,second_accounts,first,first_accounts)
+    };
+
+    let quantity = maker.quantity.min(taker.quantity);
+    let kind = matching.settle(&maker,&maker_accounts,&taker,&taker_accounts,quantity)?;
+
+    let book = &mut ctx.accounts.order_book;
+    book.update_after_fill(&maker,quantity)?;
+    book.update_after_fill(&taker,quantity)?;
+
+    msg!("Matched order {} (maker) with {} (taker)", maker.id, taker.id);
+    msg!("Quantity: {} @ {} collateral", quantity, format_amount(maker.price, matching.collateral_decimals));
+
+    emit!(OrdersMatched{
+        order_book : book_key,
+        maker_order_id : maker.id,
+        taker_order_id : taker.id,
+        kind,
+        quantity,
+        maker_price : maker.price,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
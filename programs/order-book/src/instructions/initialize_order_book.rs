@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use escrow_vault::{EscrowVault, VAULT_SEED};
+use crate::{constants::ORDER_BOOK_SEED, error::OrderBookError, events::OrderBookInitialized, state::OrderBook};
+
+// creates the order book of a binary market, the protocol admin must then register
+// the book PDA as a settlement authority so crank_match can mint pairs
+#[derive(Accounts)]
+pub struct InitializeOrderBook<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    // registry protocol admin, the same key that manages the vault
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ OrderBookError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        seeds = [VAULT_SEED,vault.market.as_ref()],
+        bump = vault.bump,
+        seeds::program = escrow_vault::ID,
+        constraint = !vault.is_categorical() @ OrderBookError::UnsupportedMarket
+    )]
+    pub vault : Box<Account<'info,EscrowVault>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = OrderBook::LEN,
+        seeds = [ORDER_BOOK_SEED,vault.market.as_ref()],
+        bump
+    )]
+    pub order_book : Box<Account<'info,OrderBook>>,
+
+    #[account(
+        constraint = usdc_mint.key() == usdc_vault.mint @ OrderBookError::EscrowMismatch
+    )]
+    pub usdc_mint : Box<Account<'info,Mint>>,
+
+    #[account(
+        constraint = usdc_vault.key() == vault.usdc_vault @ OrderBookError::EscrowMismatch
+    )]
+    pub usdc_vault : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        constraint = yes_token_mint.key() == vault.yes_token_mint @ OrderBookError::EscrowMismatch
+    )]
+    pub yes_token_mint : Box<Account<'info,Mint>>,
+
+    #[account(
+        constraint = no_token_mint.key() == vault.no_token_mint @ OrderBookError::EscrowMismatch
+    )]
+    pub no_token_mint : Box<Account<'info,Mint>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = order_book
+    )]
+    pub usdc_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = yes_token_mint,
+        associated_token::authority = order_book
+    )]
+    pub yes_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = no_token_mint,
+        associated_token::authority = order_book
+    )]
+    pub no_escrow : Box<Account<'info,TokenAccount>>,
+
+    pub system_program : Program<'info,System>,
+    pub token_program : Program<'info,Token>,
+    pub associated_token_program : Program<'info,AssociatedToken>,
+}
+
+pub fn handler(ctx:Context<InitializeOrderBook>)->Result<()>{
+    let book_key = ctx.accounts.order_book.key();
+    let book = &mut ctx.accounts.order_book;
+    let clock = Clock::get()?;
+
+    book.market = ctx.accounts.vault.market;
+    book.vault = ctx.accounts.vault.key();
+    book.usdc_escrow = ctx.accounts.usdc_escrow.key();
+    book.yes_escrow = ctx.accounts.yes_escrow.key();
+    book.no_escrow = ctx.accounts.no_escrow.key();
+    book.next_order_id = 1;
+    book.yes_bids = Vec::new();
+    book.yes_asks = Vec::new();
+    book.no_bids = Vec::new();
+    book.no_asks = Vec::new();
+    book.bump = ctx.bumps.order_book;
+    book.collateral_per_pair = ctx.accounts.vault.collateral_per_pair;
+
+    msg!("Order book initialized");
+    msg!("Order book: {}", book_key);
+    msg!("Market: {}", book.market);
+
+    emit!(OrderBookInitialized{
+        order_book : book_key,
+        market : book.market,
+        timestamp : clock.unix_timestamp
+    });
+    Ok(())
+}
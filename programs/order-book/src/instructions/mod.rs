@@ -0,0 +1,25 @@
+pub mod initialize_order_book;
+pub mod place_order;
+pub mod cancel_order;
+pub mod crank_match;
+
+pub use initialize_order_book::InitializeOrderBook;
+pub use place_order::PlaceOrder;
+pub use cancel_order::CancelOrder;
+pub use crank_match::CrankMatch;
+
+// client account modules generated by #[derive(Accounts)], the #[program] macro resolves them from the crate root
+pub(crate) use {
+    initialize_order_book::__client_accounts_initialize_order_book,
+    place_order::__client_accounts_place_order,
+    cancel_order::__client_accounts_cancel_order,
+    crank_match::__client_accounts_crank_match,
+};
+
+#[cfg(feature = "cpi")]
+pub(crate) use {
+    initialize_order_book::__cpi_client_accounts_initialize_order_book,
+    place_order::__cpi_client_accounts_place_order,
+    cancel_order::__cpi_client_accounts_cancel_order,
+    crank_match::__cpi_client_accounts_crank_match,
+};
@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use escrow_vault::program::EscrowVault as EscrowVaultProgram;
+use escrow_vault::EscrowVault;
+use market_registry::cpi::accounts::AssertMarketOpen;
+use market_registry::program::MarketRegistry;
+use crate::{constants::{MIN_ORDER_VALUE_PAIRS, ORDER_BOOK_SEED}, error::OrderBookError, events::{OrderCancelled, OrderPlaced, OrdersMatched}, state::{Order, OrderBook, OrderSide, OrderType, OutcomeSide}, utils::MatchAccounts};
+
+// funds are locked in the book escrow first, bids lock price * quantity usdc, asks lock quantity outcome tokens
+// limit orders rest on the book until match or cancel
+// immediate-or-cancel orders match the best crossing orders right away and get the unfilled rest back,
+// the makers' (usdc, outcome) accounts are passed as remaining accounts in the order they are hit
+#[derive(Accounts)]
+pub struct PlaceOrder<'info>{
+    pub owner : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_BOOK_SEED,order_book.market.as_ref()],
+        bump = order_book.bump
+    )]
+    pub order_book : Box<Account<'info,OrderBook>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == order_book.vault @ OrderBookError::EscrowMismatch
+    )]
+    pub vault : Box<Account<'info,EscrowVault>>,
+
+    /// CHECK: validated via CPI
+    #[account(
+        constraint = market.key() == order_book.market @ OrderBookError::EscrowMismatch
+    )]
+    pub market : UncheckedAccount<'info>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    pub escrow_vault_program : Program<'info,EscrowVaultProgram>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub usdc_vault : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    pub usdc_mint : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub yes_token_mint : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI
+    #[account(mut)]
+    pub no_token_mint : UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = usdc_escrow.key() == order_book.usdc_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub usdc_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = yes_escrow.key() == order_book.yes_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub yes_escrow : Box<Account<'info,TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = no_escrow.key() == order_book.no_escrow @ OrderBookError::EscrowMismatch
+    )]
+    pub no_escrow : Box<Account<'info,TokenAccount>>,
+
+    /// CHECK: validated via CPI (mint fee)
+    pub protocol_config : UncheckedAccount<'info>,
+
+    /// CHECK: validated via CPI (mint fee)
+    #[account(mut)]
+    pub treasury : UncheckedAccount<'info>,
+
+    // usdc + outcome accounts of the owner, checked against the order in the handler
+    #[account(mut)]
+    pub owner_usdc : Box<Account<'info,TokenAccount>>,
+
+    #[account(mut)]
+    pub owner_outcome : Box<Account<'info,TokenAccount>>,
+
+    pub token_program : Program<'info,Token>
+}
+
+impl<'info> PlaceOrder<'info> {
+    fn match_accounts(&self)->MatchAccounts<'info>{
+        MatchAccounts {
+            order_book : self.order_book.to_account_info(),
+            market_key : self.order_book.market,
+            book_bump : self.order_book.bump,
+            collateral_per_pair : self.order_book.collateral_per_pair,
+            collateral_decimals : self.vault.collateral_decimals,
+            yes_mint : self.vault.yes_token_mint,
+            no_mint : self.vault.no_token_mint,
+            vault : self.vault.to_account_info(),
+            market : self.market.to_account_info(),
+            market_registry_program : self.market_registry_program.to_account_info(),
+            escrow_vault_program : self.escrow_vault_program.to_account_info(),
+            usdc_vault : self.usdc_vault.to_account_info(),
+            usdc_mint : self.usdc_mint.to_account_info(),
+            yes_token_mint : self.yes_token_mint.to_account_info(),
+            no_token_mint : self.no_token_mint.to_account_info(),
+            usdc_escrow : self.usdc_escrow.to_account_info(),
+            yes_escrow : self.yes_escrow.to_account_info(),
+            no_escrow : self.no_escrow.to_account_info(),
+            protocol_config : self.protocol_config.to_account_info(),
+            treasury : self.treasury.to_account_info(),
+            token_program : self.token_program.to_account_info(),
+        }
+    }
+}
+
+pub fn handler<'info>(ctx:Context<'_, '_, 'info, 'info, PlaceOrder<'info>>,outcome:OutcomeSide,side:OrderSide,price:u64,quantity:u64,order_type:OrderType)->Result<()>{
+    let book_key = ctx.accounts.order_book.key();
+    let clock = Clock::get()?;
+    let collateral_per_pair = ctx.accounts.order_book.collateral_per_pair;
+
+    require!(price > 0 && price < collateral_per_pair,OrderBookError::InvalidPrice);
+    require!(quantity > 0,OrderBookError::InvalidQuantity);
+
+    // dust orders would let anyone fill a slab for next to nothing
+    let min_value = MIN_ORDER_VALUE_PAIRS.checked_mul(collateral_per_pair).ok_or(OrderBookError::ArithmeticOverflow)?;
+    require!(
+        price.checked_mul(quantity).ok_or(OrderBookError::ArithmeticOverflow)? >= min_value,
+        OrderBookError::OrderTooSmall
+    );
+
+    market_registry::cpi::assert_market_open(CpiContext::new(
+        ctx.accounts.market_registry_program.to_account_info(),
+        AssertMarketOpen {
+            market: ctx.accounts.market.to_account_info(),
+        },
+    ))?;
+
+    let matching = ctx.accounts.match_accounts();
+
+    let mut order = Order {
+        id : ctx.accounts.order_book.next_order_id,
+        owner : ctx.accounts.owner.key(),
+        outcome,
+        side,
+        order_type,
+        price,
+        quantity
+    };
+    let owner_accounts = matching.owner_accounts(
+        &order,
+        &ctx.accounts.owner_usdc.to_account_info(),
+        &ctx.accounts.owner_outcome.to_account_info()
+    )?;
+
+    let (source,escrow) = match side {
+        OrderSide::Bid => (owner_accounts.usdc.clone(),matching.usdc_escrow.clone()),
+        OrderSide::Ask => (owner_accounts.outcome.clone(),matching.outcome_escrow(outcome)),
+    };
+
+    token::transfer(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            Transfer{
+                from : source.clone(),
+                to : escrow.clone(),
+                authority : ctx.accounts.owner.to_account_info()
+            }
+        ),
+        order.locked_amount()?)?;
+
+    let book = &mut ctx.accounts.order_book;
+    book.next_order_id = book.next_order_id.checked_add(1).ok_or(OrderBookError::ArithmeticOverflow)?;
+
+    msg!("Order {} placed", order.id);
+    msg!("{:?} {:?} {} @ {} collateral", side, outcome, quantity, format_amount(price, matching.collateral_decimals));
+
+    emit!(OrderPlaced{
+        order_book : book_key,
+        order_id : order.id,
+        owner : order.owner,
+        outcome,
+        side,
+        order_type,
+        price,
+        quantity,
+        timestamp : clock.unix_timestamp
+    });
+
+    if order_type == OrderType::Limit {
+        return book.insert(order);
+    }
+
+    // the immediate-or-cancel order is the taker of every fill, makers are hit best first
+    let mut filled : u64 = 0;
+    for maker_pair in ctx.remaining_accounts.chunks_exact(2) {
+        if order.quantity == 0 {
+            break;
+        }
+        let Some(maker) = book.best_match(outcome,side,price) else {
+            break;
+        };
+        let maker_accounts = matching.owner_accounts(&maker,&maker_pair[0],&maker_pair[1])?;
+
+        let fill = maker.quantity.min(order.quantity);
+        let kind = matching.settle(&maker,&maker_accounts,&order,&owner_accounts,fill)?;
+
+        book.update_after_fill(&maker,fill)?;
+        order.quantity = order.quantity.checked_sub(fill).ok_or(OrderBookError::ArithmeticUnderflow)?;
+        filled = filled.checked_add(fill).ok_or(OrderBookError::ArithmeticOverflow)?;
+
+        msg!("Matched order {} (maker) with {} (taker)", maker.id, order.id);
+        msg!("Quantity: {} @ {} collateral", fill, format_amount(maker.price, matching.collateral_decimals));
+
+        emit!(OrdersMatched{
+            order_book : book_key,
+            maker_order_id : maker.id,
+            taker_order_id : order.id,
+            kind,
+            quantity : fill,
+            maker_price : maker.price,
+            timestamp : clock.unix_timestamp
+        });
+    }
+    require!(filled > 0,OrderBookError::NoCrossingOrder);
+
+    // whatever did not fill is cancelled and goes back to the owner
+    if order.quantity > 0 {
+        let refund = order.locked_amount()?;
+        matching.release_from_escrow(escrow,source,refund)?;
+
+        msg!("Order {} cancelled", order.id);
+        msg!("Refunded: {}", refund);
+
+        emit!(OrderCancelled{
+            order_book : book_key,
+            order_id : order.id,
+            owner : order.owner,
+            refunded : refund,
+            timestamp : clock.unix_timestamp
+        });
+    }
+    Ok(())
+}
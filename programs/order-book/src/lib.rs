@@ -0,0 +1,39 @@
+#![allow(unexpected_cfgs)]
+use anchor_lang::prelude::*;
+pub mod state;
+pub mod error;
+pub mod events;
+pub mod constants;
+pub mod instructions;
+pub mod utils;
+
+pub use constants::*;
+pub use error::*;
+pub use events::*;
+pub use instructions::*;
+pub use state::*;
+pub use utils::*;
+declare_id!("C3TTuDESWa8fKG7x4prTkcDXkQBtan2nqofgzBFCtbJV");
+
+
+#[program]
+
+pub mod order_book {
+    use super::*;
+
+    pub fn initialize_order_book(ctx:Context<InitializeOrderBook>)->Result<()>{
+        instructions::initialize_order_book::handler(ctx)
+    }
+
+    pub fn place_order<'info>(ctx:Context<'_, '_, 'info, 'info, PlaceOrder<'info>>,outcome:OutcomeSide,side:OrderSide,price:u64,quantity:u64,order_type:OrderType)->Result<()>{
+        instructions::place_order::handler(ctx, outcome, side, price, quantity, order_type)
+    }
+
+    pub fn cancel_order(ctx:Context<CancelOrder>,order_id:u64)->Result<()>{
+        instructions::cancel_order::handler(ctx, order_id)
+    }
+
+    pub fn crank_match(ctx:Context<CrankMatch>,first_order_id:u64,second_order_id:u64)->Result<()>{
+        instructions::crank_match::handler(ctx, first_order_id, second_order_id)
+    }
+}
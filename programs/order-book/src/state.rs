@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+pub use escrow_vault::OutcomeSide;
+
+use crate::{constants::MAX_ORDERS_PER_SLAB, error::OrderBookError};
+
+// one book per market, four fixed-capacity slabs of resting orders
+// bids lock usdc (price * quantity), asks lock outcome tokens in the book escrows
+#[account]
+pub struct OrderBook {
+    pub market : Pubkey,
+    // escrow vault of the market (the book PDA must be one of its settlement authorities)
+    pub vault : Pubkey,
+
+    pub usdc_escrow : Pubkey,
+
+    pub yes_escrow : Pubkey,
+
+    pub no_escrow : Pubkey,
+
+    pub next_order_id : u64,
+
+    pub yes_bids : Vec<Order>,
+
+    pub yes_asks : Vec<Order>,
+
+    pub no_bids : Vec<Order>,
+
+    pub no_asks : Vec<Order>,
+
+    pub bump : u8,
+    // collateral behind one YES + NO pair, copied from the vault -> prices are quoted in collateral base units per token
+    pub collateral_per_pair : u64
+}
+
+impl OrderBook {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // market
+        32 + // vault
+        32 + // usdc_escrow
+        32 + // yes_escrow
+        32 + // no_escrow
+        8 + // next_order_id
+        4 * (4 + MAX_ORDERS_PER_SLAB * Order::LEN) + // slabs
+        1 + // bump
+        8; // collateral_per_pair
+
+    pub fn slab(&self,outcome:OutcomeSide,side:OrderSide)->&Vec<Order>{
+        match (outcome,side) {
+            (OutcomeSide::Yes,OrderSide::Bid) => &self.yes_bids,
+            (OutcomeSide::Yes,OrderSide::Ask) => &self.yes_asks,
+            (OutcomeSide::No,OrderSide::Bid) => &self.no_bids,
+            (OutcomeSide::No,OrderSide::Ask) => &self.no_asks,
+        }
+    }
+
+    pub fn slab_mut(&mut self,outcome:OutcomeSide,side:OrderSide)->&mut Vec<Order>{
+        match (outcome,side) {
+            (OutcomeSide::Yes,OrderSide::Bid) => &mut self.yes_bids,
+            (OutcomeSide::Yes,OrderSide::Ask) => &mut self.yes_asks,
+            (OutcomeSide::No,OrderSide::Bid) => &mut self.no_bids,
+            (OutcomeSide::No,OrderSide::Ask) => &mut self.no_asks,
+        }
+    }
+
+    // price-time priority: best price first, older order first on ties
+    pub fn insert(&mut self,order:Order)->Result<()>{
+        let slab = self.slab_mut(order.outcome,order.side);
+        require!(slab.len() < MAX_ORDERS_PER_SLAB,OrderBookError::SlabFull);
+
+        let position = slab
+            .iter()
+            .position(|resting| match order.side {
+                OrderSide::Bid => order.price > resting.price,
+                OrderSide::Ask => order.price < resting.price,
+            })
+            .unwrap_or(slab.len());
+        slab.insert(position,order);
+        Ok(())
+    }
+
+    pub fn find(&self,order_id:u64)->Result<Order>{
+        [&self.yes_bids,&self.yes_asks,&self.no_bids,&self.no_asks]
+            .iter()
+            .flat_map(|slab| slab.iter())
+            .find(|order| order.id == order_id)
+            .copied()
+            .ok_or(OrderBookError::OrderNotFound.into())
+    }
+
+    pub fn remove(&mut self,order:&Order)->Result<()>{
+        let slab = self.slab_mut(order.outcome,order.side);
+        let position = slab
+            .iter()
+            .position(|resting| resting.id == order.id)
+            .ok_or(OrderBookError::OrderNotFound)?;
+        slab.remove(position);
+        Ok(())
+    }
+
+    // writes back the remaining quantity, filled orders leave the book
+    pub fn update_after_fill(&mut self,order:&Order,filled:u64)->Result<()>{
+        let remaining = order.quantity.checked_sub(filled).ok_or(OrderBookError::ArithmeticUnderflow)?;
+        if remaining == 0 {
+            return self.remove(order);
+        }
+        let slab = self.slab_mut(order.outcome,order.side);
+        let resting = slab
+            .iter_mut()
+            .find(|resting| resting.id == order.id)
+            .ok_or(OrderBookError::OrderNotFound)?;
+        resting.quantity = remaining;
+        Ok(())
+    }
+
+    // head of its slab -> best price, oldest on ties
+    pub fn is_best(&self,order:&Order)->bool{
+        self.slab(order.outcome,order.side).first().is_some_and(|best| best.id == order.id)
+    }
+
+    // best resting order an incoming order trades against, either directly
+    // (same outcome, opposite side) or through a pair mint / merge (other outcome, same side)
+    pub fn best_match(&self,outcome:OutcomeSide,side:OrderSide,price:u64)->Option<Order>{
+        let other_outcome = match outcome {
+            OutcomeSide::Yes => OutcomeSide::No,
+            OutcomeSide::No => OutcomeSide::Yes,
+        };
+        let direct = match side {
+            OrderSide::Bid => self.slab(outcome,OrderSide::Ask).first().filter(|ask| price >= ask.price),
+            OrderSide::Ask => self.slab(outcome,OrderSide::Bid).first().filter(|bid| bid.price >= price),
+        };
+        let complementary = match side {
+            OrderSide::Bid => self.slab(other_outcome,OrderSide::Bid).first().filter(|bid| price + bid.price >= self.collateral_per_pair),
+            OrderSide::Ask => self.slab(other_outcome,OrderSide::Ask).first().filter(|ask| price + ask.price <= self.collateral_per_pair),
+        };
+
+        match (direct,complementary) {
+            (Some(direct),Some(complementary)) => {
+                // a complementary maker leaves the rest of the pair to the incoming order
+                let direct_price = direct.price;
+                let complementary_price = self.collateral_per_pair - complementary.price;
+                let direct_is_better = match side {
+                    OrderSide::Bid => direct_price < complementary_price,
+                    OrderSide::Ask => direct_price > complementary_price,
+                };
+                if direct_is_better || (direct_price == complementary_price && direct.id < complementary.id) {
+                    Some(*direct)
+                } else {
+                    Some(*complementary)
+                }
+            }
+            (direct,complementary) => direct.or(complementary).copied(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct Order {
+    pub id : u64,
+
+    pub owner : Pubkey,
+
+    pub outcome : OutcomeSide,
+
+    pub side : OrderSide,
+
+    pub order_type : OrderType,
+    // collateral base units per outcome token (1..collateral_per_pair)
+    pub price : u64,
+    // remaining outcome tokens
+    pub quantity : u64
+}
+
+impl Order {
+    pub const LEN: usize =
+        8 + // id
+        32 + // owner
+        1 + // outcome
+        1 + // side
+        1 + // order_type
+        8 + // price
+        8; // quantity
+
+    // usdc (bids) or outcome tokens (asks) still locked in the escrow for this order
+    pub fn locked_amount(&self)->Result<u64>{
+        match self.side {
+            OrderSide::Bid => Ok(self.price.checked_mul(self.quantity).ok_or(OrderBookError::ArithmeticOverflow)?),
+            OrderSide::Ask => Ok(self.quantity),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OrderSide {
+    Bid,
+    Ask
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OrderType {
+    Limit,
+    ImmediateOrCancel
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MatchKind {
+    // bid and ask on the same outcome -> tokens and usdc change hands through the escrows
+    Direct,
+    // YES bid + NO bid -> escrowed usdc is turned into a new pair via mint_pairs
+    MintPair,
+    // YES ask + NO ask -> escrowed tokens are merged back into usdc via merge_pairs
+    MergePair
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token::{self, accessor, TokenAccount, Transfer};
+use escrow_vault::cpi::accounts::{MergePairs, MintPairs};
+use crate::{constants::ORDER_BOOK_SEED, error::OrderBookError, state::{MatchKind, Order, OrderSide, OutcomeSide}};
+
+// usdc + outcome token accounts of an order's owner
+pub struct OwnerAccounts<'info> {
+    pub usdc : AccountInfo<'info>,
+
+    pub outcome : AccountInfo<'info>
+}
+
+// everything a match settles through, shared by crank_match and immediate-or-cancel orders in place_order
+// 1. YES bid x YES ask (or NO x NO)  -> tokens and usdc move between the book escrows
+// 2. YES bid x NO bid                -> escrowed usdc mints new pairs through the escrow vault
+// 3. YES ask x NO ask                -> escrowed tokens are merged back into usdc
+pub struct MatchAccounts<'info> {
+    pub order_book : AccountInfo<'info>,
+
+    pub market_key : Pubkey,
+
+    pub book_bump : u8,
+
+    pub collateral_per_pair : u64,
+
+    pub collateral_decimals : u8,
+    // outcome mints of the vault, owner accounts are checked against them
+    pub yes_mint : Pubkey,
+
+    pub no_mint : Pubkey,
+
+    pub vault : AccountInfo<'info>,
+
+    pub market : AccountInfo<'info>,
+
+    pub market_registry_program : AccountInfo<'info>,
+
+    pub escrow_vault_program : AccountInfo<'info>,
+
+    pub usdc_vault : AccountInfo<'info>,
+
+    pub usdc_mint : AccountInfo<'info>,
+
+    pub yes_token_mint : AccountInfo<'info>,
+
+    pub no_token_mint : AccountInfo<'info>,
+
+    pub usdc_escrow : AccountInfo<'info>,
+
+    pub yes_escrow : AccountInfo<'info>,
+
+    pub no_escrow : AccountInfo<'info>,
+
+    pub protocol_config : AccountInfo<'info>,
+
+    pub treasury : AccountInfo<'info>,
+
+    pub token_program : AccountInfo<'info>
+}
+
+// spl token account passed outside the accounts struct (crank owners, immediate-or-cancel makers)
+fn load_token_account(info:&AccountInfo)->Result<TokenAccount>{
+    require_keys_eq!(*info.owner,token::ID,OrderBookError::InvalidTokenAccount);
+    TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])
+}
+
+impl<'info> MatchAccounts<'info> {
+    fn outcome_mint(&self,outcome:OutcomeSide)->Pubkey{
+        match outcome {
+            OutcomeSide::Yes => self.yes_mint,
+            OutcomeSide::No => self.no_mint,
+        }
+    }
+
+    pub fn outcome_escrow(&self,outcome:OutcomeSide)->AccountInfo<'info>{
+        match outcome {
+            OutcomeSide::Yes => self.yes_escrow.clone(),
+            OutcomeSide::No => self.no_escrow.clone(),
+        }
+    }
+
+    // token accounts passed for an order must belong to its owner and hold the book's usdc / the order's outcome
+    pub fn owner_accounts(&self,order:&Order,usdc:&AccountInfo<'info>,outcome:&AccountInfo<'info>)->Result<OwnerAccounts<'info>>{
+        let usdc_account = load_token_account(usdc)?;
+        let outcome_account = load_token_account(outcome)?;
+        require!(
+            usdc_account.owner == order.owner && usdc_account.mint == accessor::mint(&self.usdc_escrow)?,
+            OrderBookError::InvalidTokenAccount
+        );
+        require!(
+            outcome_account.owner == order.owner && outcome_account.mint == self.outcome_mint(order.outcome),
+            OrderBookError::InvalidTokenAccount
+        );
+        Ok(OwnerAccounts { usdc : usdc.clone(), outcome : outcome.clone() })
+    }
+
+    // moves tokens out of a book escrow, the book PDA signs
+    pub fn release_from_escrow(&self,from:AccountInfo<'info>,to:AccountInfo<'info>,amount:u64)->Result<()>{
+        if amount == 0 {
+            return Ok(());
+        }
+        let book_seeds = &[
+            ORDER_BOOK_SEED,
+            self.market_key.as_ref(),
+            &[self.book_bump]
+        ];
+        let book_signer = &[&book_seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(self.token_program.clone(),
+                Transfer{
+                    from,
+                    to,
+                    authority : self.order_book.clone()
+                },
+                book_signer
+            ),
+            amount)
+    }
+
+    // settles quantity between two crossing orders at the maker's price, the caller updates the book
+    pub fn settle(&self,maker:&Order,maker_accounts:&OwnerAccounts<'info>,taker:&Order,taker_accounts:&OwnerAccounts<'info>,quantity:u64)->Result<MatchKind>{
+        let collateral_per_pair = self.collateral_per_pair;
+        let maker_value = maker.price.checked_mul(quantity).ok_or(OrderBookError::ArithmeticOverflow)?;
+        let taker_value = taker.price.checked_mul(quantity).ok_or(OrderBookError::ArithmeticOverflow)?;
+        let pair_value = quantity.checked_mul(collateral_per_pair).ok_or(OrderBookError::ArithmeticOverflow)?;
+
+        let book_seeds = &[
+            ORDER_BOOK_SEED,
+            self.market_key.as_ref(),
+            &[self.book_bump]
+        ];
+        let book_signer = &[&book_seeds[..]];
+
+        if maker.outcome == taker.outcome && maker.side != taker.side {
+            let (bid,bid_accounts,ask,ask_accounts) = if maker.side == OrderSide::Bid {
+                (maker,maker_accounts,taker,taker_accounts)
+            } else {
+                (taker,taker_accounts,maker,maker_accounts)
+            };
+            require!(bid.price >= ask.price,OrderBookError::OrdersDoNotCross);
+
+            // seller gets the maker price, buyer gets back whatever it locked above that
+            let bid_locked = if bid.id == maker.id { maker_value } else { taker_value };
+            let buyer_refund = bid_locked.checked_sub(maker_value).ok_or(OrderBookError::ArithmeticUnderflow)?;
+
+            self.release_from_escrow(self.outcome_escrow(ask.outcome),bid_accounts.outcome.clone(),quantity)?;
+            self.release_from_escrow(self.usdc_escrow.clone(),ask_accounts.usdc.clone(),maker_value)?;
+            self.release_from_escrow(self.usdc_escrow.clone(),bid_accounts.usdc.clone(),buyer_refund)?;
+
+            Ok(MatchKind::Direct)
+        } else if maker.outcome != taker.outcome && maker.side == OrderSide::Bid && taker.side == OrderSide::Bid {
+            require!(
+                maker.price.checked_add(taker.price).ok_or(OrderBookError::ArithmeticOverflow)? >= collateral_per_pair,
+                OrderBookError::OrdersDoNotCross
+            );
+
+            let (yes_recipient,no_recipient) = if maker.outcome == OutcomeSide::Yes {
+                (maker_accounts.outcome.clone(),taker_accounts.outcome.clone())
+            } else {
+                (taker_accounts.outcome.clone(),maker_accounts.outcome.clone())
+            };
+
+            let escrow_before = accessor::amount(&self.usdc_escrow)?;
+
+            // the book is a settlement authority of the vault and pays collateral + mint fee from its usdc escrow
+            escrow_vault::cpi::mint_pairs(
+                CpiContext::new_with_signer(
+                    self.escrow_vault_program.clone(),
+                    MintPairs {
+                        authority : self.order_book.clone(),
+                        vault : self.vault.clone(),
+                        market : self.market.clone(),
+                        market_registry_program : self.market_registry_program.clone(),
+                        usdc_vault : self.usdc_vault.clone(),
+                        usdc_mint : self.usdc_mint.clone(),
+                        hot_wallet_usdc : self.usdc_escrow.clone(),
+                        yes_token_mint : self.yes_token_mint.clone(),
+                        no_token_mint : self.no_token_mint.clone(),
+                        yes_recipient,
+                        no_recipient,
+                        protocol_config : self.protocol_config.clone(),
+                        treasury : self.treasury.clone(),
+                        token_program : self.token_program.clone(),
+                        outcome_token_program : self.token_program.clone(),
+                    },
+                    book_signer
+                ),
+                quantity)?;
+
+            let spent = escrow_before.checked_sub(accessor::amount(&self.usdc_escrow)?).ok_or(OrderBookError::ArithmeticUnderflow)?;
+
+            // maker pays its own price, taker covers the rest of the collateral and the fee
+            let taker_cost = spent.checked_sub(maker_value).ok_or(OrderBookError::ArithmeticUnderflow)?;
+            require!(taker_cost <= taker_value,OrderBookError::OrdersDoNotCross);
+
+            self.release_from_escrow(self.usdc_escrow.clone(),taker_accounts.usdc.clone(),taker_value - taker_cost)?;
+
+            msg!("Minted {} pairs, collateral + fee: {}", quantity, format_amount(spent, self.collateral_decimals));
+            Ok(MatchKind::MintPair)
+        } else if maker.outcome != taker.outcome && maker.side == OrderSide::Ask && taker.side == OrderSide::Ask {
+            require!(
+                maker.price.checked_add(taker.price).ok_or(OrderBookError::ArithmeticOverflow)? <= collateral_per_pair,
+                OrderBookError::OrdersDoNotCross
+            );
+
+            let escrow_before = accessor::amount(&self.usdc_escrow)?;
+
+            // the book holds both legs in its escrows and merges them for the collateral
+            escrow_vault::cpi::merge_pairs(
+                CpiContext::new_with_signer(
+                    self.escrow_vault_program.clone(),
+                    MergePairs {
+                        user : self.order_book.clone(),
+                        vault : self.vault.clone(),
+                        usdc_vault : self.usdc_vault.clone(),
+                        user_usdc : self.usdc_escrow.clone(),
+                        yes_token_mint : self.yes_token_mint.clone(),
+                        no_token_mint : self.no_token_mint.clone(),
+                        user_yes_account : self.yes_escrow.clone(),
+                        user_no_account : self.no_escrow.clone(),
+                        usdc_mint : self.usdc_mint.clone(),
+                        token_program : self.token_program.clone(),
+                        outcome_token_program : self.token_program.clone(),
+                    },
+                    book_signer
+                ),
+                quantity)?;
+
+            let received = accessor::amount(&self.usdc_escrow)?.checked_sub(escrow_before).ok_or(OrderBookError::ArithmeticUnderflow)?;
+            require!(received == pair_value,OrderBookError::OrdersDoNotCross);
+
+            // maker gets its own price, taker gets the rest of the released collateral
+            let taker_proceeds = received.checked_sub(maker_value).ok_or(OrderBookError::ArithmeticUnderflow)?;
+            require!(taker_proceeds >= taker_value,OrderBookError::OrdersDoNotCross);
+
+            self.release_from_escrow(self.usdc_escrow.clone(),maker_accounts.usdc.clone(),maker_value)?;
+            self.release_from_escrow(self.usdc_escrow.clone(),taker_accounts.usdc.clone(),taker_proceeds)?;
+
+            msg!("Merged {} pairs, released: {}", quantity, format_amount(received, self.collateral_decimals));
+            Ok(MatchKind::MergePair)
+        } else {
+            err!(OrderBookError::OrdersDoNotCross)
+        }
+    }
+}
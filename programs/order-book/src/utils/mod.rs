@@ -0,0 +1,3 @@
+pub mod matching;
+
+pub use matching::*;
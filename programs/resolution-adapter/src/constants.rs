@@ -5,33 +5,30 @@ pub const RESOLUTION_SEED: &[u8] = b"resolution";
 /// Seed for oracle bond vault
 pub const BOND_VAULT_SEED: &[u8] = b"bond_vault";
 
-/// Minimum bond required to propose outcome (1000 USDC)
-pub const MIN_PROPOSAL_BOND: u64 = 1_000 * 1_000_000; // 1000 USDC with 6 decimals
+/// Seed for the oracle reward vault of a bond mint
+pub const REWARD_VAULT_SEED: &[u8] = b"reward_vault";
 
-#[cfg(not(feature = "testing"))]
-pub const DISPUTE_WINDOW_SECONDS: i64 = 86400; // 24 hours (production)
+/// Seed for a staker's vote record
+pub const VOTE_SEED: &[u8] = b"vote";
 
-#[cfg(feature = "testing")]
-pub const DISPUTE_WINDOW_SECONDS: i64 = 10; // 10 seconds (testing)
+/// Share of stake lost by voters outside the majority, unrevealed votes included (10%)
+pub const VOTE_SLASH_BPS: u64 = 1000;
 
-/// Extended dispute window if challenged (additional 24 hours)
-pub const DISPUTE_EXTENSION_SECONDS: i64 = 24 * 60 * 60;
+/// Share of the slashed stake paid to the majority, the rest goes to the winning bond (50%)
+pub const VOTE_MAJORITY_SHARE_BPS: u64 = 5000;
+
+/// Proposal rounds per market (sizes the round history and the bond ledger)
+pub const MAX_PROPOSAL_ROUNDS: u8 = 3;
 
 /// Maximum number of data sources allowed per proposal
 pub const MAX_DATA_SOURCES: usize = 5;
 
-/// Reward for correct oracle proposal (100 USDC)
-pub const ORACLE_REWARD: u64 = 100 * 1_000_000;
-
-/// Maximum price deviation allowed between sources (5%)
-pub const MAX_PRICE_DEVIATION_BPS: u16 = 500; // 5% in basis points
+/// Reward for correct oracle proposal, in whole tokens of the bond mint (100)
+pub const ORACLE_REWARD: u64 = 100;
 
 /// Minimum confidence required for Pyth price feeds (90%)
 pub const MIN_PYTH_CONFIDENCE_BPS: u16 = 9000; // 90% in basis points
 
-/// Maximum staleness for oracle data (5 minutes)
-pub const MAX_ORACLE_STALENESS_SECONDS: i64 = 5 * 60;
-
 /// USDC decimals
 pub const USDC_DECIMALS: u8 = 6;
 
@@ -74,7 +74,7 @@ pub enum ResolutionError {
     #[msg("Cannot dispute own proposal")]
     CannotDisputeOwnProposal,
 
-    #[msg("Dispute bond must be equal or greater than original bond")]
+    #[msg("Dispute bond must be at least double the previous bond")]
     InsufficientDisputeBond,
 
     #[msg("Maximum disputes reached")]
@@ -89,10 +89,76 @@ pub enum ResolutionError {
     #[msg("Bond vault mismatch")]
     BondVaultMismatch,
 
+    #[msg("Bond mint mismatch")]
+    BondMintMismatch,
+
     #[msg("Invalid account count")]
     InvalidAccountCount,
     
     #[msg("Unauthorized admin")]
     UnauthorizedAdmin,
 
+    #[msg("Scalar market requires a Between condition matching its bounds")]
+    ScalarConditionMismatch,
+
+    #[msg("Resolution is not finalized or the market grace period has not passed")]
+    ResolutionNotClosable,
+
+    #[msg("Treasury must be a bond mint account of the protocol admin")]
+    TreasuryMismatch,
+
+    #[msg("Dispute must challenge the standing outcome with a different one")]
+    DisputeOutcomeUnchanged,
+
+    #[msg("Every escalation round was used, the arbitrator has not ruled yet")]
+    AwaitingArbitration,
+
+    #[msg("Proposal has not been escalated to arbitration")]
+    NotAwaitingArbitration,
+
+    #[msg("Arbitrator already ruled on this proposal")]
+    AlreadyArbitrated,
+
+    #[msg("Staker vote is still running")]
+    VoteInProgress,
+
+    #[msg("Staker vote decided the dispute, no arbitration needed")]
+    VoteDecided,
+
+    #[msg("Commit phase of the vote has ended")]
+    VoteCommitClosed,
+
+    #[msg("Vote is not in its reveal phase")]
+    NotInRevealPhase,
+
+    #[msg("Vote already revealed")]
+    VoteAlreadyRevealed,
+
+    #[msg("Revealed vote does not match the commitment")]
+    InvalidVoteReveal,
+
+    #[msg("Vote stake must be greater than zero")]
+    InvalidVoteStake,
+
+    #[msg("Resolution is not finalized yet")]
+    NotFinalized,
+
+    #[msg("No unclaimed bond on the settled outcome")]
+    NothingToClaim,
+
+    #[msg("Winner does not match the winning bond")]
+    WinnerMismatch,
+
+    #[msg("Reward funding must be greater than zero")]
+    InvalidRewardFunding,
+
+    #[msg("Proposal round is neither empty nor stale")]
+    RoundStillActive,
+
+    #[msg("Maximum proposal rounds reached")]
+    MaxProposalRoundsReached,
+
+    #[msg("The market's resolution window has closed, no new proposal round can be opened")]
+    ResolutionWindowExhausted,
+
 }
\ No newline at end of file
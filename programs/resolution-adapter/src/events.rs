@@ -2,7 +2,7 @@
 use anchor_lang::prelude::*;
 use market_registry::ResultOutcome;
 
-use crate::state::{MarketCategory, OracleType};
+use crate::state::{MarketCategory, OracleType, OutcomeStake, RoundCloseReason};
 
 #[event]
 
@@ -50,6 +50,8 @@ pub struct  ProposalDispute{
 pub struct OutcomeFinalized{
     pub market : Pubkey,
 
+    pub finalizer : Pubkey,
+
     pub outcome : ResultOutcome,
 
     pub winning_proposer : Pubkey,
@@ -65,6 +67,68 @@ pub struct OutcomeFinalized{
 
 #[event]
 
+pub struct ProposalRoundOpened{
+    pub market : Pubkey,
+    // the new round
+    pub round : u8,
+    // why the previous round closed
+    pub reason : RoundCloseReason,
+    // bonds and vote stakes of the closed round, refundable via claim_bond / claim_vote
+    pub refunded_amount : u64,
+    // the new round counts as empty after this
+    pub proposal_window_end : i64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct RewardVaultFunded{
+    pub bond_mint : Pubkey,
+
+    pub funder : Pubkey,
+
+    pub amount : u64,
+
+    pub balance : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct BondsSettled{
+    pub market : Pubkey,
+    // None -> emergency resolution, every bond refunded
+    pub outcome : Option<ResultOutcome>,
+
+    pub winning_bonds : u64,
+
+    pub slashed_bonds : u64,
+
+    pub protocol_fee : u64,
+
+    pub reward_pool : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct BondClaimed{
+    pub market : Pubkey,
+
+    pub participant : Pubkey,
+    // bonds of the participant on the settled outcome
+    pub bonded : u64,
+
+    pub payout : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
 pub struct  CryptoPriceValidated{
     pub market : Pubkey,
 
@@ -111,4 +175,104 @@ pub struct EmergencyResolution{
     pub refunded_amount : u64,
 
     pub timestamp : i64 
-}
\ No newline at end of file
+}
+
+#[event]
+
+pub struct EscalatedToVote{
+    pub market : Pubkey,
+
+    pub rounds : u8,
+
+    pub standing_outcome : ResultOutcome,
+
+    pub total_bonded : u64,
+
+    pub commit_ends_at : i64,
+
+    pub reveal_ends_at : i64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct VoteCommitted{
+    pub market : Pubkey,
+
+    pub voter : Pubkey,
+
+    pub stake : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct VoteRevealed{
+    pub market : Pubkey,
+
+    pub voter : Pubkey,
+
+    pub outcome : ResultOutcome,
+
+    pub stake : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct VoteTallied{
+    pub market : Pubkey,
+    // None -> the vote failed and the arbitrator ruled
+    pub result : Option<ResultOutcome>,
+
+    pub tally : Vec<OutcomeStake>,
+
+    pub total_stake : u64,
+    // returned to voters (stakes minus slashing plus the majority share)
+    pub voter_pool : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct VoteClaimed{
+    pub market : Pubkey,
+
+    pub voter : Pubkey,
+
+    pub stake : u64,
+
+    pub payout : u64,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct DisputeArbitrated{
+    pub market : Pubkey,
+
+    pub arbitrator : Pubkey,
+
+    pub outcome : ResultOutcome,
+
+    pub timestamp : i64
+}
+
+#[event]
+
+pub struct ResolutionClosed{
+    pub market : Pubkey,
+
+    pub resolution : Pubkey,
+
+    pub payer : Pubkey,
+    // unclaimed bond vault balance sent to the treasury before closing
+    pub swept : u64,
+
+    pub timestamp : i64
+}
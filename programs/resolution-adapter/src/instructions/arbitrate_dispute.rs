@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use market_registry::{ResultOutcome, state::AdminAuthority, ADMIN_SEED};
+
+use crate::{constants::RESOLUTION_SEED, error::ResolutionError, events::DisputeArbitrated, state::ResolutionProposal};
+
+// fallback once the staker vote failed, finalize_outcome then settles on the ruling
+#[derive(Accounts)]
+pub struct ArbitrateDispute<'info>{
+    pub arbitrator : Signer<'info>,
+
+    /// Protocol admin config (from MarketRegistry), names the arbitrator
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.arbitrator == arbitrator.key() @ ResolutionError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
+        bump = resolution_proposal.bump,
+        constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>
+}
+
+pub fn handler(ctx:Context<ArbitrateDispute>,outcome:ResultOutcome)->Result<()>{
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let clock = Clock::get()?;
+
+    require!(resolution.is_fully_escalated(),ResolutionError::NotAwaitingArbitration);
+    require!(resolution.arbitrated_outcome.is_none(),ResolutionError::AlreadyArbitrated);
+    require!(clock.unix_timestamp >= resolution.vote_ends_at(),ResolutionError::VoteInProgress);
+    require!(resolution.vote_outcome().is_none(),ResolutionError::VoteDecided);
+
+    // the ruling picks one of the bonded sides, otherwise nobody could be paid out
+    require!(resolution.is_bonded_outcome(outcome),ResolutionError::InvalidOutcome);
+
+    resolution.arbitrated_outcome = Some(outcome);
+
+    msg!("Dispute arbitrated");
+    msg!("Market: {}", resolution.market);
+    msg!("Arbitrator: {}", ctx.accounts.arbitrator.key());
+    msg!("Outcome: {:?}", outcome);
+
+    emit!(DisputeArbitrated {
+        market: resolution.market,
+        arbitrator: ctx.accounts.arbitrator.key(),
+        outcome,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{constants::RESOLUTION_SEED, error::ResolutionError, events::BondClaimed, state::ResolutionProposal};
+
+// pays every unclaimed bond of the participant out of the settlement, losing bonds stay in the reward pool;
+// bonds of closed proposal rounds are refunded in full
+#[derive(Accounts)]
+pub struct ClaimBond<'info>{
+    pub participant : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
+        bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
+    )]
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = participant_token_account.mint == bond_vault.mint,
+        constraint = participant_token_account.owner == participant.key()
+    )]
+    pub participant_token_account : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<ClaimBond>)->Result<()>{
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let participant = ctx.accounts.participant.key();
+    let settlement = resolution.settlement;
+    let clock = Clock::get()?;
+
+    let round = resolution.round;
+    let is_finalized = resolution.is_finalized;
+
+    // closed rounds first, they are refunded whether or not the current round settled
+    let mut refunded : u64 = 0;
+    for contributor in resolution.bond_contributors.iter_mut()
+        .filter(|contributor| contributor.participant == participant && !contributor.claimed && contributor.round < round)
+    {
+        contributor.claimed = true;
+        refunded = refunded.checked_add(contributor.amount).ok_or(ResolutionError::ArithmeticOverflow)?;
+    }
+    let refunded = refunded.min(resolution.refund_pool);
+    resolution.refund_pool -= refunded;
+
+    let mut bonded : u64 = 0;
+    let mut settled : u64 = 0;
+    if is_finalized {
+        for contributor in resolution.bond_contributors.iter_mut()
+            .filter(|contributor| contributor.participant == participant && !contributor.claimed && contributor.round == round && settlement.is_winning(contributor.outcome))
+        {
+            contributor.claimed = true;
+            bonded = bonded.checked_add(contributor.amount).ok_or(ResolutionError::ArithmeticOverflow)?;
+            settled = settled.checked_add(settlement.payout(contributor.amount)?).ok_or(ResolutionError::ArithmeticOverflow)?;
+        }
+    }
+    require!(refunded > 0 || bonded > 0,ResolutionError::NothingToClaim);
+
+    if bonded > 0 {
+        // the last winning bond sweeps the rounding dust
+        let is_last = !resolution.bond_contributors.iter()
+            .any(|contributor| !contributor.claimed && contributor.round == round && settlement.is_winning(contributor.outcome));
+        settled = if is_last { settlement.remaining } else { settled.min(settlement.remaining) };
+        resolution.settlement.remaining -= settled;
+    }
+    let payout = refunded.checked_add(settled).ok_or(ResolutionError::ArithmeticOverflow)?;
+
+    if payout > 0 {
+        let resolution_seeds = &[
+            RESOLUTION_SEED,
+            resolution.market.as_ref(),
+            &[resolution.bump],
+        ];
+        let resolution_signer = &[&resolution_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.bond_vault.to_account_info(),
+                mint: ctx.accounts.bond_mint.to_account_info(),
+                to: ctx.accounts.participant_token_account.to_account_info(),
+                authority: resolution.to_account_info(),
+            },
+            resolution_signer,
+        );
+        token_interface::transfer_checked(transfer_ctx, payout, ctx.accounts.bond_mint.decimals)?;
+    }
+
+    msg!("Bond claimed");
+    msg!("Market: {}", resolution.market);
+    msg!("Participant: {}", participant);
+    msg!("Bonded: {} tokens", format_amount(bonded, resolution.bond_decimals));
+    msg!("Refunded from closed rounds: {} tokens", format_amount(refunded, resolution.bond_decimals));
+    msg!("Payout: {} tokens", format_amount(payout, resolution.bond_decimals));
+
+    emit!(BondClaimed {
+        market: resolution.market,
+        participant,
+        bonded: bonded.checked_add(refunded).ok_or(ResolutionError::ArithmeticOverflow)?,
+        payout,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
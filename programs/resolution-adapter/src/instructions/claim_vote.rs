@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{constants::{RESOLUTION_SEED, VOTE_SEED}, error::ResolutionError, events::VoteClaimed, state::{ResolutionProposal, VoteRecord}};
+
+// pays a voter out of the tallied pool (or refunds a vote of a closed round) and closes the vote record
+#[derive(Accounts)]
+pub struct ClaimVote<'info>{
+    #[account(mut)]
+    pub voter : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
+        bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>,
+
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, resolution_proposal.key().as_ref(), voter.key().as_ref(), &[vote_record.round]],
+        bump = vote_record.bump,
+        close = voter
+    )]
+    pub vote_record : Account<'info,VoteRecord>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
+    )]
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == bond_vault.mint,
+        constraint = voter_token_account.owner == voter.key()
+    )]
+    pub voter_token_account : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<ClaimVote>)->Result<()>{
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let vote_record = &ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+
+    let payout = if vote_record.round < resolution.round {
+        // the round was reopened, its vote does not count
+        let payout = vote_record.stake.min(resolution.refund_pool);
+        resolution.refund_pool -= payout;
+        payout
+    } else {
+        require!(resolution.is_finalized,ResolutionError::NotFinalized);
+
+        // the last claimer sweeps the rounding dust left in the pool
+        let payout = if resolution.vote.voters == 1 {
+            resolution.vote.pool
+        } else {
+            resolution.vote.payout(vote_record)?.min(resolution.vote.pool)
+        };
+
+        resolution.vote.pool -= payout;
+        resolution.vote.voters -= 1;
+        payout
+    };
+
+    if payout > 0 {
+        let resolution_seeds = &[
+            RESOLUTION_SEED,
+            resolution.market.as_ref(),
+            &[resolution.bump],
+        ];
+        let resolution_signer = &[&resolution_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.bond_vault.to_account_info(),
+                mint: ctx.accounts.bond_mint.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: resolution.to_account_info(),
+            },
+            resolution_signer,
+        );
+        token_interface::transfer_checked(transfer_ctx, payout, ctx.accounts.bond_mint.decimals)?;
+    }
+
+    msg!("Vote claimed");
+    msg!("Market: {}", resolution.market);
+    msg!("Voter: {}", vote_record.voter);
+    msg!("Voted: {:?}", vote_record.revealed_outcome);
+    msg!("Stake: {} tokens", format_amount(vote_record.stake, resolution.bond_decimals));
+    msg!("Payout: {} tokens", format_amount(payout, resolution.bond_decimals));
+
+    emit!(VoteClaimed {
+        market: resolution.market,
+        voter: vote_record.voter,
+        stake: vote_record.stake,
+        payout,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
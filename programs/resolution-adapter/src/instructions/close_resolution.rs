@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+use market_registry::{state::{AdminAuthority, Market}, ADMIN_SEED};
+
+use crate::{constants::RESOLUTION_SEED, error::ResolutionError, events::ResolutionClosed, state::ResolutionProposal};
+
+#[derive(Accounts)]
+
+pub struct CloseResolution<'info>{
+
+    pub closer : Signer<'info>,
+
+    /// CHECK: market may already be closed, validated in the handler
+    pub market : UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, market.key().as_ref()],
+        bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
+        close = payer
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
+    )]
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    /// Protocol admin config (from MarketRegistry), owner of the treasury
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    // anything still in the bond vault after the grace period (unclaimed bonds, stakes, transfers) goes here
+    #[account(
+        mut,
+        constraint = treasury.owner == config.admin @ ResolutionError::TreasuryMismatch,
+        constraint = treasury.mint == bond_mint.key() @ ResolutionError::TreasuryMismatch
+    )]
+    pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+    /// CHECK: original rent payer, checked against the stored key
+    #[account(
+        mut,
+        address = resolution_proposal.payer @ ResolutionError::Unauthorized
+    )]
+    pub payer : UncheckedAccount<'info>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<CloseResolution>)->Result<()>{
+    let resolution = &ctx.accounts.resolution_proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        resolution.is_finalized || resolution.is_emergency_resolved,
+        ResolutionError::ResolutionNotClosable
+    );
+
+    // a market that is already closed passed its own grace period check
+    if !ctx.accounts.market.data_is_empty() {
+        require_keys_eq!(*ctx.accounts.market.owner, market_registry::ID, ResolutionError::MarketRegistryMismatch);
+        let market = Market::try_deserialize(&mut &ctx.accounts.market.try_borrow_data()?[..])?;
+        require!(market.is_closable(clock.unix_timestamp), ResolutionError::ResolutionNotClosable);
+    }
+
+    let resolution_seeds = &[
+        RESOLUTION_SEED,
+        resolution.market.as_ref(),
+        &[resolution.bump],
+    ];
+    let resolution_signer = &[&resolution_seeds[..]];
+
+    // a dust transfer must not keep the proposal open, claims had the whole grace period
+    let swept = ctx.accounts.bond_vault.amount;
+    if swept > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.bond_vault.to_account_info(),
+                    mint: ctx.accounts.bond_mint.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: resolution.to_account_info(),
+                },
+                resolution_signer,
+            ),
+            swept,
+            ctx.accounts.bond_mint.decimals,
+        )?;
+        msg!("Swept to treasury: {}", swept);
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.bond_vault.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: resolution.to_account_info(),
+        },
+        resolution_signer,
+    ))?;
+
+    msg!("Resolution closed for market: {}", resolution.market);
+    msg!("Rent returned to: {}", resolution.payer);
+
+    emit!(ResolutionClosed{
+        market : resolution.market,
+        resolution : resolution.key(),
+        payer : resolution.payer,
+        swept,
+        timestamp : clock.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{constants::{RESOLUTION_SEED, VOTE_SEED}, error::ResolutionError, events::VoteCommitted, state::{ResolutionProposal, VoteRecord}};
+
+// commit phase of the staker vote, the stake is locked in the bond vault until claim_vote
+#[derive(Accounts)]
+pub struct CommitVote<'info>{
+    #[account(mut)]
+    pub voter : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
+        bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
+        constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [VOTE_SEED, resolution_proposal.key().as_ref(), voter.key().as_ref(), &[resolution_proposal.round]],
+        bump
+    )]
+    pub vote_record : Account<'info,VoteRecord>,
+
+    #[account(
+        mut,
+        constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
+    )]
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == bond_vault.mint,
+        constraint = voter_token_account.owner == voter.key()
+    )]
+    pub voter_token_account : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
+
+    pub system_program : Program<'info,System>
+}
+
+pub fn handler(ctx:Context<CommitVote>,commitment:[u8;32],stake:u64)->Result<()>{
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let clock = Clock::get()?;
+
+    require!(resolution.is_fully_escalated(),ResolutionError::NotAwaitingArbitration);
+    require!(clock.unix_timestamp < resolution.vote_commit_ends_at(),ResolutionError::VoteCommitClosed);
+    require!(stake > 0,ResolutionError::InvalidVoteStake);
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.voter_token_account.to_account_info(),
+            mint: ctx.accounts.bond_mint.to_account_info(),
+            to: ctx.accounts.bond_vault.to_account_info(),
+            authority: ctx.accounts.voter.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, stake, ctx.accounts.bond_mint.decimals)?;
+
+    resolution.vote.total_stake = resolution.vote.total_stake.checked_add(stake).ok_or(ResolutionError::ArithmeticOverflow)?;
+    resolution.vote.voters = resolution.vote.voters.checked_add(1).ok_or(ResolutionError::ArithmeticOverflow)?;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.resolution = resolution.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.commitment = commitment;
+    vote_record.stake = stake;
+    vote_record.revealed_outcome = None;
+    vote_record.round = resolution.round;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    msg!("Vote committed");
+    msg!("Market: {}", resolution.market);
+    msg!("Voter: {}", vote_record.voter);
+    msg!("Stake: {} tokens", format_amount(stake, resolution.bond_decimals));
+    msg!("Reveal from: {}", resolution.vote_commit_ends_at());
+
+    emit!(VoteCommitted {
+        market: resolution.market,
+        voter: vote_record.voter,
+        stake,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use market_registry::ResultOutcome;
 
-use crate::{constants::{DISPUTE_EXTENSION_SECONDS, RESOLUTION_SEED}, error::ResolutionError, events::ProposalDispute, state::{BondContributor, DisputeProposal as DisputeData, ResolutionProposal}};
+use crate::{constants::RESOLUTION_SEED, error::ResolutionError, events::{EscalatedToVote, ProposalDispute}, state::{BondContributor, DisputeProposal as DisputeData, ResolutionProposal}};
 
 #[derive(Accounts)]
 pub struct  DisputeProposal<'info>{
@@ -13,6 +14,7 @@ pub struct  DisputeProposal<'info>{
         mut,
         seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
         bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
         constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
     )]
     pub resolution_proposal : Account<'info,ResolutionProposal>,
@@ -21,16 +23,18 @@ pub struct  DisputeProposal<'info>{
         mut,
         constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
     )]
-    pub bond_vault : Account<'info,TokenAccount>,
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
 
     #[account(
         mut,
         constraint = dispute_bonder_account.mint == bond_vault.mint,
         constraint = dispute_bonder_account.owner == disputer.key()
     )]
-    pub dispute_bonder_account : Account<'info,TokenAccount>,
+    pub dispute_bonder_account : InterfaceAccount<'info,TokenAccount>,
 
-    pub token_program : Program<'info,Token>
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
 }
 
 
@@ -57,17 +61,27 @@ pub fn handler(
     msg!("Time remaining: {} seconds", 
         resolution.dispute_deadline - clock.unix_timestamp);
 
-    // Cannot dispute own proposal 
+    // fully escalated -> stakers vote, no further rounds
+    require!(!resolution.is_fully_escalated(),ResolutionError::MaxDisputesReached);
 
-    require!(ctx.accounts.disputer.key() != resolution.proposer,ResolutionError::CannotDisputeOwnProposal);
-    
-    // Bond amount must be greater that original amoutn 
+    // Cannot dispute the standing round it posted itself, the proposer may counter-escalate a later round
+    require!(ctx.accounts.disputer.key() != resolution.standing_participant(),ResolutionError::CannotDisputeOwnProposal);
+
+    // every round flips the standing outcome
     require!(
-        bond_amount >= resolution.bond_amount,
+        resolution.standing_outcome() != Some(counter_outcome),
+        ResolutionError::DisputeOutcomeUnchanged
+    );
+
+    // Bond amount must at least double the previous round
+    let standing_bond = resolution.standing_bond();
+    let required_bond = standing_bond.checked_mul(2).ok_or(ResolutionError::ArithmeticOverflow)?;
+    require!(
+        bond_amount >= required_bond,
         ResolutionError::InsufficientDisputeBond
     );
-    msg!("Dispute bond: {} USDC", bond_amount as f64 / 1_000_000.0);
-    msg!("Original bond: {} USDC", resolution.bond_amount as f64 / 1_000_000.0);
+    msg!("Dispute bond: {} tokens", format_amount(bond_amount, resolution.bond_decimals));
+    msg!("Previous bond: {} tokens", format_amount(standing_bond, resolution.bond_decimals));
 
     // Check Valid Reason means check its length 
 
@@ -75,34 +89,40 @@ pub fn handler(
 
     // Transfer Bond To Vault
 
-    let transfer_account = Transfer{
+    let transfer_account = TransferChecked{
         from : ctx.accounts.dispute_bonder_account.to_account_info(),
+        mint : ctx.accounts.bond_mint.to_account_info(),
         to  : ctx.accounts.bond_vault.to_account_info(),
         authority : ctx.accounts.disputer.to_account_info()
     };
 
     let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_account);
 
-    token::transfer(cpi_ctx, bond_amount)?;
+    token_interface::transfer_checked(cpi_ctx, bond_amount, ctx.accounts.bond_mint.decimals)?;
     
     msg!("Dispute bond locked successfully");
 
+    let round = resolution.round;
     resolution.bond_contributors.push(BondContributor{
         participant : ctx.accounts.disputer.key(),
-        amount : bond_amount
+        amount : bond_amount,
+        outcome : counter_outcome,
+        claimed : false,
+        round
     });
 
-    // Extend dispute window by 24 hours
+    // Extend dispute window by the market's dispute extension
+    let dispute_extension = resolution.config.dispute_extension;
     let new_deadline = clock
         .unix_timestamp
-        .checked_add(DISPUTE_EXTENSION_SECONDS)
+        .checked_add(dispute_extension)
         .ok_or(ResolutionError::ArithmeticOverflow)?;
 
     resolution.dispute_deadline = new_deadline;
 
     msg!("Dispute window extended");
     msg!("New deadline: {}", new_deadline);
-    msg!("Extension: {} hours", DISPUTE_EXTENSION_SECONDS / 3600);
+    msg!("Extension: {} seconds", dispute_extension);
 
     // Create Dispute Record
 
@@ -117,7 +137,7 @@ pub fn handler(
     resolution.add_dispute(dispute)?;
 
     msg!("Dispute recorded");
-    msg!("Total disputes: {}", resolution.disputes.len());
+    msg!("Escalation round: {}/{}", resolution.disputes.len(), resolution.config.max_escalation_rounds);
 
     // Emit dispute event
     emit!(ProposalDispute {
@@ -131,6 +151,25 @@ pub fn handler(
         timestamp: clock.unix_timestamp,
     });
 
+    if resolution.is_fully_escalated() {
+        let total_bonded = resolution.bond_contributors.iter().map(|c| c.amount).sum();
+        // stakers decide from here, the arbitrator only if their vote fails
+        resolution.vote.started_at = clock.unix_timestamp;
+        msg!("Last escalation round used, staker vote opened");
+        msg!("Commit until: {}", resolution.vote_commit_ends_at());
+        msg!("Reveal until: {}", resolution.vote_ends_at());
+
+        emit!(EscalatedToVote {
+            market: resolution.market,
+            rounds: resolution.disputes.len() as u8,
+            standing_outcome: counter_outcome,
+            total_bonded,
+            commit_ends_at: resolution.vote_commit_ends_at(),
+            reveal_ends_at: resolution.vote_ends_at(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     msg!("Dispute successful");
     Ok(())
 }
\ No newline at end of file
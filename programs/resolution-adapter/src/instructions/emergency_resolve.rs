@@ -1,6 +1,7 @@
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use market_registry::format_amount;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use market_registry::{
     cpi::accounts::EmergencyFinalizeMarket,
     program::MarketRegistry,
@@ -9,7 +10,7 @@ use market_registry::{
 use crate::{
     constants::RESOLUTION_SEED,
     error::ResolutionError,
-    events::EmergencyResolution,
+    events::{BondsSettled, EmergencyResolution},
     state::ResolutionProposal,
 };
 
@@ -24,6 +25,10 @@ pub struct EmergencyResolve<'info> {
     /// CHECK: Validated via CPI - NOT mut here, market_registry handles it
     pub market: UncheckedAccount<'info>,
 
+    /// Protocol admin config (from MarketRegistry)
+    /// CHECK: Validated via CPI - admin must match the config admin
+    pub config: UncheckedAccount<'info>,
+
     /// Market Registry program
     pub market_registry_program: Program<'info, MarketRegistry>,
 
@@ -31,7 +36,8 @@ pub struct EmergencyResolve<'info> {
     #[account(
         mut,
         seeds = [RESOLUTION_SEED, market.key().as_ref()],
-        bump = resolution_proposal.bump
+        bump = resolution_proposal.bump,
+        constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
     )]
     pub resolution_proposal: Account<'info, ResolutionProposal>,
 
@@ -40,9 +46,9 @@ pub struct EmergencyResolve<'info> {
         mut,
         constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
     )]
-    pub bond_vault: Account<'info, TokenAccount>,
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(
@@ -66,14 +72,15 @@ pub fn handler(
         ResolutionError::InvalidOutcome
     );
 
-    let refunded_amount = ctx.accounts.bond_vault.amount;
+    // every bond is refunded in full through claim_bond
+    let settlement = resolution.settle_bonds(None, 0)?;
+    let refunded_amount = settlement.remaining;
 
     if refunded_amount > 0 {
-        msg!("Bond vault balance: {} USDC", refunded_amount as f64 / 1_000_000.0);
-        msg!("⚠️ NOTE: Bonds remain in vault for manual distribution");
-        msg!("Admin must manually refund participants");
+        msg!("Bonds refundable: {} tokens", format_amount(refunded_amount, resolution.bond_decimals));
+        msg!("Participants claim their bonds via claim_bond");
     } else {
-        msg!("No bonds to refund (vault empty)");
+        msg!("No bonds to refund");
     }
 
     // Finalize market via CPI using emergency instruction
@@ -83,6 +90,7 @@ pub fn handler(
         ctx.accounts.market_registry_program.to_account_info(),
         EmergencyFinalizeMarket {
             admin: ctx.accounts.admin.to_account_info(),
+            config: ctx.accounts.config.to_account_info(),
             market: ctx.accounts.market.to_account_info(),
         },
     );
@@ -99,6 +107,10 @@ pub fn handler(
     resolution.is_finalized = true;
     resolution.is_emergency_resolved = true;
 
+    // voters get their full stake back through claim_vote
+    let voter_pool = resolution.vote.close_tally(None);
+    msg!("Voter stake returned in full: {} tokens", format_amount(voter_pool, resolution.bond_decimals));
+
     msg!("Resolution marked as emergency finalized: ✅");
 
     // Emit emergency resolution event
@@ -111,6 +123,16 @@ pub fn handler(
         timestamp: clock.unix_timestamp,
     });
 
+    emit!(BondsSettled {
+        market: resolution.market,
+        outcome: None,
+        winning_bonds: settlement.winning_bonds,
+        slashed_bonds: 0,
+        protocol_fee: 0,
+        reward_pool: 0,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Event emitted: ✅");
     msg!("⚠️ EMERGENCY RESOLUTION COMPLETE ⚠️");
 
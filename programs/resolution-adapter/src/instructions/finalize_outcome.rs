@@ -1,19 +1,17 @@
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use market_registry::{ResultOutcome, program::MarketRegistry,cpi::accounts::FinalizeMarket,};
+use market_registry::{format_amount, whole_tokens};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use market_registry::{program::MarketRegistry,cpi::accounts::FinalizeMarket,state::AdminAuthority,ADMIN_SEED};
 
-use crate::{constants::{ORACLE_REWARD, RESOLUTION_SEED}, error::ResolutionError, events::OutcomeFinalized, state::ResolutionProposal};
+use crate::{constants::{ORACLE_REWARD, RESOLUTION_SEED, REWARD_VAULT_SEED}, error::ResolutionError, events::{BondsSettled, OutcomeFinalized, VoteTallied}, state::ResolutionProposal};
 
 #[derive(Accounts)]
 
 pub struct FinalizeOutcome<'info>{
     
-    #[account(mut)]
-    pub authority : Signer<'info>,
-
-    #[account(mut)]
-    pub reward_authority: Signer<'info>,
+    // permissionless (keepers), the outcome comes from the escalation game and every payout is bound on-chain
+    pub finalizer : Signer<'info>,
 
     #[account(mut)]
     /// CHECK: Validated via CPI to market_registry program
@@ -26,6 +24,7 @@ pub struct FinalizeOutcome<'info>{
         mut,
         seeds = [RESOLUTION_SEED, market.key().as_ref()],
         bump = resolution_proposal.bump,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
         constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
     )]
     pub resolution_proposal : Account<'info,ResolutionProposal>,
@@ -34,37 +33,91 @@ pub struct FinalizeOutcome<'info>{
         mut,
         constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
     )]
-    pub bond_vault : Account<'info,TokenAccount>,
-
-     /// Winner's USDC account (receives bond + reward)
-     #[account(mut)]
-     pub winner_account: Account<'info, TokenAccount>,
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+     /// CHECK: compared against the winning bond in the handler
+     pub winner : UncheckedAccount<'info>,
+
+     /// Winner's bond mint ATA (receives the reward, bonds are paid out through claim_bond)
+     #[account(
+         mut,
+         associated_token::mint = bond_mint,
+         associated_token::authority = winner,
+         associated_token::token_program = token_program
+     )]
+     pub winner_account: InterfaceAccount<'info, TokenAccount>,
  
-     /// Protocol treasury (source of rewards)
-     #[account(mut)]
-     pub protocol_treasury: Account<'info, TokenAccount>,
+     /// Reward vault of the bond mint (source of rewards)
+     #[account(
+         mut,
+         seeds = [REWARD_VAULT_SEED, bond_mint.key().as_ref()],
+         bump
+     )]
+     pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+
+     /// Protocol admin config (from MarketRegistry), owner of the treasury
+     #[account(
+         seeds = [ADMIN_SEED],
+         bump = config.bump,
+         seeds::program = market_registry::ID
+     )]
+     pub config : Account<'info,AdminAuthority>,
+
+     // receives the protocol cut of slashed bonds
+     #[account(
+         mut,
+         constraint = treasury.owner == config.admin @ ResolutionError::TreasuryMismatch,
+         constraint = treasury.mint == bond_mint.key() @ ResolutionError::TreasuryMismatch
+     )]
+     pub treasury : InterfaceAccount<'info,TokenAccount>,
+
+     pub bond_mint: InterfaceAccount<'info, Mint>,
  
-     pub token_program: Program<'info, Token>,
+     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx:Context<FinalizeOutcome>,final_outcome : ResultOutcome)->Result<()>{
+pub fn handler(ctx:Context<FinalizeOutcome>)->Result<()>{
     let resolution = &mut ctx.accounts.resolution_proposal;
     let clock = Clock::get()?;
 
+    // a fully escalated proposal is settled by the vote (or the ruling), everything else waits for the last round to go unanswered
+    if !resolution.is_fully_escalated() {
+        require!(
+            resolution.is_dispute_window_closed(clock.unix_timestamp),ResolutionError::DisputeWindowOpen
+        );
+    }
+    let final_outcome = resolution.final_outcome(clock.unix_timestamp)?;
+
+    // voter stakes stay in the bond vault until claim_vote, only the rest goes to the winner
+    if resolution.is_fully_escalated() {
+        let vote_result = match resolution.arbitrated_outcome {
+            Some(_) => None,
+            None => Some(final_outcome)
+        };
+        let voter_pool = resolution.vote.close_tally(vote_result);
+
+        msg!("Vote tallied: {:?}", vote_result);
+        msg!("Voter pool: {} tokens", format_amount(voter_pool, resolution.bond_decimals));
+
+        emit!(VoteTallied {
+            market: resolution.market,
+            result: vote_result,
+            tally: resolution.vote.tally.clone(),
+            total_stake: resolution.vote.total_stake,
+            voter_pool,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     msg!("🏁 FINALIZING MARKET RESOLUTION 🏁");
     msg!("Market: {}", resolution.market);
     msg!("Final outcome: {:?}", final_outcome);
 
-    require!(
-        resolution.is_dispute_window_closed(clock.unix_timestamp),ResolutionError::DisputeWindowOpen
-    );
-
     msg!("Current time: {}", clock.unix_timestamp);
     msg!("Dispute deadline: {}", resolution.dispute_deadline);
     msg!("Dispute window closed:");
 
     let winning_proposer : Pubkey;
-    let slashed_amount : u64;
 
     if !resolution.is_disputed {
          // NO DISPUTE: Accept original proposal
@@ -72,24 +125,19 @@ pub fn handler(ctx:Context<FinalizeOutcome>,final_outcome : ResultOutcome)->Resu
          msg!("Accepting original proposal");
 
          winning_proposer = resolution.proposer; 
-         slashed_amount = 0;
 
         msg!("Winner: {}", winning_proposer);
     }else {
         // DISPUTED: Determine winner based on final outcome
         msg!("Market was disputed");
-        msg!("Total disputes: {}", resolution.disputes.len());
+        msg!("Escalation rounds: {}", resolution.disputes.len());
+        msg!("Arbitrated: {}", resolution.arbitrated_outcome.is_some());
         msg!("Determining winner...");
         
         if Some(final_outcome) == resolution.proposed_outcome {
             winning_proposer = resolution.proposer;
             msg!("Original proposer was correct");
             msg!("Winner: {}", winning_proposer);
-
-            slashed_amount = resolution.disputes.iter().map(|d|d.bond_amount).sum();
-            
-            msg!("Slashing {} disputer bonds", resolution.disputes.len());
-            msg!("Slashed amount: {} USDC", slashed_amount as f64 / 1_000_000.0);
         }else {
             // Disputer is correct 
 
@@ -99,28 +147,29 @@ pub fn handler(ctx:Context<FinalizeOutcome>,final_outcome : ResultOutcome)->Resu
 
             msg!("Disputer was correct");
             msg!("Winner: {}", winning_proposer);
-
-            slashed_amount = resolution.bond_amount + 
-                             resolution.disputes.iter()
-                             .filter(|d| d.counter_outcome!=final_outcome)
-                             .map(|d|d.bond_amount)
-                             .sum::<u64>();
-
-        msg!("Slashing original proposer + {} wrong disputers", 
-        resolution.disputes.iter().filter(|d| d.counter_outcome != final_outcome).count());
-        msg!("Slashed amount: {} USDC", slashed_amount as f64 / 1_000_000.0);
         }
     }
-
-    // calculate total vault payout 
-    let bond_vault_balance = ctx.accounts.bond_vault.amount;
-
-    let total_payout = bond_vault_balance.checked_add(ORACLE_REWARD).ok_or(ResolutionError::ArithmeticOverflow)?;
-
-    msg!("Payout calculation:");
-    msg!("  Bond vault balance: {} USDC", bond_vault_balance as f64 / 1_000_000.0);
-    msg!("  Oracle reward: {} USDC", ORACLE_REWARD as f64 / 1_000_000.0);
-    msg!("  Total payout: {} USDC", total_payout as f64 / 1_000_000.0);
+    require_keys_eq!(ctx.accounts.winner.key(), winning_proposer, ResolutionError::WinnerMismatch);
+
+    // whatever the vault holds beyond the bonds, the voter pool and the refunds of closed rounds
+    // is slashed vote stake for the winning bonds
+    let vote_share = ctx.accounts.bond_vault.amount
+        .checked_sub(resolution.vote.pool)
+        .and_then(|amount| amount.checked_sub(resolution.total_bonds()))
+        .and_then(|amount| amount.checked_sub(resolution.refund_pool))
+        .ok_or(ResolutionError::ArithmeticOverflow)?;
+    let settlement = resolution.settle_bonds(Some(final_outcome), vote_share)?;
+
+    msg!("Bond settlement:");
+    msg!("  Winning bonds: {} tokens", format_amount(settlement.winning_bonds, resolution.bond_decimals));
+    msg!("  Slashed bonds: {} tokens", format_amount(settlement.slashed_bonds, resolution.bond_decimals));
+    msg!("  Protocol fee: {} tokens", format_amount(settlement.protocol_fee, resolution.bond_decimals));
+    msg!("  Reward pool: {} tokens", format_amount(settlement.reward_pool, resolution.bond_decimals));
+
+    // an underfunded reward vault pays what it has instead of blocking finalization
+    let oracle_reward = whole_tokens(ORACLE_REWARD,resolution.bond_decimals).ok_or(ResolutionError::ArithmeticOverflow)?;
+    let reward_amount = ctx.accounts.reward_vault.amount.min(oracle_reward);
+    msg!("  Oracle reward: {} tokens", format_amount(reward_amount, resolution.bond_decimals));
 
     let resolution_seeds = &[
         RESOLUTION_SEED,
@@ -129,42 +178,60 @@ pub fn handler(ctx:Context<FinalizeOutcome>,final_outcome : ResultOutcome)->Resu
     ];
     let resolution_signer = &[&resolution_seeds[..]];
 
-      // Transfer entire bond vault balance to winner
-      msg!("Transferring bonds to winner...");
-      let bond_transfer_ctx = CpiContext::new_with_signer(
-          ctx.accounts.token_program.to_account_info(),
-          Transfer {
-              from: ctx.accounts.bond_vault.to_account_info(),
-              to: ctx.accounts.winner_account.to_account_info(),
-              authority: resolution.to_account_info(),
-          },
-          resolution_signer,
-      );
-      token::transfer(bond_transfer_ctx, bond_vault_balance)?;
-
-          // Transfer reward from protocol treasury
-    msg!("Transferring oracle reward...");
-    let reward_transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.protocol_treasury.to_account_info(),
-            to: ctx.accounts.winner_account.to_account_info(),
-            authority: ctx.accounts.reward_authority.to_account_info(),
-        },
-    );
-    token::transfer(reward_transfer_ctx, ORACLE_REWARD)?;
-
-    msg!("Reward transferred: ✅");
+    // bonds stay in the vault until claim_bond, only the protocol cut leaves now
+    if settlement.protocol_fee > 0 {
+        msg!("Transferring protocol fee...");
+        let fee_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.bond_vault.to_account_info(),
+                mint: ctx.accounts.bond_mint.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: resolution.to_account_info(),
+            },
+            resolution_signer,
+        );
+        token_interface::transfer_checked(fee_transfer_ctx, settlement.protocol_fee, ctx.accounts.bond_mint.decimals)?;
+    }
+
+    // Transfer reward from the reward vault
+    if reward_amount > 0 {
+        msg!("Transferring oracle reward...");
+        let bond_mint_key = ctx.accounts.bond_mint.key();
+        let reward_vault_seeds = &[
+            REWARD_VAULT_SEED,
+            bond_mint_key.as_ref(),
+            &[ctx.bumps.reward_vault],
+        ];
+        let reward_vault_signer = &[&reward_vault_seeds[..]];
+
+        let reward_transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                mint: ctx.accounts.bond_mint.to_account_info(),
+                to: ctx.accounts.winner_account.to_account_info(),
+                authority: ctx.accounts.reward_vault.to_account_info(),
+            },
+            reward_vault_signer,
+        );
+        token_interface::transfer_checked(reward_transfer_ctx, reward_amount, ctx.accounts.bond_mint.decimals)?;
+
+        msg!("Reward transferred: ✅");
+    } else {
+        msg!("Reward vault empty, no reward paid");
+    }
 
         // Finalize market in MarketRegistry via CPI
         msg!("Finalizing market in MarketRegistry...");
         
+        // the market only accepts its own resolution proposal PDA as the finalizer
         let fin_mkt = FinalizeMarket{
-            resolution_adapter : ctx.accounts.authority.to_account_info(),
+            resolution_adapter : resolution.to_account_info(),
             market:ctx.accounts.market.to_account_info()
         };
 
-        let cpi_ctx = CpiContext::new(ctx.accounts.market_registery_program.to_account_info(), fin_mkt);
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.market_registery_program.to_account_info(), fin_mkt, resolution_signer);
 
         market_registry::cpi::finalize_market(cpi_ctx, final_outcome)?;
         msg!("Market finalized in MarketRegistry: ✅");
@@ -177,17 +244,28 @@ pub fn handler(ctx:Context<FinalizeOutcome>,final_outcome : ResultOutcome)->Resu
     // Emit finalization event
     emit!(OutcomeFinalized {
         market: resolution.market,
+        finalizer: ctx.accounts.finalizer.key(),
         outcome: final_outcome,
         winning_proposer,
         was_disputed: resolution.is_disputed,
-        slashed_amount,
-        reward_amount: ORACLE_REWARD,
+        slashed_amount: settlement.slashed_bonds,
+        reward_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(BondsSettled {
+        market: resolution.market,
+        outcome: settlement.outcome,
+        winning_bonds: settlement.winning_bonds,
+        slashed_bonds: settlement.slashed_bonds,
+        protocol_fee: settlement.protocol_fee,
+        reward_pool: settlement.reward_pool,
         timestamp: clock.unix_timestamp,
     });
 
     msg!("🎉 FINALIZATION COMPLETE 🎉");
     msg!("Final outcome: {:?}", final_outcome);
     msg!("Winner: {}", winning_proposer);
-    msg!("Total earned: {} USDC", total_payout as f64 / 1_000_000.0);
+    msg!("Bonds claimable: {} tokens", format_amount(settlement.remaining, resolution.bond_decimals));
     Ok(())
 }
\ No newline at end of file
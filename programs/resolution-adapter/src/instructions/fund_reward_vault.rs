@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+use crate::{constants::REWARD_VAULT_SEED, error::ResolutionError, events::RewardVaultFunded};
+
+// protocol admin tops up the oracle rewards
+#[derive(Accounts)]
+pub struct FundRewardVault<'info>{
+    pub funder : Signer<'info>,
+
+    /// Protocol admin config (from MarketRegistry)
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == funder.key() @ ResolutionError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == bond_mint.key() @ ResolutionError::BondMintMismatch,
+        constraint = funder_token_account.owner == funder.key() @ ResolutionError::Unauthorized
+    )]
+    pub funder_token_account : InterfaceAccount<'info,TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, bond_mint.key().as_ref()],
+        bump
+    )]
+    pub reward_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<FundRewardVault>,amount:u64)->Result<()>{
+    require!(amount > 0,ResolutionError::InvalidRewardFunding);
+    let clock = Clock::get()?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            mint: ctx.accounts.bond_mint.to_account_info(),
+            to: ctx.accounts.reward_vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.bond_mint.decimals)?;
+
+    let balance = ctx.accounts.reward_vault.amount.checked_add(amount).ok_or(ResolutionError::ArithmeticOverflow)?;
+
+    msg!("Reward vault funded");
+    msg!("Funder: {}", ctx.accounts.funder.key());
+    msg!("Amount: {} tokens", format_amount(amount, ctx.accounts.bond_mint.decimals));
+    msg!("Balance: {} tokens", format_amount(balance, ctx.accounts.bond_mint.decimals));
+
+    emit!(RewardVaultFunded {
+        bond_mint: ctx.accounts.bond_mint.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        balance,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
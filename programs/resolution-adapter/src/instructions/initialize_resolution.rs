@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{constants::*, state::{MarketCategory, ResolutionProposal}};
+use market_registry::ResolutionConfig;
+
+use crate::{constants::*, error::ResolutionError, state::{MarketCategory, ResolutionProposal}};
 
 
 #[derive(Accounts)]
@@ -9,7 +11,11 @@ use crate::{constants::*, state::{MarketCategory, ResolutionProposal}};
 pub struct InitializeResolution<'info>{
     #[account(mut)]
     pub authority : Signer<'info>,
-    /// CHECK : validate via seeds  
+    /// CHECK : the market PDA signs, so the proposal can only be created by market creation
+    #[account(
+        signer,
+        owner = market_registry::ID @ ResolutionError::MarketRegistryMismatch
+    )]
     pub market : UncheckedAccount<'info>,
 
     #[account(
@@ -27,20 +33,22 @@ pub struct InitializeResolution<'info>{
         seeds  = [BOND_VAULT_SEED, market.key().as_ref()],
         bump,
         token::mint = bond_mint,
-        token::authority = resolution_proposal 
+        token::authority = resolution_proposal,
+        token::token_program = token_program
     )]
-    pub bond_vault : Account<'info,TokenAccount>,
-    /// CHECK : validation by constraint
-    pub bond_mint : UncheckedAccount<'info>,
+    pub bond_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
 
     pub system_program : Program<'info,System>,
 
-    pub token_program : Program<'info,Token>,
+    pub token_program : Interface<'info,TokenInterface>,
 
     pub rent : Sysvar<'info,Rent>
 }
 
-pub fn handler(ctx:Context<InitializeResolution>,category : MarketCategory)->Result<()>{
+// config was validated by the registry against the protocol bounds
+pub fn handler(ctx:Context<InitializeResolution>,category : MarketCategory,config : ResolutionConfig)->Result<()>{
     let resolution = &mut ctx.accounts.resolution_proposal;
     let clock = Clock::get()?;
 
@@ -58,13 +66,22 @@ pub fn handler(ctx:Context<InitializeResolution>,category : MarketCategory)->Res
     resolution.is_finalized = false;
     resolution.disputes = Vec::new();
     resolution.bond_vault = ctx.accounts.bond_vault.key();
+    resolution.bond_mint = ctx.accounts.bond_mint.key();
+    resolution.bond_decimals = ctx.accounts.bond_mint.decimals;
     resolution.bump = ctx.bumps.resolution_proposal;
     resolution.bond_contributors = Vec::new();  
     resolution.is_emergency_resolved = false; 
+    resolution.payer = ctx.accounts.authority.key();
+    resolution.config = config;
+    resolution.round = 1;
+    resolution.round_opened_at = 0;
+    resolution.round_history = Vec::new();
+    resolution.refund_pool = 0;
 
     msg!("Resolution proposal initialized for market: {}", ctx.accounts.market.key());
     msg!("Category: {:?}", category);
     msg!("Bond vault: {}", ctx.accounts.bond_vault.key());
+    msg!("Dispute window: {} seconds", config.dispute_window);
 
     Ok(())
 }
\ No newline at end of file
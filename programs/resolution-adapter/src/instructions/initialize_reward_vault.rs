@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use market_registry::{state::AdminAuthority, ADMIN_SEED};
+
+use crate::{constants::REWARD_VAULT_SEED, error::ResolutionError};
+
+// one reward vault per bond mint, it owns itself so only finalize_outcome can spend from it (protocol admin)
+#[derive(Accounts)]
+pub struct InitializeRewardVault<'info>{
+    #[account(mut)]
+    pub admin : Signer<'info>,
+
+    /// Protocol admin config (from MarketRegistry)
+    #[account(
+        seeds = [ADMIN_SEED],
+        bump = config.bump,
+        seeds::program = market_registry::ID,
+        constraint = config.admin == admin.key() @ ResolutionError::Unauthorized
+    )]
+    pub config : Account<'info,AdminAuthority>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [REWARD_VAULT_SEED, bond_mint.key().as_ref()],
+        bump,
+        token::mint = bond_mint,
+        token::authority = reward_vault,
+        token::token_program = token_program
+    )]
+    pub reward_vault : InterfaceAccount<'info,TokenAccount>,
+
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub system_program : Program<'info,System>,
+
+    pub token_program : Interface<'info,TokenInterface>
+}
+
+pub fn handler(ctx:Context<InitializeRewardVault>)->Result<()>{
+    msg!("Reward vault initialized");
+    msg!("Bond mint: {}", ctx.accounts.bond_mint.key());
+    msg!("Vault: {}", ctx.accounts.reward_vault.key());
+    Ok(())
+}
@@ -1,15 +1,70 @@
 pub mod initialize_resolution;
+pub mod initialize_reward_vault;
+pub mod fund_reward_vault;
 pub mod propose_crypto_outcome;
 pub mod propose_sports_outcome;
 pub mod dispute_proposal;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod claim_vote;
+pub mod arbitrate_dispute;
+pub mod open_proposal_round;
 pub mod finalize_outcome;
+pub mod claim_bond;
 pub mod emergency_resolve;
+pub mod close_resolution;
 
 
-// Re-export ALL items from each module at the instructions level
-pub use initialize_resolution::*;
-pub use propose_crypto_outcome::*;
-pub use propose_sports_outcome::*;
-pub use dispute_proposal::*;
-pub use finalize_outcome::*;
-pub use emergency_resolve::*;
+pub use initialize_resolution::InitializeResolution;
+pub use initialize_reward_vault::InitializeRewardVault;
+pub use fund_reward_vault::FundRewardVault;
+pub use propose_crypto_outcome::ProposeCryptoOutcome;
+pub use propose_sports_outcome::{ProposeSportsOutcome, SportsOracleData};
+pub use dispute_proposal::DisputeProposal;
+pub use commit_vote::CommitVote;
+pub use reveal_vote::RevealVote;
+pub use claim_vote::ClaimVote;
+pub use arbitrate_dispute::ArbitrateDispute;
+pub use open_proposal_round::OpenProposalRound;
+pub use finalize_outcome::FinalizeOutcome;
+pub use claim_bond::ClaimBond;
+pub use emergency_resolve::EmergencyResolve;
+pub use close_resolution::CloseResolution;
+
+// client account modules generated by #[derive(Accounts)], the #[program] macro resolves them from the crate root
+pub(crate) use {
+    initialize_resolution::__client_accounts_initialize_resolution,
+    initialize_reward_vault::__client_accounts_initialize_reward_vault,
+    fund_reward_vault::__client_accounts_fund_reward_vault,
+    propose_crypto_outcome::__client_accounts_propose_crypto_outcome,
+    propose_sports_outcome::__client_accounts_propose_sports_outcome,
+    dispute_proposal::__client_accounts_dispute_proposal,
+    commit_vote::__client_accounts_commit_vote,
+    reveal_vote::__client_accounts_reveal_vote,
+    claim_vote::__client_accounts_claim_vote,
+    arbitrate_dispute::__client_accounts_arbitrate_dispute,
+    open_proposal_round::__client_accounts_open_proposal_round,
+    finalize_outcome::__client_accounts_finalize_outcome,
+    claim_bond::__client_accounts_claim_bond,
+    emergency_resolve::__client_accounts_emergency_resolve,
+    close_resolution::__client_accounts_close_resolution,
+};
+
+#[cfg(feature = "cpi")]
+pub(crate) use {
+    initialize_resolution::__cpi_client_accounts_initialize_resolution,
+    initialize_reward_vault::__cpi_client_accounts_initialize_reward_vault,
+    fund_reward_vault::__cpi_client_accounts_fund_reward_vault,
+    propose_crypto_outcome::__cpi_client_accounts_propose_crypto_outcome,
+    propose_sports_outcome::__cpi_client_accounts_propose_sports_outcome,
+    dispute_proposal::__cpi_client_accounts_dispute_proposal,
+    commit_vote::__cpi_client_accounts_commit_vote,
+    reveal_vote::__cpi_client_accounts_reveal_vote,
+    claim_vote::__cpi_client_accounts_claim_vote,
+    arbitrate_dispute::__cpi_client_accounts_arbitrate_dispute,
+    open_proposal_round::__cpi_client_accounts_open_proposal_round,
+    finalize_outcome::__cpi_client_accounts_finalize_outcome,
+    claim_bond::__cpi_client_accounts_claim_bond,
+    emergency_resolve::__cpi_client_accounts_emergency_resolve,
+    close_resolution::__cpi_client_accounts_close_resolution,
+};
\ No newline at end of file
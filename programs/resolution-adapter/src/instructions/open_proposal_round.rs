@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use market_registry::state::Market;
+use market_registry::{program::MarketRegistry,cpi::accounts::ExtendResolutionWindow};
+
+use crate::{
+    constants::{MAX_PROPOSAL_ROUNDS, RESOLUTION_SEED},
+    error::ResolutionError,
+    events::ProposalRoundOpened,
+    state::{ProposalRound, ResolutionProposal, RoundCloseReason, VoteRound},
+};
+
+// permissionless: closes an empty, stale or overturned proposal round so a new proposal can be posted
+#[derive(Accounts)]
+pub struct OpenProposalRound<'info>{
+    pub caller : Signer<'info>,
+
+    // read for expiry, resolution window and valid outcomes, the window is extended via CPI when the new round needs it
+    #[account(mut)]
+    pub market : Account<'info,Market>,
+
+    pub market_registry_program : Program<'info,MarketRegistry>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, market.key().as_ref()],
+        bump = resolution_proposal.bump,
+        constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>
+}
+
+pub fn handler(ctx:Context<OpenProposalRound>)->Result<()>{
+    let market = &ctx.accounts.market;
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    let reason = if resolution.is_round_empty(current_time, market) {
+        RoundCloseReason::NoProposal
+    } else if resolution.is_round_stale(current_time, market) {
+        RoundCloseReason::Stale
+    } else if resolution.is_round_overturned(current_time, market) {
+        RoundCloseReason::DisputeWon
+    } else {
+        return err!(ResolutionError::RoundStillActive);
+    };
+    require!(resolution.round < MAX_PROPOSAL_ROUNDS,ResolutionError::MaxProposalRoundsReached);
+
+    require!(current_time <= market.resolution_window_end(),ResolutionError::ResolutionWindowExhausted);
+
+    // a proposal at the end of the new round's window still has to settle before the registry stops accepting it
+    let config = resolution.config;
+    let proposal_window_end = current_time
+        .checked_add(config.proposal_window)
+        .ok_or(ResolutionError::ArithmeticOverflow)?;
+    let latest_settlement = config.round_duration()
+        .and_then(|duration| duration.checked_add(proposal_window_end))
+        .ok_or(ResolutionError::ArithmeticOverflow)?;
+    if latest_settlement > market.resolution_window_end() {
+        // the market only accepts its own resolution proposal PDA as the extender
+        let resolution_seeds = &[
+            RESOLUTION_SEED,
+            resolution.market.as_ref(),
+            &[resolution.bump],
+        ];
+        let resolution_signer = &[&resolution_seeds[..]];
+
+        let extend_window = ExtendResolutionWindow{
+            resolution_adapter : resolution.to_account_info(),
+            market : market.to_account_info()
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.market_registry_program.to_account_info(), extend_window, resolution_signer);
+        market_registry::cpi::extend_resolution_window(cpi_ctx, latest_settlement)?;
+        msg!("Resolution window extended to: {}", latest_settlement);
+    }
+
+    // nobody is at fault for a round that cannot settle: bonds go back in full,
+    // an overturned proposal still loses its bond to the dispute that beat it
+    let round_bonds = match reason {
+        RoundCloseReason::DisputeWon => {
+            let outcome = resolution.final_outcome(current_time)?;
+            resolution.settle_overturned_round(outcome)?
+        }
+        _ => resolution.total_bonds()
+    };
+    // vote stakes of a closed round go back in full
+    let vote_stake = resolution.vote.close_tally(None);
+    let refunded_amount = round_bonds
+        .checked_add(vote_stake)
+        .ok_or(ResolutionError::ArithmeticOverflow)?;
+    resolution.refund_pool = resolution.refund_pool
+        .checked_add(refunded_amount)
+        .ok_or(ResolutionError::ArithmeticOverflow)?;
+
+    let closed_round = ProposalRound{
+        round : resolution.round,
+        proposer : resolution.proposer,
+        proposed_outcome : resolution.proposed_outcome,
+        bond_amount : resolution.bond_amount,
+        disputes : resolution.disputes.len() as u8,
+        opened_at : resolution.round_opened_at(market),
+        closed_at : current_time,
+        reason
+    };
+    resolution.round_history.push(closed_round);
+
+    resolution.round += 1;
+    resolution.round_opened_at = current_time;
+    resolution.proposer = Pubkey::default();
+    resolution.proposed_outcome = None;
+    resolution.bond_amount = 0;
+    resolution.proposal_timestamp = 0;
+    resolution.dispute_deadline = 0;
+    resolution.data_source = Vec::new();
+    resolution.is_disputed = false;
+    resolution.disputes = Vec::new();
+    resolution.arbitrated_outcome = None;
+    resolution.vote = VoteRound::default();
+
+    msg!("Proposal round opened");
+    msg!("Market: {}", resolution.market);
+    msg!("Round: {}", resolution.round);
+    msg!("Previous round closed: {:?}", reason);
+    msg!("Refundable: {} tokens", format_amount(refunded_amount, resolution.bond_decimals));
+    msg!("Proposal window ends: {}", proposal_window_end);
+
+    emit!(ProposalRoundOpened {
+        market: resolution.market,
+        round: resolution.round,
+        reason,
+        refunded_amount,
+        proposal_window_end,
+        timestamp: current_time,
+    });
+    Ok(())
+}
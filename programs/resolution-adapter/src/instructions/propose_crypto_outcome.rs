@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use market_registry::{ResultOutcome, cpi::accounts::AssertMarketExpired, program::MarketRegistry};
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use market_registry::{ResultOutcome, cpi::accounts::AssertMarketExpired, program::MarketRegistry, state::Market};
 
 use crate::{
-    constants::{DISPUTE_WINDOW_SECONDS, MAX_DATA_SOURCES, MIN_PROPOSAL_BOND, RESOLUTION_SEED},
+    constants::{MAX_DATA_SOURCES, RESOLUTION_SEED},
     error::ResolutionError,
     events::{CryptoPriceValidated, ProposalSumbitted},
     state::{BondContributor, DataSource, MarketCategory, OracleType, OracleValue, PriceCondition, ResolutionProposal},
@@ -19,8 +20,8 @@ pub struct ProposeCryptoOutcome<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
     
-    /// CHECK: Validated via CPI to market_registry program
-    pub market: UncheckedAccount<'info>,
+    // read for the scalar bounds, expiry validated via CPI
+    pub market: Account<'info, Market>,
 
     pub market_registry_program: Program<'info, MarketRegistry>,
 
@@ -29,6 +30,7 @@ pub struct ProposeCryptoOutcome<'info> {
         seeds = [RESOLUTION_SEED, market.key().as_ref()],
         bump = resolution_proposal.bump,
         constraint = resolution_proposal.category == MarketCategory::Crypto @ ResolutionError::InvalidMarketCategory,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
         constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
     )]
     pub resolution_proposal: Account<'info, ResolutionProposal>,
@@ -37,16 +39,18 @@ pub struct ProposeCryptoOutcome<'info> {
         mut,
         constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
     )]
-    pub bond_vault: Account<'info, TokenAccount>,
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = proposer_bond_account.mint == bond_vault.mint,
         constraint = proposer_bond_account.owner == proposer.key()
     )]
-    pub proposer_bond_account: Account<'info, TokenAccount>,
+    pub proposer_bond_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>
+    pub bond_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>
 }
 
 pub fn handler(
@@ -57,10 +61,11 @@ pub fn handler(
     bond_amount: u64
 ) -> Result<()> {
     let resolution = &mut ctx.accounts.resolution_proposal;
+    let config = resolution.config;
     let clock = Clock::get()?;
 
     // Validate bond amount
-    require!(bond_amount >= MIN_PROPOSAL_BOND, ResolutionError::InsufficientBond);
+    require!(bond_amount >= config.min_proposal_bond, ResolutionError::InsufficientBond);
     
     // Check for no existing proposal
     require!(resolution.bond_amount == 0, ResolutionError::ProposalAlreadyExists);
@@ -129,7 +134,7 @@ pub fn handler(
             msg!("Reading Pyth feed {}: {}", idx + 1, feed_id);
 
             let price_update_account = &ctx.remaining_accounts[idx];
-            let price_data = read_pyth_price(price_update_account, feed_id)?;
+            let price_data = read_pyth_price(price_update_account, feed_id, config.max_oracle_staleness)?;
             
             msg!(
                 "Pyth price: {} (confidence: {}, expo: {}, timestamp: {})",
@@ -139,7 +144,7 @@ pub fn handler(
                 price_data.timestamp
             );
             
-            validate_pyth_price(&price_data, clock.unix_timestamp)?;
+            validate_pyth_price(&price_data, clock.unix_timestamp, config.max_oracle_staleness)?;
             
             let normalized_price = normalize_price(price_data.price, price_data.expo)?;
             prices.push(normalized_price);
@@ -169,18 +174,27 @@ pub fn handler(
     let consensus_price = calcualte_median(&prices)?;
 
     // Validate price agreement
-    validate_price_agreement(&prices, consensus_price)?;
+    validate_price_agreement(&prices, consensus_price, config.max_price_deviation_bps)?;
 
     // Determine outcome based on condition
-    let outcome = if condition.is_met(consensus_price) {
+    // scalar markets resolve to the consensus price itself, the escrow splits LONG/SHORT linearly
+    let outcome = if let Some(bounds) = ctx.accounts.market.scalar_bounds {
+        require!(
+            condition == PriceCondition::Between { min: bounds.lower, max: bounds.upper },
+            ResolutionError::ScalarConditionMismatch
+        );
+        msg!("Scalar consensus price: {} (range {} -> {})", consensus_price, bounds.lower, bounds.upper);
+        ResultOutcome::Scalar(consensus_price)
+    } else if condition.is_met(consensus_price) {
         ResultOutcome::Yes
     } else {
         ResultOutcome::No
     };
 
     // Transfer bond to vault
-    let transfer_account = Transfer {
+    let transfer_account = TransferChecked {
         from: ctx.accounts.proposer_bond_account.to_account_info(),
+        mint: ctx.accounts.bond_mint.to_account_info(),
         to: ctx.accounts.bond_vault.to_account_info(),
         authority: ctx.accounts.proposer.to_account_info()
     };
@@ -189,14 +203,18 @@ pub fn handler(
         ctx.accounts.token_program.to_account_info(),
         transfer_account
     );
-    token::transfer(cpi_ctx, bond_amount)?;
+    token_interface::transfer_checked(cpi_ctx, bond_amount, ctx.accounts.bond_mint.decimals)?;
 
-    msg!("Bond locked: {} USDC", bond_amount as f64 / 1_000_000.0);
+    msg!("Bond locked: {} tokens", format_amount(bond_amount, resolution.bond_decimals));
 
     // Track bond contribution
+    let round = resolution.round;
     resolution.bond_contributors.push(BondContributor {
         participant: ctx.accounts.proposer.key(),
-        amount: bond_amount
+        amount: bond_amount,
+        outcome,
+        claimed: false,
+        round
     });
 
     // Update resolution proposal
@@ -205,11 +223,11 @@ pub fn handler(
     resolution.proposal_timestamp = clock.unix_timestamp;
     resolution.bond_amount = bond_amount;
     resolution.dispute_deadline = clock.unix_timestamp
-        .checked_add(DISPUTE_WINDOW_SECONDS)
+        .checked_add(config.dispute_window)
         .ok_or(ResolutionError::ArithmeticOverflow)?;
     resolution.data_source = data_source;
 
-    msg!("Dispute window: {} seconds", DISPUTE_WINDOW_SECONDS);
+    msg!("Dispute window: {} seconds", config.dispute_window);
     msg!("Dispute deadline: {}", resolution.dispute_deadline);
 
     // Emit proposal event
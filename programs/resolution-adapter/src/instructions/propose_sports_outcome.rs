@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use market_registry::{cpi::accounts::AssertMarketExpired, program::MarketRegistry};
+use market_registry::format_amount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use market_registry::{cpi::accounts::AssertMarketExpired, program::MarketRegistry, state::Market};
 
-use crate::{constants::{DISPUTE_WINDOW_SECONDS, MAX_DATA_SOURCES, MAX_ORACLE_STALENESS_SECONDS, MIN_PROPOSAL_BOND, RESOLUTION_SEED}, error::ResolutionError, events::{ProposalSumbitted, SportsEventvalidated}, state::{BondContributor, DataSource, MarketCategory, OracleType, ResolutionProposal, SportsEventType}, utils::{determine_sports_outcome, find_consensus, validate_sports_consensus}};
+use crate::{constants::{MAX_DATA_SOURCES, RESOLUTION_SEED}, error::ResolutionError, events::{ProposalSumbitted, SportsEventvalidated}, state::{BondContributor, DataSource, MarketCategory, OracleType, ResolutionProposal, SportsEventType}, utils::{determine_categorical_outcome, determine_sports_outcome, find_consensus, validate_sports_consensus}};
 
 #[derive(Accounts)]
 
@@ -10,8 +11,8 @@ pub struct  ProposeSportsOutcome<'info>{
     #[account(mut)]
     pub proposer : Signer<'info>,
 
-    /// CHECK: Validated via CPI to market_registry program
-    pub market : UncheckedAccount<'info>,
+    // read for the outcome labels of categorical markets, expiry validated via CPI
+    pub market : Account<'info,Market>,
 
     pub market_registery_program : Program<'info,MarketRegistry>,
 
@@ -20,6 +21,7 @@ pub struct  ProposeSportsOutcome<'info>{
         seeds = [RESOLUTION_SEED , market.key().as_ref()],
         bump = resolution_proposal.bump,
         constraint = resolution_proposal.category == MarketCategory::Sports @ ResolutionError::InvalidMarketCategory,
+        has_one = bond_mint @ ResolutionError::BondMintMismatch,
         constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
     )]
     pub resolution_proposal : Account<'info,ResolutionProposal>,
@@ -28,16 +30,18 @@ pub struct  ProposeSportsOutcome<'info>{
         mut,
         constraint = bond_vault.key() == resolution_proposal.bond_vault @ ResolutionError::BondVaultMismatch
     )]
-    pub bond_vault  : Account<'info,TokenAccount>,
+    pub bond_vault  : InterfaceAccount<'info,TokenAccount>,
 
     #[account(
         mut ,
         constraint = proposer_bond_account.mint == bond_vault.mint,
         constraint = proposer_bond_account.owner == proposer.key()
     )]
-    pub proposer_bond_account : Account<'info,TokenAccount>,
+    pub proposer_bond_account : InterfaceAccount<'info,TokenAccount>,
 
-    pub token_program : Program<'info,Token>,
+    pub bond_mint : InterfaceAccount<'info,Mint>,
+
+    pub token_program : Interface<'info,TokenInterface>,
 }
 
 pub fn handler(
@@ -49,10 +53,11 @@ pub fn handler(
 )->Result<()>{
     let resolution_proposal_key = ctx.accounts.proposer.key();
     let resolution = &mut ctx.accounts.resolution_proposal;
+    let config = resolution.config;
     let clock = Clock::get()?;
 
     // Validate Bond Amount 
-    require!(bond_amount>=MIN_PROPOSAL_BOND,ResolutionError::InsufficientBond);
+    require!(bond_amount>=config.min_proposal_bond,ResolutionError::InsufficientBond);
     // validate no existing proposal -> This is the first porposal of their 
     require!(resolution.bond_amount == 0 , ResolutionError::ProposalAlreadyExists);
     let cpi_account = AssertMarketExpired {
@@ -84,7 +89,7 @@ pub fn handler(
                        .checked_sub(oracle_info.timestamp)
                        .ok_or(ResolutionError::InvalidTimestamp)?;
     
-        require!(age<MAX_ORACLE_STALENESS_SECONDS,ResolutionError::StaleOracleData);
+        require!(age<config.max_oracle_staleness,ResolutionError::StaleOracleData);
 
         results.push(oracle_info.result.clone());
 
@@ -115,26 +120,41 @@ pub fn handler(
     // it Checks that more than outcome comes from more than 50% chances
     validate_sports_consensus(&results, &consensus_result)?;
 
-    let outcome = determine_sports_outcome(event_type, &consensus_result)?;
+    // categorical markets only resolve through their labels, binary markets never do
+    require!(
+        ctx.accounts.market.is_categorical() == (event_type == SportsEventType::Categorical),
+        ResolutionError::InvalidEventOutcome
+    );
+
+    let outcome = if event_type == SportsEventType::Categorical {
+        determine_categorical_outcome(&consensus_result, &ctx.accounts.market.outcome_labels)?
+    } else {
+        determine_sports_outcome(event_type, &consensus_result)?
+    };
     msg!("Proposed outcome: {:?}", outcome);
 
     // Transfer amount from bond to vault
-    let cpi_account = Transfer{
+    let cpi_account = TransferChecked{
         from : ctx.accounts.proposer_bond_account.to_account_info(),
+        mint : ctx.accounts.bond_mint.to_account_info(),
         to  : ctx.accounts.bond_vault.to_account_info(),
         authority : ctx.accounts.proposer.to_account_info()
     };
 
     let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_account);
 
-    token::transfer(cpi_ctx, bond_amount)?;
-    msg!("Bond locked: {} USDC", bond_amount as f64 / 1_000_000.0);
+    token_interface::transfer_checked(cpi_ctx, bond_amount, ctx.accounts.bond_mint.decimals)?;
+    msg!("Bond locked: {} tokens", format_amount(bond_amount, resolution.bond_decimals));
 
     // Added Track bond Contribution 
 
+    let round = resolution.round;
     resolution.bond_contributors.push(BondContributor{
         participant: ctx.accounts.proposer.key(),
-        amount : bond_amount
+        amount : bond_amount,
+        outcome,
+        claimed : false,
+        round
     });
 
     // Update Resolution proposal 
@@ -144,10 +164,10 @@ pub fn handler(
     resolution.proposal_timestamp = clock.unix_timestamp;
     resolution.bond_amount = bond_amount;  // ✅ ADD: Set bond_amount
     resolution.data_source = data_source;
-    resolution.dispute_deadline = clock.unix_timestamp.checked_add(DISPUTE_WINDOW_SECONDS).ok_or(ResolutionError::ArithmeticOverflow)?;
+    resolution.dispute_deadline = clock.unix_timestamp.checked_add(config.dispute_window).ok_or(ResolutionError::ArithmeticOverflow)?;
 
 
-    msg!("Dispute window: {} seconds", DISPUTE_WINDOW_SECONDS);
+    msg!("Dispute window: {} seconds", config.dispute_window);
     msg!("Dispute deadline: {}", resolution.dispute_deadline);
     
 
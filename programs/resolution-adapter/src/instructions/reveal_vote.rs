@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use market_registry::format_amount;
+use market_registry::ResultOutcome;
+use solana_program::hash::hashv;
+
+use crate::{constants::{RESOLUTION_SEED, VOTE_SEED}, error::ResolutionError, events::VoteRevealed, state::{ResolutionProposal, VoteRecord}};
+
+// reveal phase of the staker vote, unrevealed stake is slashed like a losing vote
+#[derive(Accounts)]
+pub struct RevealVote<'info>{
+    pub voter : Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RESOLUTION_SEED, resolution_proposal.market.as_ref()],
+        bump = resolution_proposal.bump,
+        constraint = !resolution_proposal.is_finalized @ ResolutionError::AlreadyFinalized
+    )]
+    pub resolution_proposal : Account<'info,ResolutionProposal>,
+
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, resolution_proposal.key().as_ref(), voter.key().as_ref(), &[resolution_proposal.round]],
+        bump = vote_record.bump
+    )]
+    pub vote_record : Account<'info,VoteRecord>
+}
+
+pub fn handler(ctx:Context<RevealVote>,outcome:ResultOutcome,salt:[u8;32])->Result<()>{
+    let resolution = &mut ctx.accounts.resolution_proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+
+    require!(resolution.is_fully_escalated(),ResolutionError::NotAwaitingArbitration);
+    require!(
+        clock.unix_timestamp >= resolution.vote_commit_ends_at() && clock.unix_timestamp < resolution.vote_ends_at(),
+        ResolutionError::NotInRevealPhase
+    );
+    require!(vote_record.revealed_outcome.is_none(),ResolutionError::VoteAlreadyRevealed);
+
+    // commitment = hash(borsh(outcome) || salt || voter)
+    let commitment = hashv(&[&outcome.try_to_vec()?, &salt, vote_record.voter.as_ref()]);
+    require!(commitment.to_bytes() == vote_record.commitment,ResolutionError::InvalidVoteReveal);
+
+    // only a bonded side can win, the finalize payout needs its bonder
+    require!(resolution.is_bonded_outcome(outcome),ResolutionError::InvalidOutcome);
+    resolution.vote.add_stake(outcome,vote_record.stake)?;
+    vote_record.revealed_outcome = Some(outcome);
+
+    msg!("Vote revealed");
+    msg!("Market: {}", resolution.market);
+    msg!("Voter: {}", vote_record.voter);
+    msg!("Outcome: {:?}", outcome);
+    msg!("Stake: {} tokens", format_amount(vote_record.stake, resolution.bond_decimals));
+
+    emit!(VoteRevealed {
+        market: resolution.market,
+        voter: vote_record.voter,
+        outcome,
+        stake: vote_record.stake,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
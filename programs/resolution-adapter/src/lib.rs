@@ -22,8 +22,19 @@ pub mod resolution_adapter {
     pub fn initialize_resolution(
         ctx: Context<InitializeResolution>,
         category: state::MarketCategory,
+        config: market_registry::ResolutionConfig,
     ) -> Result<()> {
-        instructions::initialize_resolution::handler(ctx, category)
+        instructions::initialize_resolution::handler(ctx, category, config)
+    }
+
+    /// Create the oracle reward vault of a bond mint (protocol admin)
+    pub fn initialize_reward_vault(ctx: Context<InitializeRewardVault>) -> Result<()> {
+        instructions::initialize_reward_vault::handler(ctx)
+    }
+
+    /// Top up the oracle reward vault of a bond mint (protocol admin)
+    pub fn fund_reward_vault(ctx: Context<FundRewardVault>, amount: u64) -> Result<()> {
+        instructions::fund_reward_vault::handler(ctx, amount)
     }
 
     /// Propose outcome for crypto market using price oracles
@@ -58,12 +69,50 @@ pub mod resolution_adapter {
         instructions::dispute_proposal::handler(ctx, counter_outcome, reason, bond_amount)
     }
 
-    /// Finalize outcome after dispute window
-    pub fn finalize_outcome(
-        ctx: Context<FinalizeOutcome>,
-        final_outcome: market_registry::ResultOutcome,
+    /// Stake on a hidden vote for a proposal escalated through every dispute round
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        commitment: [u8; 32],
+        stake: u64,
+    ) -> Result<()> {
+        instructions::commit_vote::handler(ctx, commitment, stake)
+    }
+
+    /// Reveal a committed vote during the reveal phase
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        outcome: market_registry::ResultOutcome,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_vote::handler(ctx, outcome, salt)
+    }
+
+    /// Return a voter's stake (plus reward or minus slashing) once finalized
+    pub fn claim_vote(ctx: Context<ClaimVote>) -> Result<()> {
+        instructions::claim_vote::handler(ctx)
+    }
+
+    /// Arbitrator rules on an escalated proposal whose staker vote failed
+    pub fn arbitrate_dispute(
+        ctx: Context<ArbitrateDispute>,
+        outcome: market_registry::ResultOutcome,
     ) -> Result<()> {
-        instructions::finalize_outcome::handler(ctx, final_outcome)
+        instructions::arbitrate_dispute::handler(ctx, outcome)
+    }
+
+    /// Permissionless: close an empty or stale proposal round and open the next one
+    pub fn open_proposal_round(ctx: Context<OpenProposalRound>) -> Result<()> {
+        instructions::open_proposal_round::handler(ctx)
+    }
+
+    /// Permissionless: finalize outcome after dispute window (standing outcome, staker vote or arbitration ruling)
+    pub fn finalize_outcome(ctx: Context<FinalizeOutcome>) -> Result<()> {
+        instructions::finalize_outcome::handler(ctx)
+    }
+
+    /// Bond contributor takes back its bonds on the final outcome plus their share of the slashed ones
+    pub fn claim_bond(ctx: Context<ClaimBond>) -> Result<()> {
+        instructions::claim_bond::handler(ctx)
     }
 
     /// Emergency resolution (admin only)
@@ -75,5 +124,10 @@ pub mod resolution_adapter {
         instructions::emergency_resolve::handler(ctx, forced_outcome, reason)
     }
 
+    /// Close a finalized resolution and its empty bond vault, refunding rent to the payer
+    pub fn close_resolution(ctx: Context<CloseResolution>) -> Result<()> {
+        instructions::close_resolution::handler(ctx)
+    }
+
     
 }
\ No newline at end of file
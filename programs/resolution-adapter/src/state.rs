@@ -1,14 +1,22 @@
 use anchor_lang::prelude::*;
-use market_registry::ResultOutcome;
+use market_registry::{ResolutionConfig, ResultOutcome, MAX_ESCALATION_ROUNDS, state::Market};
 
-use crate::error::ResolutionError;
+use crate::{constants::{MAX_PROPOSAL_ROUNDS, VOTE_MAJORITY_SHARE_BPS, VOTE_SLASH_BPS}, error::ResolutionError};
 
 
+// one entry per posted bond (proposal or dispute round)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BondContributor{
     pub participant : Pubkey,
 
-    pub amount : u64
+    pub amount : u64,
+    // outcome the bond was posted on
+    pub outcome : ResultOutcome,
+
+    pub claimed : bool,
+    // proposal round the bond was posted in, bonds of closed rounds are refunded
+    // (a winning bond of an overturned round includes its share of the losing bonds)
+    pub round : u8
 }
 
 #[account]
@@ -43,12 +51,34 @@ pub struct ResolutionProposal{
     // 2) Oracle Locks 1000USDC in bond Vault 
     // 3)If Result is correct then Bond will retutn 1000 usdc for the Honsety 
     pub bond_vault : Pubkey,
+    // collateral mint of the bond vault, classic spl or token-2022
+    pub bond_mint : Pubkey,
+    // decimals of the bond mint, for logs and decimal-scaled bond limits
+    pub bond_decimals : u8,
 
     pub bump : u8,
 
     pub bond_contributors:Vec<BondContributor>,
 
-    pub is_emergency_resolved : bool
+    pub is_emergency_resolved : bool,
+    // who paid rent for the proposal and bond vault, refunded on close
+    pub payer : Pubkey,
+    // dispute timing, bond minimum and oracle tolerances of the market (set by market creation)
+    pub config : ResolutionConfig,
+    // ruling of the arbitrator, only once the staker vote failed
+    pub arbitrated_outcome : Option<ResultOutcome>,
+    // staker vote opened by the last escalation round
+    pub vote : VoteRound,
+    // bond ledger fixed on finalization, paid out through claim_bond
+    pub settlement : BondSettlement,
+    // current proposal round, starts at 1
+    pub round : u8,
+    // 0 for the first round, which opens at market expiry
+    pub round_opened_at : i64,
+    // closed rounds, oldest first
+    pub round_history : Vec<ProposalRound>,
+    // bonds and vote stakes of closed rounds not refunded yet
+    pub refund_pool : u64
 }
 
 
@@ -56,7 +86,7 @@ impl ResolutionProposal{
     pub const LEN: usize = 8 +  // discriminator
     32 +  // market
     32 +  // proposer
-    1 +   // proposed_outcome (enum)
+    1 + 1 + 8 +   // proposed_outcome (Option<enum>, Scalar carries an i64)
     8 +   // bond_amount
     8 +   // proposal_timestamp
     8 +   // dispute_deadline
@@ -64,11 +94,22 @@ impl ResolutionProposal{
     4 + (5 * DataSource::LEN) +  // data_sources (vec with max 5)
     1 +   // is_disputed
     1 +   // is_finalized
-    4 + (3 * DisputeProposal::LEN) +  // disputes (vec with max 3)
+    4 + (MAX_ESCALATION_ROUNDS as usize * DisputeProposal::LEN) +  // disputes (one per escalation round)
     32 +  // bond_vault
-    1 +
-    4 + (10 * BondContributor::LEN) + // ✅ ADD: bond_contributors (max 10)
-    1;    // bump
+    32 +  // bond_mint
+    1 +   // bond_decimals
+    1 +   // bump
+    4 + (MAX_PROPOSAL_ROUNDS as usize * (1 + MAX_ESCALATION_ROUNDS as usize) * BondContributor::LEN) + // bond_contributors (proposal + disputes per round)
+    1 +   // is_emergency_resolved
+    32 +  // payer
+    ResolutionConfig::LEN + // config
+    1 + 1 + 8 + // arbitrated_outcome (Option<enum>)
+    VoteRound::LEN + // vote
+    BondSettlement::LEN + // settlement
+    1 +   // round
+    8 +   // round_opened_at
+    4 + ((MAX_PROPOSAL_ROUNDS as usize - 1) * ProposalRound::LEN) + // round_history
+    8;    // refund_pool
 
     pub fn is_dispute_window_open(&self,current_time:i64) -> bool{
         current_time< self.dispute_deadline && !self.is_finalized
@@ -79,14 +120,396 @@ impl ResolutionProposal{
     }
 
     pub fn add_dispute(&mut self , dispute:DisputeProposal)->Result<()>{
-        require!(self.disputes.len() < 3, ResolutionError::MaxDisputesReached);
+        require!(!self.is_fully_escalated(), ResolutionError::MaxDisputesReached);
         self.disputes.push(dispute);
         self.is_disputed = true;
         Ok(())
     }
+
+    // outcome of the last escalation round (the proposal itself while undisputed)
+    pub fn standing_outcome(&self) -> Option<ResultOutcome>{
+        match self.disputes.last() {
+            Some(dispute) => Some(dispute.counter_outcome),
+            None => self.proposed_outcome
+        }
+    }
+
+    // the next dispute has to post at least twice this
+    pub fn standing_bond(&self) -> u64{
+        self.disputes.last().map_or(self.bond_amount, |dispute| dispute.bond_amount)
+    }
+
+    pub fn standing_participant(&self) -> Pubkey{
+        self.disputes.last().map_or(self.proposer, |dispute| dispute.disputer)
+    }
+
+    pub fn is_fully_escalated(&self) -> bool{
+        self.disputes.len() >= self.config.max_escalation_rounds as usize
+    }
+
+    // undisputed -> the proposal, escalated -> the last unanswered round,
+    // fully escalated -> the staker vote, or the arbitrator if the vote failed
+    pub fn final_outcome(&self,current_time:i64) -> Result<ResultOutcome>{
+        if self.is_fully_escalated() {
+            if let Some(outcome) = self.arbitrated_outcome {
+                return Ok(outcome);
+            }
+            require!(current_time >= self.vote_ends_at(), ResolutionError::VoteInProgress);
+            return self.vote_outcome().ok_or(ResolutionError::AwaitingArbitration.into());
+        }
+        self.standing_outcome().ok_or(ResolutionError::NoActiveProposal.into())
+    }
+
+    pub fn vote_commit_ends_at(&self) -> i64{
+        self.vote.started_at.saturating_add(self.config.vote_duration)
+    }
+
+    pub fn vote_ends_at(&self) -> i64{
+        self.vote_commit_ends_at().saturating_add(self.config.vote_duration)
+    }
+
+    // revealed stake has to reach the last escalation bond, so a vote costs at least what the escalation did
+    pub fn vote_outcome(&self) -> Option<ResultOutcome>{
+        self.vote.decided_outcome(self.standing_bond())
+    }
+
+    // bonds of the current round
+    pub fn total_bonds(&self) -> u64{
+        self.bond_contributors.iter()
+            .filter(|contributor| contributor.round == self.round)
+            .map(|contributor| contributor.amount)
+            .sum()
+    }
+
+    // None refunds every bond (emergency resolution), extra is added to the reward pool
+    pub fn settle_bonds(&mut self,outcome:Option<ResultOutcome>,extra:u64) -> Result<BondSettlement>{
+        let total_bonds = self.total_bonds();
+        let winning_bonds : u64 = match outcome {
+            Some(outcome) => self.bond_contributors.iter()
+                .filter(|contributor| contributor.round == self.round && contributor.outcome == outcome)
+                .map(|contributor| contributor.amount)
+                .sum(),
+            None => total_bonds
+        };
+        require!(winning_bonds > 0 || total_bonds == 0, ResolutionError::InvalidOutcome);
+
+        let slashed_bonds = total_bonds - winning_bonds;
+        let protocol_fee = (slashed_bonds as u128 * self.config.slash_fee_bps as u128 / 10_000) as u64;
+        let reward_pool = (slashed_bonds - protocol_fee)
+            .checked_add(extra)
+            .ok_or(ResolutionError::ArithmeticOverflow)?;
+
+        self.settlement = BondSettlement{
+            outcome,
+            winning_bonds,
+            slashed_bonds,
+            protocol_fee,
+            reward_pool,
+            remaining : winning_bonds.checked_add(reward_pool).ok_or(ResolutionError::ArithmeticOverflow)?
+        };
+        Ok(self.settlement)
+    }
+
+    pub fn round_opened_at(&self,market:&Market) -> i64{
+        if self.round_opened_at == 0 { market.expire_at } else { self.round_opened_at }
+    }
+
+    // nobody proposed within the proposal window of the round
+    pub fn is_round_empty(&self,current_time:i64,market:&Market) -> bool{
+        self.bond_amount == 0
+            && current_time >= self.round_opened_at(market).saturating_add(self.config.proposal_window)
+    }
+
+    // the round can no longer settle: the vote failed and the arbitrator stayed silent for a dispute extension,
+    // or it would settle on an outcome the market rejects
+    pub fn is_round_stale(&self,current_time:i64,market:&Market) -> bool{
+        if self.bond_amount == 0 || self.is_finalized {
+            return false;
+        }
+        match self.final_outcome(current_time) {
+            Ok(outcome) => {
+                (self.is_fully_escalated() || self.is_dispute_window_closed(current_time))
+                    && !market.is_valid_outcome(outcome)
+            }
+            Err(_) => {
+                self.is_fully_escalated()
+                    && current_time >= self.vote_ends_at().saturating_add(self.config.dispute_extension)
+            }
+        }
+    }
+
+    // the round settled on another outcome than the proposal: the last dispute went unanswered, or the vote or ruling went against it
+    pub fn is_round_overturned(&self,current_time:i64,market:&Market) -> bool{
+        if !self.is_disputed || self.is_finalized {
+            return false;
+        }
+        if !self.is_fully_escalated() && !self.is_dispute_window_closed(current_time) {
+            return false;
+        }
+        match self.final_outcome(current_time) {
+            Ok(outcome) => Some(outcome) != self.proposed_outcome && market.is_valid_outcome(outcome),
+            Err(_) => false
+        }
+    }
+
+    // winning bonds of an overturned round split its losing bonds pro rata and are refunded with the closed round,
+    // losing bonds are marked claimed; returns the bonds of the round
+    pub fn settle_overturned_round(&mut self,outcome:ResultOutcome) -> Result<u64>{
+        let round = self.round;
+        let total_bonds = self.total_bonds();
+        let winning_bonds : u64 = self.bond_contributors.iter()
+            .filter(|contributor| contributor.round == round && contributor.outcome == outcome)
+            .map(|contributor| contributor.amount)
+            .sum();
+        require!(winning_bonds > 0, ResolutionError::InvalidOutcome);
+        let slashed_bonds = total_bonds - winning_bonds;
+
+        let mut credited : u64 = 0;
+        let mut last_winner = None;
+        for (index, contributor) in self.bond_contributors.iter_mut().enumerate()
+            .filter(|(_, contributor)| contributor.round == round)
+        {
+            if contributor.outcome == outcome {
+                let share = (contributor.amount as u128 * slashed_bonds as u128 / winning_bonds as u128) as u64;
+                contributor.amount = contributor.amount.checked_add(share).ok_or(ResolutionError::ArithmeticOverflow)?;
+                credited = credited.checked_add(contributor.amount).ok_or(ResolutionError::ArithmeticOverflow)?;
+                last_winner = Some(index);
+            } else {
+                contributor.claimed = true;
+            }
+        }
+        // the last winning bond takes the rounding dust
+        if let Some(index) = last_winner {
+            self.bond_contributors[index].amount += total_bonds - credited;
+        }
+        Ok(total_bonds)
+    }
+
+    // outcomes someone posted a bond on, the only ones the arbitrator can pick
+    pub fn is_bonded_outcome(&self,outcome:ResultOutcome) -> bool{
+        self.proposed_outcome == Some(outcome) || self.disputes.iter().any(|dispute| dispute.counter_outcome == outcome)
+    }
 }
 
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundCloseReason{
+    // no proposal within the proposal window
+    NoProposal,
+    // proposal could not settle (failed vote without ruling, or an outcome the market rejects)
+    Stale,
+    // a dispute overturned the proposal, the winning bonds took the losing ones
+    DisputeWon
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalRound{
+    pub round : u8,
+
+    pub proposer : Pubkey,
+
+    pub proposed_outcome : Option<ResultOutcome>,
+
+    pub bond_amount : u64,
+
+    pub disputes : u8,
+
+    pub opened_at : i64,
+
+    pub closed_at : i64,
+
+    pub reason : RoundCloseReason
+}
+
+impl ProposalRound{
+    pub const LEN: usize =
+    1 +   // round
+    32 +  // proposer
+    1 + 1 + 8 +   // proposed_outcome (Option<enum>)
+    8 +   // bond_amount
+    1 +   // disputes
+    8 +   // opened_at
+    8 +   // closed_at
+    1;    // reason
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BondSettlement{
+    // final outcome, None after an emergency resolution (every bond is refunded)
+    pub outcome : Option<ResultOutcome>,
+    // bonds posted on the final outcome
+    pub winning_bonds : u64,
+    // bonds posted on any other outcome
+    pub slashed_bonds : u64,
+    // config.slash_fee_bps of the slashed bonds, sent to the protocol
+    pub protocol_fee : u64,
+    // slashed bonds after the fee plus the slashed vote stake, shared pro rata by the winning bonds
+    pub reward_pool : u64,
+    // bond vault tokens still owed to bond contributors
+    pub remaining : u64
+}
+
+impl BondSettlement{
+    pub const LEN: usize =
+    1 + 1 + 8 +   // outcome (Option<enum>)
+    8 +   // winning_bonds
+    8 +   // slashed_bonds
+    8 +   // protocol_fee
+    8 +   // reward_pool
+    8;    // remaining
+
+    pub fn is_winning(&self,outcome:ResultOutcome) -> bool{
+        match self.outcome {
+            Some(final_outcome) => final_outcome == outcome,
+            None => true
+        }
+    }
+
+    // bond back plus its share of the reward pool (rounded down)
+    pub fn payout(&self,amount:u64) -> Result<u64>{
+        if self.winning_bonds == 0 {
+            return Ok(0);
+        }
+        let reward = (amount as u128)
+            .checked_mul(self.reward_pool as u128)
+            .ok_or(ResolutionError::ArithmeticOverflow)?
+            / self.winning_bonds as u128;
+        Ok(amount.checked_add(reward as u64).ok_or(ResolutionError::ArithmeticOverflow)?)
+    }
+}
+
+// revealed stake on one bonded outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutcomeStake{
+    pub outcome : ResultOutcome,
+
+    pub stake : u64
+}
+
+impl OutcomeStake{
+    pub const LEN: usize = 1 + 8 + 8; // outcome (enum, Scalar carries an i64) + stake
+}
+
+// commit phase [started_at, +vote_duration), reveal phase right after, tallied by finalize_outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct VoteRound{
+    // 0 until the last escalation round
+    pub started_at : i64,
+    // revealed stake per bonded outcome (proposal + one per dispute at most), any outcome type
+    pub tally : Vec<OutcomeStake>,
+    // committed stake, revealed or not
+    pub total_stake : u64,
+    // vote records not claimed yet
+    pub voters : u32,
+    // majority outcome, None if the vote failed (no quorum or no absolute majority)
+    pub result : Option<ResultOutcome>,
+    // bond vault tokens still owed to voters once tallied
+    pub pool : u64
+}
+
+impl VoteRound{
+    pub const LEN: usize =
+    8 +   // started_at
+    4 + ((1 + MAX_ESCALATION_ROUNDS as usize) * OutcomeStake::LEN) +   // tally
+    8 +   // total_stake
+    4 +   // voters
+    1 + 1 + 8 +   // result (Option<enum>)
+    8;    // pool
+
+    pub fn stake_on(&self,outcome:ResultOutcome) -> u64{
+        self.tally.iter().find(|entry| entry.outcome == outcome).map_or(0, |entry| entry.stake)
+    }
+
+    // the caller checks the outcome is bonded, which bounds the tally length
+    pub fn add_stake(&mut self,outcome:ResultOutcome,stake:u64) -> Result<()>{
+        match self.tally.iter_mut().find(|entry| entry.outcome == outcome) {
+            Some(entry) => {
+                entry.stake = entry.stake.checked_add(stake).ok_or(ResolutionError::ArithmeticOverflow)?;
+            }
+            None => self.tally.push(OutcomeStake{ outcome, stake })
+        }
+        Ok(())
+    }
+
+    pub fn decided_outcome(&self,quorum:u64) -> Option<ResultOutcome>{
+        let revealed : u64 = self.tally.iter().map(|entry| entry.stake).sum();
+        if revealed == 0 || revealed < quorum {
+            return None;
+        }
+        self.tally
+            .iter()
+            .find(|entry| (entry.stake as u128) * 2 > revealed as u128)
+            .map(|entry| entry.outcome)
+    }
+
+    fn slashed(&self,winning_stake:u64) -> u64{
+        ((self.total_stake.saturating_sub(winning_stake) as u128) * VOTE_SLASH_BPS as u128 / 10_000) as u64
+    }
+
+    // fixes the result and what the voters get back in total, returns that pool
+    pub fn close_tally(&mut self,result:Option<ResultOutcome>) -> u64{
+        self.result = result;
+        self.pool = match result {
+            Some(outcome) => {
+                let slashed = self.slashed(self.stake_on(outcome));
+                let majority_share = (slashed as u128 * VOTE_MAJORITY_SHARE_BPS as u128 / 10_000) as u64;
+                self.total_stake - slashed + majority_share
+            }
+            None => self.total_stake
+        };
+        self.pool
+    }
+
+    // majority: stake + pro-rata share of the slashed stake, everyone else: stake minus the slash (rounded up)
+    pub fn payout(&self,record:&VoteRecord) -> Result<u64>{
+        let Some(result) = self.result else {
+            return Ok(record.stake);
+        };
+        if record.revealed_outcome == Some(result) {
+            let winning_stake = self.stake_on(result);
+            let slashed = self.slashed(winning_stake);
+            let majority_share = slashed as u128 * VOTE_MAJORITY_SHARE_BPS as u128 / 10_000;
+            let reward = majority_share
+                .checked_mul(record.stake as u128)
+                .ok_or(ResolutionError::ArithmeticOverflow)?
+                / winning_stake as u128;
+            Ok(record.stake.checked_add(reward as u64).ok_or(ResolutionError::ArithmeticOverflow)?)
+        } else {
+            let slash = (record.stake as u128 * VOTE_SLASH_BPS as u128).div_ceil(10_000) as u64;
+            Ok(record.stake - slash)
+        }
+    }
+}
+
+// one per staker and resolution proposal, closed to the voter on claim
+#[account]
+pub struct VoteRecord{
+    pub resolution : Pubkey,
+
+    pub voter : Pubkey,
+    // hash(outcome || salt || voter)
+    pub commitment : [u8;32],
+    // bond mint units locked in the bond vault
+    pub stake : u64,
+
+    pub revealed_outcome : Option<ResultOutcome>,
+    // proposal round voted in, a closed round refunds the stake
+    pub round : u8,
+
+    pub bump : u8
+}
+
+impl VoteRecord{
+    pub const LEN: usize = 8 +  // discriminator
+    32 +  // resolution
+    32 +  // voter
+    32 +  // commitment
+    8 +   // stake
+    1 + 1 + 8 +   // revealed_outcome (Option<enum>)
+    1 +   // round
+    1;    // bump
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct  DisputeProposal{
     // who sumbited the dispute 
@@ -104,7 +527,7 @@ pub struct  DisputeProposal{
 impl DisputeProposal {
     pub const LEN: usize = 
     32+   //dispute 
-    1+  // Conter Outcome
+    1+ 8+  // Conter Outcome (Scalar carries an i64)
     8+ // bond amount 
     4+ 100+ //reason (String)
     8;  // timestamp
@@ -213,10 +636,12 @@ pub struct SportsResolutionData{
 pub enum SportsEventType {
     Winner,
     ScoreThreshold,
-    YesNo
+    YesNo,
+    // result is matched against the market's outcome labels
+    Categorical
 }
 
 
 impl BondContributor {
-    pub const LEN: usize = 32 + 8;  // pubkey + u64
+    pub const LEN: usize = 32 + 8 + 1 + 8 + 1 + 1;  // pubkey + u64 + outcome (enum) + claimed + round
 }
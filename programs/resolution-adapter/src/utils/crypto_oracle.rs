@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, get_feed_id_from_hex};
 
-use crate::{constants::{MAX_ORACLE_STALENESS_SECONDS, MAX_PRICE_DEVIATION_BPS}, error::ResolutionError};
+use crate::error::ResolutionError;
 
 
 pub struct PriceData{
@@ -13,8 +13,9 @@ pub struct PriceData{
 }
 
 // price_update_account -> Is a solana account that Store Price pyth Data on chain
+// max_staleness / max_deviation_bps come from the market's ResolutionConfig
 pub fn read_pyth_price(price_update_account : &AccountInfo
-    ,feed_id:&str)->Result<PriceData>{
+    ,feed_id:&str,max_staleness:i64)->Result<PriceData>{
         // try_deserialize -> Convert Byte data into Rust Structs 
     let price_update = PriceUpdateV2::try_deserialize(&mut price_update_account.data.borrow().as_ref())
                                                         .map_err(|_| ResolutionError::InvalidPythAccount)?;   
@@ -26,7 +27,7 @@ pub fn read_pyth_price(price_update_account : &AccountInfo
     let feed_id_bytes = get_feed_id_from_hex(feed_id).map_err(|_| ResolutionError::InvalidPythAccount)?; 
 
     let price_feed = price_update.get_price_no_older_than(&Clock::get()?,
-                 MAX_ORACLE_STALENESS_SECONDS as u64,   
+                 max_staleness as u64,   
                              &feed_id_bytes)
                             .map_err(|_| ResolutionError::InvalidPythAccount)?; 
     Ok(PriceData { 
@@ -48,12 +49,12 @@ pub fn normalize_price(price : i64 , expo : i32)->Result<i64>{
    
 }
 
-pub fn validate_pyth_price(price_data :&PriceData,current_time:i64)->Result<()>{
+pub fn validate_pyth_price(price_data :&PriceData,current_time:i64,max_staleness:i64)->Result<()>{
     let age = current_time.checked_sub(price_data.timestamp).ok_or(ResolutionError::InvalidTimestamp)?;
     
     
-    // If price  is fetch freshly then Means less than 5 min that means price is fresh and we can use it 
-    require!(age<=MAX_ORACLE_STALENESS_SECONDS,ResolutionError::StaleOracleData);
+    // If price  is fetch freshly then Means no older than max_staleness that means price is fresh and we can use it 
+    require!(age<=max_staleness,ResolutionError::StaleOracleData);
 
 
     //Calculating maximum confidence in this 
@@ -86,11 +87,11 @@ pub fn calcualte_median(prices:&[i64])->Result<i64>{
 }
 
 
-pub fn validate_price_agreement(prices:&[i64],consensus:i64)->Result<()>{
+pub fn validate_price_agreement(prices:&[i64],consensus:i64,max_deviation_bps:u16)->Result<()>{
     for price in prices {
         let diff = (price-consensus).abs();
 
-        let max_deviation = (consensus.abs()as u64).checked_mul(MAX_PRICE_DEVIATION_BPS as u64)
+        let max_deviation = (consensus.abs()as u64).checked_mul(max_deviation_bps as u64)
                                                     .ok_or(ResolutionError::ArithmeticOverflow)?
                                                     .checked_div(10000)
                                                     .ok_or(ResolutionError::ArithmeticOverflow)?;
@@ -74,6 +74,9 @@ pub fn determine_sports_outcome(
             }
         }
 
+        // needs the market's outcome labels -> see determine_categorical_outcome
+        SportsEventType::Categorical => Err(ResolutionError::InvalidEventOutcome.into()),
+
         SportsEventType::YesNo => {
             match result.to_lowercase().as_str() {
                 "yes" => Ok(ResultOutcome::Yes),
@@ -90,6 +93,36 @@ pub fn determine_sports_outcome(
     }
 }
 
+// maps the consensus result onto an outcome index of a categorical market
+// exact label match wins, otherwise a single fuzzy match (teams_match) is required
+pub fn determine_categorical_outcome(
+    result : &str,
+    outcome_labels : &[String],
+)->Result<ResultOutcome>{
+    let normalized = normalize_team_name(result);
+
+    if let Some(index) = outcome_labels.iter().position(|label| normalize_team_name(label) == normalized){
+        return Ok(ResultOutcome::Index(index as u8));
+    }
+
+    match normalized.as_str() {
+        "draw" | "tie" | "invalid" | "canceled" | "cancelled" => return Ok(ResultOutcome::Invalid),
+        _ => {}
+    }
+
+    let matches : Vec<usize> = outcome_labels
+        .iter()
+        .enumerate()
+        .filter(|(_,label)| teams_match(label, result))
+        .map(|(index,_)| index)
+        .collect();
+
+    // ambiguous or unknown result cannot be proposed
+    require!(matches.len() == 1,ResolutionError::InvalidEventOutcome);
+
+    Ok(ResultOutcome::Index(matches[0] as u8))
+}
+
 pub fn normalize_team_name(name: &str) -> String {
     name.to_lowercase()
         .chars()
@@ -3,7 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { EscrowVault } from "../target/types/escrow_vault";
 import { MarketRegistry } from "../target/types/market_registry";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID,  createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID,  createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import { expect } from "chai";
 
 
@@ -117,13 +117,34 @@ describe("escrow Vault Tests",()=>{
             escrowProgram.programId
         );
 
+        const usdcVaultPda = getAssociatedTokenAddressSync(
+            usdcMint,
+            escrowVaultPda,
+            true
+        );
+
+        // resolution proposal is created alongside the vault
+        const resolutionProgramId = anchor.workspace.ResolutionAdapter.programId as PublicKey;
+        const [resolutionPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("resolution"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+        const [bondVaultPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("bond_vault"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+
         const param = {
             marketId : Array.from(marketId),
             question,
             description : `Test ${question}`,
             category : "Test",
             expireAt : new anchor.BN(futureExpiry),
-            resolutionSource : "Test" 
+            resolutionSource : "Test",
+            resolutionCategory : {crypto:{}},
+            openAt : null,
+            tradingCloseAt : null,
+            resolutionConfig : null
         }
 
         await marketProgram.methods.initializeMarket(param).accounts({
@@ -134,46 +155,24 @@ describe("escrow Vault Tests",()=>{
             noTokenMint : noMint.publicKey,
             escrowVault : escrowVaultPda,
             escrowProgram : escrowProgram.programId,
-            resolutionAdapter : Keypair.generate().publicKey,
+            usdcMint,
+            usdcVault : usdcVaultPda,
+            resolutionAdapter : resolutionPda,
+            resolutionBondVault : bondVaultPda,
+            resolutionProgram : resolutionProgramId,
+            creatorBondAccount : null,
+            bondVault : null,
             systemProgram :  SystemProgram.programId,
             tokenProgram:TOKEN_PROGRAM_ID,
+            collateralTokenProgram : TOKEN_PROGRAM_ID,
+            associatedTokenProgram : ASSOCIATED_TOKEN_PROGRAM_ID,
             rent : anchor.web3.SYSVAR_RENT_PUBKEY
         }).signers([admin,yesMint,noMint]).rpc()
 
-        return {marketPda,yesMint,noMint,escrowVaultPda};
+        return {marketPda,yesMint,noMint,escrowVaultPda,usdcVaultPda};
     }
 
 
-    async function initializeVault(marketPda : PublicKey,yesMint:PublicKey,noMint:PublicKey){
-        const [vaultPda] = PublicKey.findProgramAddressSync(
-            [Buffer.from("escrow_vault"), marketPda.toBuffer()],
-            escrowProgram.programId
-        );
-
-       const usdcVaultPda = await getAssociatedTokenAddressSync(
-        usdcMint,
-        vaultPda,
-        true
-       )
-
-        await escrowProgram.methods.initializeVault().accounts({
-            admin : admin.publicKey,
-            market : marketPda,
-            // @ts-ignore
-            vault:vaultPda,
-            yesTokenMint : yesMint,
-            noTokenMint : noMint,
-            marketRegisteryProgram:marketProgram.programId,
-            usdcMint,
-            usdcVault : usdcVaultPda,
-            systemProgram : SystemProgram.programId,
-            tokenProgram : TOKEN_PROGRAM_ID,
-            rent : anchor.web3.SYSVAR_RENT_PUBKEY
-        }).signers([admin]).rpc()
-
-        return {vaultPda , usdcVaultPda};
-    }
-
     async function openMarket(marketPda : PublicKey){
         await marketProgram.methods.openMarket().accounts({
             admin : admin.publicKey,
@@ -245,6 +244,19 @@ describe("escrow Vault Tests",()=>{
         airdrop(nonAuthorized.publicKey),
         ]);
 
+        // protocol config is a singleton shared by every test file -> created once by the env wallet
+        const envWallet = anchor.AnchorProvider.env().wallet as anchor.Wallet;
+        const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("admin")], marketProgram.programId);
+        if(!(await provider.connection.getAccountInfo(configPda))){
+            await marketProgram.methods.initializeConfig().accounts({
+                admin : envWallet.publicKey,
+            }).signers([envWallet.payer]).rpc();
+            // creation starts admin-only, the test files create markets from their own keypairs
+            await marketProgram.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(0)).accounts({
+                admin : envWallet.publicKey,
+            }).signers([envWallet.payer]).rpc();
+        }
+
         // creating USDC Mock 
 
         
@@ -317,21 +329,58 @@ describe("escrow Vault Tests",()=>{
             yes1Mint = result.yesMint;
             no1Mint = result.noMint;
 
-            const vaultResult = await initializeVault(
-                market1Pda,
-                yes1Mint.publicKey,
-                no1Mint.publicKey
-            );
-
-            vault1Pda=vaultResult.vaultPda;
-            vault1UsdcPda = vaultResult.usdcVaultPda;
+            // vault is created by the market creation itself
+            vault1Pda = result.escrowVaultPda;
+            vault1UsdcPda = result.usdcVaultPda;
 
             const vault = await escrowProgram.account.escrowVault.fetch(vault1Pda);
       expect(vault.totalYesMinted.toNumber()).to.equal(0);
       expect(vault.totalNoMinted.toNumber()).to.equal(0);
       expect(vault.isSettled).to.be.false;
+      expect(vault.usdcMint.toBase58()).to.equal(usdcMint.toBase58());
+      expect(vault.tokenProgram.toBase58()).to.equal(TOKEN_PROGRAM_ID.toBase58());
+      expect(vault.outcomeTokenProgram.toBase58()).to.equal(TOKEN_PROGRAM_ID.toBase58());
+      expect(vault.collateralDecimals).to.equal(6);
+      expect(vault.collateralPerPair.toNumber()).to.equal(1_000_000);
+
+            const resolutionProgramId = anchor.workspace.ResolutionAdapter.programId as PublicKey;
+            const [resolutionPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("resolution"),market1Pda.toBuffer()],
+                resolutionProgramId
+            );
+            const market = await marketProgram.account.market.fetch(market1Pda);
+            expect(market.escrowVault.toBase58()).to.equal(vault1Pda.toBase58());
+            expect(market.resolutionAdapter.toBase58()).to.equal(resolutionPda.toBase58());
 
         })
+
+        it("Rejects a vault created outside market creation",async()=>{
+            const yesMint = Keypair.generate();
+            const noMint = Keypair.generate();
+            const strayMarket = Keypair.generate().publicKey;
+            const [strayVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"),strayMarket.toBuffer()],
+                escrowProgram.programId
+            );
+            try{
+                await escrowProgram.methods.initializeVault().accounts({
+                    payer : admin.publicKey,
+                    market : strayMarket,
+                    // @ts-ignore
+                    vault : strayVault,
+                    usdcMint,
+                    usdcVault : getAssociatedTokenAddressSync(usdcMint,strayVault,true),
+                    yesTokenMint : yesMint.publicKey,
+                    noTokenMint : noMint.publicKey,
+                    systemProgram : SystemProgram.programId,
+                    tokenProgram : TOKEN_PROGRAM_ID,
+                }).signers([admin]).rpc()
+                expect.fail("Vault must only be created by the market registry")
+            }catch(e){
+                expect(e.message).to.match(/Signature verification failed|Missing signature/);
+                console.log(" Correctly rejected standalone vault initialization");
+            }
+        })
     })
 
 
@@ -2,9 +2,10 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program, AnchorError } from "@coral-xyz/anchor";
 import { MarketRegistry } from "../target/types/market_registry";
 import { EscrowVault } from "../target/types/escrow_vault";
+import { OrderBook } from "../target/types/order_book";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
 import { program } from "@coral-xyz/anchor/dist/cjs/native/system";
-import { TOKEN_PROGRAM_ID, createAccount, createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, createAccount, createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import { expect } from "chai";
 import { set } from "@coral-xyz/anchor/dist/cjs/utils/features";
 
@@ -14,6 +15,8 @@ describe("Hydra Market Full Integration Test",()=>{
 
     const marketProgram  = anchor.workspace.MarketRegistry as Program<MarketRegistry>;
     const escrowProgram = anchor.workspace.EscrowVault as Program<EscrowVault>;
+    const orderBookProgram = anchor.workspace.OrderBook as Program<OrderBook>;
+    const resolutionProgramId = anchor.workspace.ResolutionAdapter.programId as PublicKey;
 
     // Keypairs 
 
@@ -22,7 +25,8 @@ describe("Hydra Market Full Integration Test",()=>{
     let alice : Keypair;
     let bob : Keypair;
     let charlie : Keypair;
-    let resolutionAdapter : Keypair
+    let resolutionAdapterPda : PublicKey;
+    let resolutionBondVaultPda : PublicKey;
     // Market Account 
     let marketPda : PublicKey;
     let yesTokenMint : Keypair;
@@ -35,6 +39,7 @@ describe("Hydra Market Full Integration Test",()=>{
     let aliceUsdc : PublicKey;
     let bobUsdc : PublicKey;
     let charlieUsdc:PublicKey;
+    let treasury : PublicKey;
 
     // Token accounts for YES/NO
     let aliceYes: PublicKey;
@@ -52,7 +57,8 @@ describe("Hydra Market Full Integration Test",()=>{
     const category = "Crypto";
     const resolutionSource = "Pyth Network BTC/USDC";
     const nowTimestamp = Math.floor(Date.now() / 1000);
-    const expireAt = new anchor.BN(nowTimestamp + 15);
+    // trading stops at expiry -> leave room for the minting and order book steps
+    const expireAt = new anchor.BN(nowTimestamp + 60);
 
     async function airdrop(pubkey:PublicKey,amount: number=10){
         const sig = await provider.connection.requestAirdrop(
@@ -98,19 +104,31 @@ describe("Hydra Market Full Integration Test",()=>{
         if (state.paused) return "PAUSED";
         if (state.resolving) return "RESOLVING";
         if (state.resolved) return "RESOLVED";
+        if (state.cancelled) return "CANCELLED";
         return "UNKNOWN";
       }
     
 
       before(async ()=>{
         console.log("\n Setting up test environment...\n");
+        // protocol config is a singleton shared by every test file -> created once by the provider wallet
+        const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("admin")], marketProgram.programId);
+        if(!(await provider.connection.getAccountInfo(configPda))){
+            await marketProgram.methods.initializeConfig().accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+            // creation starts admin-only, the test files create markets from their own keypairs
+            await marketProgram.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(0)).accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+        }
+
 
         admin = Keypair.generate();
         settlementWorker = Keypair.generate();
         alice = Keypair.generate();
         bob = Keypair.generate();
         charlie = Keypair.generate();
-        resolutionAdapter = Keypair.generate();
 
         console.log("💰 Airdropping SOL...");
         await Promise.all([
@@ -168,6 +186,15 @@ describe("Hydra Market Full Integration Test",()=>{
         );
         charlieUsdc = charlieUsdcAccount .address; 
 
+        // Protocol fee treasury
+        const treasuryAccount = await getOrCreateAssociatedTokenAccount(
+            provider.connection,
+            admin,
+            usdcMint,
+            admin.publicKey
+        );
+        treasury = treasuryAccount.address;
+
         // Mint some Usdc to User 
         await Promise.all([ //provider,payer,mint,destination,authority,amount
             mintTo(provider.connection, admin, usdcMint, aliceUsdc, admin, 10_000 * 1_000_000),
@@ -179,7 +206,6 @@ describe("Hydra Market Full Integration Test",()=>{
         // Generate Token Mint 
         yesTokenMint = Keypair.generate();
         noTokenMint = Keypair.generate();
-        resolutionAdapter = Keypair.generate();
 
         // Generate market Pda 
         [marketPda] = PublicKey.findProgramAddressSync(
@@ -192,6 +218,17 @@ describe("Hydra Market Full Integration Test",()=>{
             escrowProgram.programId
         );
 
+        // resolution proposal + its bond vault are created together with the market
+        [resolutionAdapterPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("resolution"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+
+        [resolutionBondVaultPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("bond_vault"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+
         console.log("\n Account Addresses:");
         console.log("  Admin:", admin.publicKey.toString());
         console.log("  Settlement Worker:", settlementWorker.publicKey.toString());
@@ -216,7 +253,11 @@ describe("Hydra Market Full Integration Test",()=>{
                 description,
                 category,
                 expireAt,
-                resolutionSource
+                resolutionSource,
+                resolutionCategory : {crypto:{}},
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
             };
 
             await marketProgram.methods.initializeMarket(params).accounts({
@@ -227,9 +268,17 @@ describe("Hydra Market Full Integration Test",()=>{
                 noTokenMint : noTokenMint.publicKey,
                 escrowVault : escrowVaultPda,
                 escrowProgram : escrowProgram.programId,
-                resolutionAdapter : resolutionAdapter.publicKey,
+                usdcMint,
+                usdcVault : getAssociatedTokenAddressSync(usdcMint, escrowVaultPda, true),
+                resolutionAdapter : resolutionAdapterPda,
+                resolutionBondVault : resolutionBondVaultPda,
+                resolutionProgram : resolutionProgramId,
+                creatorBondAccount : null,
+                bondVault : null,
                 systemProgram: SystemProgram.programId,
                 tokenProgram: TOKEN_PROGRAM_ID,
+                collateralTokenProgram : TOKEN_PROGRAM_ID,
+                associatedTokenProgram : ASSOCIATED_TOKEN_PROGRAM_ID,
                 rent: anchor.web3.SYSVAR_RENT_PUBKEY,
             }).signers([admin,yesTokenMint,noTokenMint]).rpc();
 
@@ -242,6 +291,7 @@ describe("Hydra Market Full Integration Test",()=>{
             expect(market.yesTokenMint.toString()).to.equal(yesTokenMint.publicKey.toString());
             expect(market.noTokenMint.toString()).to.equal(noTokenMint.publicKey.toString());
             expect(market.escrowVault.toString()).to.equal(escrowVaultPda.toString());
+            expect(market.resolutionAdapter.toString()).to.equal(resolutionAdapterPda.toString());
             expect(market.resolutionSource).to.equal(resolutionSource);
             expect(getMarketState(market.state)).to.equal("CREATED");
             expect(market.resolutionOutcome).to.be.null;
@@ -251,29 +301,14 @@ describe("Hydra Market Full Integration Test",()=>{
             console.log("   State:", getMarketState(market.state));
         })
 
-        it("Should Initialize new Escrow Vault",async()=>{
-            console.log("\n Initializing escrow vault...");
+        it("Should create the Escrow Vault with the market",async()=>{
+            console.log("\n Checking escrow vault...");
             // Vault USDC 
             const usdcVaultPda = await anchor.utils.token.associatedAddress({
                 mint: usdcMint,
                 owner: escrowVaultPda,  // The VAULT PDA is the owner
             })
 
-            await escrowProgram.methods.initializeVault().accounts({
-                admin : admin.publicKey,
-                market : marketPda,
-                // @ts-ignore
-                vault : escrowVaultPda,
-                marketRegisteryProgram :marketProgram.programId,
-                yesTokenMint : yesTokenMint.publicKey,
-                noTokenMint : noTokenMint.publicKey,
-                usdcVault : usdcVaultPda,
-                usdcMint,
-                systemProgram : SystemProgram.programId,
-                tokenProgram : TOKEN_PROGRAM_ID,
-                associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID
-            }).signers([admin]).rpc()
-
             const vault = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
 
             expect(vault.market.toString()).to.equal(marketPda.toString());
@@ -291,6 +326,102 @@ describe("Hydra Market Full Integration Test",()=>{
             console.log("   Collateral locked: 0 USDC");
         })
 
+        it("Should initialize protocol fee config",async()=>{
+            const [protocolConfigPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("protocol_config")],
+                escrowProgram.programId
+            );
+
+            // zero fees keep the payout assertions below exact, only the upgrade authority (provider wallet) can create the config
+            // fees go to the owner's associated token account of each market's collateral mint
+            await escrowProgram.methods.initializeProtocolConfig(0, 0).accounts({
+                admin : provider.wallet.publicKey,
+                treasuryOwner : admin.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            const config = await escrowProgram.account.protocolConfig.fetch(protocolConfigPda);
+            expect(config.treasuryOwner.toString()).to.equal(admin.publicKey.toString());
+            expect(config.mintFeeBps).to.equal(0);
+            expect(config.redemptionFeeBps).to.equal(0);
+
+            console.log(" Protocol config initialized");
+        })
+
+        it("Should add settlement worker to the vault allow-list",async()=>{
+            // vaults are administered by the protocol admin, not by the market creator who only paid the rent
+            const vaultBefore = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
+            expect(vaultBefore.payer.toString()).to.equal(admin.publicKey.toString());
+
+            try{
+                await escrowProgram.methods.addSettlementAuthority(settlementWorker.publicKey).accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda
+                }).signers([admin]).rpc();
+                expect.fail("Market creator must not administer the vault")
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("Unauthorized");
+            }
+
+            await escrowProgram.methods.addSettlementAuthority(settlementWorker.publicKey).accounts({
+                admin : provider.wallet.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            const vault = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
+            expect(vault.settlementAuthorities.map((a)=>a.toString())).to.include(settlementWorker.publicKey.toString());
+
+            console.log(" Settlement worker allow-listed");
+        })
+
+        it("Should move vault control with a protocol admin handover",async()=>{
+            const envWallet = (provider.wallet as anchor.Wallet).payer;
+            const newAdmin = Keypair.generate();
+            await airdrop(newAdmin.publicKey);
+
+            await marketProgram.methods.transferAdmin(newAdmin.publicKey).accounts({
+                admin : envWallet.publicKey,
+            }).signers([envWallet]).rpc();
+            await marketProgram.methods.acceptAdmin().accounts({
+                newAdmin : newAdmin.publicKey,
+            }).signers([newAdmin]).rpc();
+
+            // the previous admin lost the existing vault, the new one controls it without touching the vault
+            try{
+                await escrowProgram.methods.pauseMinting().accounts({
+                    admin : envWallet.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda
+                }).signers([envWallet]).rpc();
+                expect.fail("Previous admin must not control the vault");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("Unauthorized");
+            }
+
+            await escrowProgram.methods.pauseMinting().accounts({
+                admin : newAdmin.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda
+            }).signers([newAdmin]).rpc();
+            await escrowProgram.methods.resumeMinting().accounts({
+                admin : newAdmin.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda
+            }).signers([newAdmin]).rpc();
+            expect((await escrowProgram.account.escrowVault.fetch(escrowVaultPda)).isMintingPaused).to.be.false;
+
+            // hand it back for the remaining tests
+            await marketProgram.methods.transferAdmin(envWallet.publicKey).accounts({
+                admin : newAdmin.publicKey,
+            }).signers([newAdmin]).rpc();
+            await marketProgram.methods.acceptAdmin().accounts({
+                newAdmin : envWallet.publicKey,
+            }).signers([envWallet]).rpc();
+
+            console.log(" Vault admin paths followed the registry admin handover");
+        })
+
         it("Should Open the market for trading",async()=>{
             console.log("Opening Market");
             
@@ -398,6 +529,7 @@ describe("Hydra Market Full Integration Test",()=>{
                 noTokenMint : noTokenMint.publicKey,
                 yesRecipient : aliceYes,
                 noRecipient : bobNo,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
             }).signers([settlementWorker]).rpc();
 
@@ -446,6 +578,7 @@ describe("Hydra Market Full Integration Test",()=>{
                 noTokenMint : noTokenMint.publicKey,
                 yesRecipient : charlieYes,
                 noRecipient : bobNo,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
             }).signers([settlementWorker]).rpc();
 
@@ -486,6 +619,7 @@ describe("Hydra Market Full Integration Test",()=>{
                     noTokenMint : noTokenMint.publicKey,
                     yesRecipient : aliceYes,
                     noRecipient : bobNo,
+                    treasury,
                     tokenProgram : TOKEN_PROGRAM_ID
                 }).signers([settlementWorker]).rpc();
                 expect.fail("Should have thrown error");
@@ -496,6 +630,513 @@ describe("Hydra Market Full Integration Test",()=>{
         })
 
 
+        it("Should reject hot wallet mint from authority not on allow-list",async()=>{
+            const usdcVaultPda = getAssociatedTokenAddressSync(
+                usdcMint,           // mint
+                escrowVaultPda,     // owner (the vault PDA)
+                true                // allowOwnerOffCurve (required for PDAs)
+            );
+
+            try{
+                await escrowProgram.methods.mintPairs(new anchor.BN(10)).accounts({
+                    authority : alice.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda,
+                    market:marketPda,
+                    marketRegisteryProgram : marketProgram.programId,
+                    usdcVault : usdcVaultPda,
+                    usdcMint,
+                    hotWalletUsdc : aliceUsdc,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    yesRecipient : aliceYes,
+                    noRecipient : aliceNo,
+                    treasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([alice]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("UnauthorizedSettlementAuthority");
+                console.log(" Correctly rejected non allow-listed authority");
+            }
+        })
+
+        it("Should self-mint pairs from Alice's own USDC and merge them back",async()=>{
+            const usdcVaultPda = getAssociatedTokenAddressSync(
+                usdcMint,           // mint
+                escrowVaultPda,     // owner (the vault PDA)
+                true                // allowOwnerOffCurve (required for PDAs)
+            );
+            const pairs = new anchor.BN(10);
+
+            const aliceUsdcBefore = await getTokenbalance(aliceUsdc);
+
+            await escrowProgram.methods.mintPairsSelf(pairs).accounts({
+                user : alice.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                market : marketPda,
+                usdcVault : usdcVaultPda,
+                usdcMint,
+                userUsdc : aliceUsdc,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                userYesAccount : aliceYes,
+                userNoAccount : aliceNo,
+                treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+
+            expect(await getTokenbalance(aliceYes)).to.equal(110);
+            expect(await getTokenbalance(aliceNo)).to.equal(10);
+            expect(aliceUsdcBefore - await getTokenbalance(aliceUsdc)).to.equal(10 * 1_000_000);
+
+            await escrowProgram.methods.mergePairs(pairs).accounts({
+                user : alice.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                usdcVault : usdcVaultPda,
+                userUsdc : aliceUsdc,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                userYesAccount : aliceYes,
+                userNoAccount : aliceNo,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+
+            const vault = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
+            expect(await getTokenbalance(aliceYes)).to.equal(100);
+            expect(await getTokenbalance(aliceNo)).to.equal(0);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore);
+            expect(vault.totalYesMinted.toNumber()).to.equal(150);
+            expect(vault.totalNoMinted.toNumber()).to.equal(150);
+            expect(vault.totalLockedCollateral.toNumber()).to.equal(150 * 1_000_000);
+
+            console.log(" Self-minted and merged 10 pairs");
+            await logBalances("Alice", aliceUsdc, aliceYes, aliceNo);
+        })
+
+        it("Should provide and withdraw AMM liquidity without changing vault totals",async()=>{
+            const usdcVaultPda = getAssociatedTokenAddressSync(usdcMint, escrowVaultPda, true);
+            const [poolPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("amm_pool"), marketPda.toBuffer()],
+                escrowProgram.programId
+            );
+            const [lpMint] = PublicKey.findProgramAddressSync(
+                [Buffer.from("lp_mint"), poolPda.toBuffer()],
+                escrowProgram.programId
+            );
+            const yesReserve = getAssociatedTokenAddressSync(yesTokenMint.publicKey, poolPda, true);
+            const noReserve = getAssociatedTokenAddressSync(noTokenMint.publicKey, poolPda, true);
+            const feeVault = getAssociatedTokenAddressSync(usdcMint, poolPda, true);
+
+            await escrowProgram.methods.initializePool(30).accounts({
+                admin : provider.wallet.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                pool : poolPda,
+                lpMint,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                usdcMint,
+                usdcVault : usdcVaultPda,
+                yesReserve,
+                noReserve,
+                feeVault,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            const bobLp = (await getOrCreateAssociatedTokenAccount(provider.connection, bob, lpMint, bob.publicKey)).address;
+            const bobUsdcBefore = await getTokenbalance(bobUsdc);
+            const bobYesBefore = await getTokenbalance(bobYes);
+            const bobNoBefore = await getTokenbalance(bobNo);
+
+            await escrowProgram.methods.addLiquidity(new anchor.BN(20)).accounts({
+                provider : bob.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                market : marketPda,
+                pool : poolPda,
+                lpMint,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                yesReserve,
+                noReserve,
+                feeVault,
+                usdcVault : usdcVaultPda,
+                providerUsdc : bobUsdc,
+                providerYesAccount : bobYes,
+                providerNoAccount : bobNo,
+                providerLpAccount : bobLp,
+                treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([bob]).rpc();
+
+            expect(await getTokenbalance(yesReserve)).to.equal(20);
+            expect(await getTokenbalance(noReserve)).to.equal(20);
+            expect(await getTokenbalance(bobLp)).to.equal(20);
+
+            // min_out above anything the pool can pay -> rejected, no state change
+            try{
+                await escrowProgram.methods.buy({ yes : {} }, new anchor.BN(2_000_000), new anchor.BN(1_000)).accounts({
+                    buyer : alice.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda,
+                    market : marketPda,
+                    pool : poolPda,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    yesReserve,
+                    noReserve,
+                    feeVault,
+                    usdcVault : usdcVaultPda,
+                    buyerUsdc : aliceUsdc,
+                    buyerOutcomeAccount : aliceYes,
+                    treasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([alice]).rpc();
+                expect.fail("Buy should fail on slippage");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("SlippageExceeded");
+            }
+
+            await escrowProgram.methods.removeLiquidity(new anchor.BN(20)).accounts({
+                provider : bob.publicKey,
+                // @ts-ignore
+                pool : poolPda,
+                lpMint,
+                yesReserve,
+                noReserve,
+                feeVault,
+                providerLpAccount : bobLp,
+                providerYesAccount : bobYes,
+                providerNoAccount : bobNo,
+                providerUsdc : bobUsdc,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([bob]).rpc();
+
+            // merge the withdrawn pairs so the rest of the suite sees the same vault
+            await escrowProgram.methods.mergePairs(new anchor.BN(20)).accounts({
+                user : bob.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                usdcVault : usdcVaultPda,
+                userUsdc : bobUsdc,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                userYesAccount : bobYes,
+                userNoAccount : bobNo,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([bob]).rpc();
+
+            const vault = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
+            expect(await getTokenbalance(bobUsdc)).to.equal(bobUsdcBefore);
+            expect(await getTokenbalance(bobYes)).to.equal(bobYesBefore);
+            expect(await getTokenbalance(bobNo)).to.equal(bobNoBefore);
+            expect(vault.totalLockedCollateral.toNumber()).to.equal(150 * 1_000_000);
+
+            console.log(" AMM liquidity round trip complete");
+        })
+
+        it("Should buy and sell against the pool and pay the fees to the providers",async()=>{
+            const usdcVaultPda = getAssociatedTokenAddressSync(usdcMint, escrowVaultPda, true);
+            const [poolPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("amm_pool"), marketPda.toBuffer()],
+                escrowProgram.programId
+            );
+            const [lpMint] = PublicKey.findProgramAddressSync(
+                [Buffer.from("lp_mint"), poolPda.toBuffer()],
+                escrowProgram.programId
+            );
+            const yesReserve = getAssociatedTokenAddressSync(yesTokenMint.publicKey, poolPda, true);
+            const noReserve = getAssociatedTokenAddressSync(noTokenMint.publicKey, poolPda, true);
+            const feeVault = getAssociatedTokenAddressSync(usdcMint, poolPda, true);
+
+            const bobLp = getAssociatedTokenAddressSync(lpMint, bob.publicKey);
+            const charlieLp = (await getOrCreateAssociatedTokenAccount(provider.connection, charlie, lpMint, charlie.publicKey)).address;
+
+            const addLiquidity = (user : Keypair, usdc : PublicKey, yes : PublicKey, no : PublicKey, lp : PublicKey) =>
+                escrowProgram.methods.addLiquidity(new anchor.BN(20)).accounts({
+                    provider : user.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda,
+                    market : marketPda,
+                    pool : poolPda,
+                    lpMint,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    yesReserve,
+                    noReserve,
+                    feeVault,
+                    usdcVault : usdcVaultPda,
+                    providerUsdc : usdc,
+                    providerYesAccount : yes,
+                    providerNoAccount : no,
+                    providerLpAccount : lp,
+                    treasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([user]).rpc();
+
+            // withdraws all 20 shares and merges the 20 returned pairs back into usdc
+            const exitPool = async (user : Keypair, usdc : PublicKey, yes : PublicKey, no : PublicKey, lp : PublicKey) => {
+                await escrowProgram.methods.removeLiquidity(new anchor.BN(20)).accounts({
+                    provider : user.publicKey,
+                    // @ts-ignore
+                    pool : poolPda,
+                    lpMint,
+                    yesReserve,
+                    noReserve,
+                    feeVault,
+                    providerLpAccount : lp,
+                    providerYesAccount : yes,
+                    providerNoAccount : no,
+                    providerUsdc : usdc,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([user]).rpc();
+                await escrowProgram.methods.mergePairs(new anchor.BN(20)).accounts({
+                    user : user.publicKey,
+                    // @ts-ignore
+                    vault : escrowVaultPda,
+                    usdcVault : usdcVaultPda,
+                    userUsdc : usdc,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    userYesAccount : yes,
+                    userNoAccount : no,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([user]).rpc();
+            };
+
+            const bobUsdcBefore = await getTokenbalance(bobUsdc);
+            await addLiquidity(bob, bobUsdc, bobYes, bobNo, bobLp);
+
+            // 5.5 USDC -> 16_500 LP fee (30 bps) and 5 whole pairs, the 0.4835 USDC left over stays with alice
+            const aliceUsdcBefore = await getTokenbalance(aliceUsdc);
+            const aliceYesBefore = await getTokenbalance(aliceYes);
+            await escrowProgram.methods.buy({ yes : {} }, new anchor.BN(5_500_000), new anchor.BN(9)).accounts({
+                buyer : alice.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                market : marketPda,
+                pool : poolPda,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                yesReserve,
+                noReserve,
+                feeVault,
+                usdcVault : usdcVaultPda,
+                buyerUsdc : aliceUsdc,
+                buyerOutcomeAccount : aliceYes,
+                treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+
+            // k = 20 * 20 -> NO reserve 20 + 5 = 25, YES reserve 400 / 25 = 16, alice gets 5 + 4 = 9 YES
+            expect(await getTokenbalance(yesReserve)).to.equal(16);
+            expect(await getTokenbalance(noReserve)).to.equal(25);
+            expect(await getTokenbalance(aliceYes)).to.equal(aliceYesBefore + 9);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore - 5_016_500);
+            expect(await getTokenbalance(feeVault)).to.equal(16_500);
+
+            // selling the 9 YES back merges 5 pairs -> 5 USDC minus the 15_000 LP fee
+            await escrowProgram.methods.sell({ yes : {} }, new anchor.BN(9), new anchor.BN(4_985_000)).accounts({
+                seller : alice.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                market : marketPda,
+                pool : poolPda,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                yesReserve,
+                noReserve,
+                feeVault,
+                usdcVault : usdcVaultPda,
+                sellerUsdc : aliceUsdc,
+                sellerOutcomeAccount : aliceYes,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+
+            expect(await getTokenbalance(yesReserve)).to.equal(20);
+            expect(await getTokenbalance(noReserve)).to.equal(20);
+            expect(await getTokenbalance(aliceYes)).to.equal(aliceYesBefore);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore - 31_500);
+            expect(await getTokenbalance(feeVault)).to.equal(31_500);
+
+            // a new provider buys into the fees already earned -> withdrawing right away only returns the buy-in
+            const charlieUsdcBefore = await getTokenbalance(charlieUsdc);
+            await addLiquidity(charlie, charlieUsdc, charlieYes, charlieNo, charlieLp);
+            expect(await getTokenbalance(charlieLp)).to.equal(20);
+            expect(await getTokenbalance(feeVault)).to.equal(63_000);
+
+            await exitPool(charlie, charlieUsdc, charlieYes, charlieNo, charlieLp);
+            expect(await getTokenbalance(charlieUsdc)).to.equal(charlieUsdcBefore);
+
+            // bob keeps the fees earned while he was the only provider
+            await exitPool(bob, bobUsdc, bobYes, bobNo, bobLp);
+            expect(await getTokenbalance(bobUsdc)).to.equal(bobUsdcBefore + 31_500);
+            expect(await getTokenbalance(feeVault)).to.equal(0);
+
+            const vault = await escrowProgram.account.escrowVault.fetch(escrowVaultPda);
+            expect(vault.totalLockedCollateral.toNumber()).to.equal(150 * 1_000_000);
+
+            console.log(" AMM buy/sell fees paid to the providers");
+        })
+
+        it("Should match YES orders on the order book in both directions",async()=>{
+            const usdcVaultPda = getAssociatedTokenAddressSync(usdcMint, escrowVaultPda, true);
+            const [orderBookPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("order_book"), marketPda.toBuffer()],
+                orderBookProgram.programId
+            );
+            const [protocolConfigPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("protocol_config")],
+                escrowProgram.programId
+            );
+            const usdcEscrow = getAssociatedTokenAddressSync(usdcMint, orderBookPda, true);
+            const yesEscrow = getAssociatedTokenAddressSync(yesTokenMint.publicKey, orderBookPda, true);
+            const noEscrow = getAssociatedTokenAddressSync(noTokenMint.publicKey, orderBookPda, true);
+
+            await orderBookProgram.methods.initializeOrderBook().accounts({
+                admin : provider.wallet.publicKey,
+                // @ts-ignore
+                vault : escrowVaultPda,
+                orderBook : orderBookPda,
+                usdcMint,
+                usdcVault : usdcVaultPda,
+                yesTokenMint : yesTokenMint.publicKey,
+                noTokenMint : noTokenMint.publicKey,
+                usdcEscrow,
+                yesEscrow,
+                noEscrow,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            const aliceUsdcBefore = await getTokenbalance(aliceUsdc);
+            const charlieUsdcBefore = await getTokenbalance(charlieUsdc);
+            const aliceYesBefore = await getTokenbalance(aliceYes);
+            const charlieYesBefore = await getTokenbalance(charlieYes);
+
+            // makers are the (usdc, outcome) accounts an immediate-or-cancel order matches against
+            const placeOrder = async (owner:Keypair, side:any, price:number, quantity:number, ownerUsdc:PublicKey, ownerOutcome:PublicKey, orderType:any = { limit : {} }, makers:PublicKey[] = [])=>{
+                await orderBookProgram.methods.placeOrder({ yes : {} }, side, new anchor.BN(price), new anchor.BN(quantity), orderType).accounts({
+                    owner : owner.publicKey,
+                    // @ts-ignore
+                    orderBook : orderBookPda,
+                    vault : escrowVaultPda,
+                    market : marketPda,
+                    usdcVault : usdcVaultPda,
+                    usdcMint,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    usdcEscrow,
+                    yesEscrow,
+                    noEscrow,
+                    protocolConfig : protocolConfigPda,
+                    treasury,
+                    ownerUsdc,
+                    ownerOutcome,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).remainingAccounts(makers.map((pubkey)=>({ pubkey, isWritable : true, isSigner : false }))).signers([owner]).rpc();
+            };
+
+            const crank = async (firstId:number, secondId:number)=>{
+                await orderBookProgram.methods.crankMatch(new anchor.BN(firstId), new anchor.BN(secondId)).accounts({
+                    cranker : admin.publicKey,
+                    // @ts-ignore
+                    orderBook : orderBookPda,
+                    vault : escrowVaultPda,
+                    market : marketPda,
+                    usdcVault : usdcVaultPda,
+                    usdcMint,
+                    yesTokenMint : yesTokenMint.publicKey,
+                    noTokenMint : noTokenMint.publicKey,
+                    usdcEscrow,
+                    yesEscrow,
+                    noEscrow,
+                    protocolConfig : protocolConfigPda,
+                    treasury,
+                    firstUsdc : aliceUsdc,
+                    firstOutcome : aliceYes,
+                    secondUsdc : charlieUsdc,
+                    secondOutcome : charlieYes,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([admin]).rpc();
+            };
+
+            // Alice asks 20 YES @ 0.60, Charlie bids @ 0.65 -> trades at the maker price
+            await placeOrder(alice, { ask : {} }, 600_000, 20, aliceUsdc, aliceYes);
+            await placeOrder(charlie, { bid : {} }, 650_000, 20, charlieUsdc, charlieYes);
+            await crank(1, 2);
+
+            expect(await getTokenbalance(charlieYes)).to.equal(charlieYesBefore + 20);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore + 12_000_000);
+            expect(await getTokenbalance(charlieUsdc)).to.equal(charlieUsdcBefore - 12_000_000);
+
+            // Charlie sells them back to Alice at the same price
+            await placeOrder(charlie, { ask : {} }, 600_000, 20, charlieUsdc, charlieYes);
+            await placeOrder(alice, { bid : {} }, 600_000, 20, aliceUsdc, aliceYes);
+            await crank(4, 3);
+
+            const book = await orderBookProgram.account.orderBook.fetch(orderBookPda);
+            expect(book.yesBids.length).to.equal(0);
+            expect(book.yesAsks.length).to.equal(0);
+            expect(await getTokenbalance(aliceYes)).to.equal(aliceYesBefore);
+            expect(await getTokenbalance(charlieYes)).to.equal(charlieYesBefore);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore);
+            expect(await getTokenbalance(charlieUsdc)).to.equal(charlieUsdcBefore);
+            expect(await getTokenbalance(usdcEscrow)).to.equal(0);
+
+            // dust orders are rejected, 5 YES @ 0.60 is worth less than 10 pairs
+            try {
+                await placeOrder(alice, { ask : {} }, 600_000, 5, aliceUsdc, aliceYes);
+                expect.fail("Should have rejected a dust order");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("OrderTooSmall");
+            }
+
+            // Alice rests 20 YES @ 0.60 and 20 YES @ 0.62, only the best ask can be cranked
+            await placeOrder(alice, { ask : {} }, 600_000, 20, aliceUsdc, aliceYes);
+            await placeOrder(alice, { ask : {} }, 620_000, 20, aliceUsdc, aliceYes);
+            await placeOrder(charlie, { bid : {} }, 650_000, 20, charlieUsdc, charlieYes);
+            try {
+                await crank(6, 7);
+                expect.fail("Should have rejected a match behind the best ask");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("NotBestOrder");
+            }
+            await orderBookProgram.methods.cancelOrder(new anchor.BN(7)).accounts({
+                signer : charlie.publicKey,
+                // @ts-ignore
+                orderBook : orderBookPda,
+                ownerTokenAccount : charlieUsdc,
+                escrowTokenAccount : usdcEscrow,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([charlie]).rpc();
+
+            // Charlie's immediate-or-cancel bid for 30 @ 0.61 takes the 0.60 ask, the 0.62 ask is above its limit so 10 are refunded
+            await placeOrder(charlie, { bid : {} }, 610_000, 30, charlieUsdc, charlieYes, { immediateOrCancel : {} }, [aliceUsdc, aliceYes]);
+
+            const bookAfterIoc = await orderBookProgram.account.orderBook.fetch(orderBookPda);
+            expect(bookAfterIoc.yesBids.length).to.equal(0);
+            expect(bookAfterIoc.yesAsks.length).to.equal(1);
+            expect(bookAfterIoc.yesAsks[0].id.toNumber()).to.equal(6);
+            expect(await getTokenbalance(charlieYes)).to.equal(charlieYesBefore + 20);
+            expect(await getTokenbalance(charlieUsdc)).to.equal(charlieUsdcBefore - 12_000_000);
+            expect(await getTokenbalance(aliceUsdc)).to.equal(aliceUsdcBefore + 12_000_000);
+            expect(await getTokenbalance(usdcEscrow)).to.equal(0);
+
+            await orderBookProgram.methods.cancelOrder(new anchor.BN(6)).accounts({
+                signer : alice.publicKey,
+                // @ts-ignore
+                orderBook : orderBookPda,
+                ownerTokenAccount : aliceYes,
+                escrowTokenAccount : yesEscrow,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+            expect(await getTokenbalance(aliceYes)).to.equal(aliceYesBefore - 20);
+
+            console.log(" Order book round trip complete");
+        })
+
+
     })
 
     describe("Market Resolution",()=>{
@@ -518,13 +1159,15 @@ describe("Hydra Market Full Integration Test",()=>{
 
         it("Should Finalize market with Yes Outcome",async()=>{
             
-            console.log("  ⏳ Waiting for market to expire (16 seconds)...");
-             await new Promise(resolve => setTimeout(resolve, 16000));
-            await marketProgram.methods.finalizeMarket({yes:{}}).accounts({
-                resolutionAdapter:resolutionAdapter.publicKey,
+            const waitMs = Math.max(0, (expireAt.toNumber() + 1) * 1000 - Date.now());
+            console.log(`  ⏳ Waiting for market to expire (${Math.ceil(waitMs / 1000)} seconds)...`);
+             await new Promise(resolve => setTimeout(resolve, waitMs));
+            // only the resolution proposal PDA can finalize through the adapter -> protocol admin override here
+            await marketProgram.methods.emergencyFinalizeMarket({yes:{}},"integration test outcome").accounts({
+                admin : provider.wallet.publicKey,
                 // @ts-ignore
                 market : marketPda
-            }).signers([resolutionAdapter]).rpc();
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
 
             const market = await marketProgram.account.market.fetch(marketPda);
             expect(getMarketState(market.state)).to.equal("RESOLVED");
@@ -582,6 +1225,7 @@ describe("Hydra Market Full Integration Test",()=>{
                 noTokenMint :  noTokenMint.publicKey,
                 userYesAccount : aliceYes,
                 userNoAccount : aliceNo,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID 
             }).signers([alice]).rpc();
 
@@ -623,6 +1267,7 @@ describe("Hydra Market Full Integration Test",()=>{
                 userNoAccount : charlieNo,
                 yesTokenMint : yesTokenMint.publicKey,
                 noTokenMint : noTokenMint.publicKey,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
             }).signers([charlie]).rpc();
 
@@ -660,6 +1305,7 @@ describe("Hydra Market Full Integration Test",()=>{
                 userNoAccount : bobNo,
                 yesTokenMint : yesTokenMint.publicKey,
                 noTokenMint : noTokenMint.publicKey,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
             }).signers([bob]).rpc();
 
@@ -723,6 +1369,7 @@ describe("Hydra Market Full Integration Test",()=>{
                     noTokenMint:noTokenMint.publicKey,
                     yesRecipient : bobYes,
                     noRecipient : charlieNo,
+                    treasury,
                     tokenProgram : TOKEN_PROGRAM_ID
                 }).signers([settlementWorker]).rpc();
                 expect.fail("It Should Fail the Error")
@@ -752,6 +1399,7 @@ describe("Hydra Market Full Integration Test",()=>{
                     noTokenMint: noTokenMint.publicKey,
                     userYesAccount : aliceYes,
                     userNoAccount : aliceNo,
+                    treasury,
                     tokenProgram : TOKEN_PROGRAM_ID
                 })
             }catch(e){
@@ -759,5 +1407,191 @@ describe("Hydra Market Full Integration Test",()=>{
             }
         })
     })
+
+    describe("Partner Collateral",()=>{
+        it("Should mint, claim and sweep on a market backed by another mint",async()=>{
+            // a partner stablecoin with 9 decimals, the treasury owner only holds an account for it
+            const partnerMint = await createMint(provider.connection, admin, admin.publicKey, null, 9);
+            const partnerUnit = 1_000_000_000;
+            const partnerTreasury = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerMint, admin.publicKey)).address;
+            const aliceToken = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerMint, alice.publicKey)).address;
+            const bobToken = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerMint, bob.publicKey)).address;
+            await mintTo(provider.connection, admin, partnerMint, aliceToken, admin, 100 * partnerUnit);
+            await mintTo(provider.connection, admin, partnerMint, bobToken, admin, 100 * partnerUnit);
+
+            const partnerMarketId = new Uint8Array(32).fill(2);
+            const partnerExpireAt = new anchor.BN(Math.floor(Date.now() / 1000) + 20);
+            const partnerYesMint = Keypair.generate();
+            const partnerNoMint = Keypair.generate();
+            const [partnerMarket] = PublicKey.findProgramAddressSync(
+                [Buffer.from("market"),Buffer.from(partnerMarketId)],
+                marketProgram.programId
+            );
+            const [partnerVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"),partnerMarket.toBuffer()],
+                escrowProgram.programId
+            );
+            const [partnerResolution] = PublicKey.findProgramAddressSync(
+                [Buffer.from("resolution"),partnerMarket.toBuffer()],
+                resolutionProgramId
+            );
+            const [partnerBondVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("bond_vault"),partnerMarket.toBuffer()],
+                resolutionProgramId
+            );
+            const partnerUsdcVault = getAssociatedTokenAddressSync(partnerMint, partnerVault, true);
+
+            await marketProgram.methods.initializeMarket({
+                marketId : Array.from(partnerMarketId),
+                question : "Will the partner market settle in its own stablecoin?",
+                description,
+                category,
+                expireAt : partnerExpireAt,
+                resolutionSource,
+                resolutionCategory : {crypto:{}},
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
+            }).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : partnerMarket,
+                yesTokenMint : partnerYesMint.publicKey,
+                noTokenMint : partnerNoMint.publicKey,
+                escrowVault : partnerVault,
+                escrowProgram : escrowProgram.programId,
+                usdcMint : partnerMint,
+                usdcVault : partnerUsdcVault,
+                resolutionAdapter : partnerResolution,
+                resolutionBondVault : partnerBondVault,
+                resolutionProgram : resolutionProgramId,
+                creatorBondAccount : null,
+                bondVault : null,
+                systemProgram: SystemProgram.programId,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                collateralTokenProgram : TOKEN_PROGRAM_ID,
+                associatedTokenProgram : ASSOCIATED_TOKEN_PROGRAM_ID,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            }).signers([admin,partnerYesMint,partnerNoMint]).rpc();
+
+            await marketProgram.methods.openMarket().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : partnerMarket
+            }).signers([admin]).rpc();
+
+            const aliceYesAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerYesMint.publicKey, alice.publicKey)).address;
+            const aliceNoAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerNoMint.publicKey, alice.publicKey)).address;
+            const bobYesAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerYesMint.publicKey, bob.publicKey)).address;
+            const bobNoAccount = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, partnerNoMint.publicKey, bob.publicKey)).address;
+
+            // the usdc treasury account of the first market is not accepted for the partner mint
+            try{
+                await escrowProgram.methods.mintPairsSelf(new anchor.BN(1)).accounts({
+                    user : alice.publicKey,
+                    // @ts-ignore
+                    vault : partnerVault,
+                    market : partnerMarket,
+                    usdcVault : partnerUsdcVault,
+                    usdcMint : partnerMint,
+                    userUsdc : aliceToken,
+                    yesTokenMint : partnerYesMint.publicKey,
+                    noTokenMint : partnerNoMint.publicKey,
+                    userYesAccount : aliceYesAccount,
+                    userNoAccount : aliceNoAccount,
+                    treasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([alice]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("TreasuryMismatch");
+            }
+
+            for (const [user, userToken, userYes, userNo, pairs] of [
+                [alice, aliceToken, aliceYesAccount, aliceNoAccount, 10],
+                [bob, bobToken, bobYesAccount, bobNoAccount, 5],
+            ] as [Keypair, PublicKey, PublicKey, PublicKey, number][]) {
+                await escrowProgram.methods.mintPairsSelf(new anchor.BN(pairs)).accounts({
+                    user : user.publicKey,
+                    // @ts-ignore
+                    vault : partnerVault,
+                    market : partnerMarket,
+                    usdcVault : partnerUsdcVault,
+                    usdcMint : partnerMint,
+                    userUsdc : userToken,
+                    yesTokenMint : partnerYesMint.publicKey,
+                    noTokenMint : partnerNoMint.publicKey,
+                    userYesAccount : userYes,
+                    userNoAccount : userNo,
+                    treasury : partnerTreasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([user]).rpc();
+            }
+            // one pair locks one whole partner token (9 decimals)
+            expect(await getTokenbalance(partnerUsdcVault)).to.equal(15 * partnerUnit);
+
+            const waitMs = Math.max(0, (partnerExpireAt.toNumber() + 1) * 1000 - Date.now());
+            await new Promise(resolve => setTimeout(resolve, waitMs));
+            await marketProgram.methods.emergencyFinalizeMarket({yes:{}},"partner collateral outcome").accounts({
+                admin : provider.wallet.publicKey,
+                // @ts-ignore
+                market : partnerMarket
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            // shortest claim window, so the leftover can be swept right away
+            await escrowProgram.methods.setClaimWindow(new anchor.BN(10)).accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+            await escrowProgram.methods.settle().accounts({
+                authority : admin.publicKey,
+                // @ts-ignore
+                vault : partnerVault,
+                market : partnerMarket
+            }).signers([admin]).rpc();
+            await escrowProgram.methods.setClaimWindow(new anchor.BN(365 * 24 * 60 * 60)).accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            const aliceBefore = await getTokenbalance(aliceToken);
+            await escrowProgram.methods.claimPayout().accounts({
+                user : alice.publicKey,
+                // @ts-ignore
+                vault : partnerVault,
+                market : partnerMarket,
+                usdcVault : partnerUsdcVault,
+                userUsdc : aliceToken,
+                yesTokenMint : partnerYesMint.publicKey,
+                noTokenMint : partnerNoMint.publicKey,
+                userYesAccount : aliceYesAccount,
+                userNoAccount : aliceNoAccount,
+                treasury : partnerTreasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([alice]).rpc();
+            expect(await getTokenbalance(aliceToken) - aliceBefore).to.equal(10 * partnerUnit);
+
+            // bob never claims, his 5 tokens go to the partner treasury account after the deadline
+            await new Promise(resolve => setTimeout(resolve, 11000));
+            const treasuryBefore = await getTokenbalance(partnerTreasury);
+            await escrowProgram.methods.sweepUnclaimed().accounts({
+                admin : provider.wallet.publicKey,
+                // @ts-ignore
+                vault : partnerVault,
+                usdcVault : partnerUsdcVault,
+                treasury : partnerTreasury,
+                usdcMint : partnerMint,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).remainingAccounts([
+                { pubkey : partnerYesMint.publicKey, isSigner : false, isWritable : false },
+                { pubkey : partnerNoMint.publicKey, isSigner : false, isWritable : false },
+            ]).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+
+            expect(await getTokenbalance(partnerTreasury) - treasuryBefore).to.equal(5 * partnerUnit);
+            expect(await getTokenbalance(partnerUsdcVault)).to.equal(0);
+            const vault = await escrowProgram.account.escrowVault.fetch(partnerVault);
+            expect(vault.unclaimedSwept.toNumber()).to.equal(5 * partnerUnit);
+
+            console.log(" Partner collateral minted, claimed and swept to its own treasury account");
+        })
+    })
 })
 
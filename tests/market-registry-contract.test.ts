@@ -3,7 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
 import { expect, use } from "chai";
 import { MarketRegistry     } from "../target/types/market_registry";
-import { TOKEN_PROGRAM_ID, calculateEpochFee } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, calculateEpochFee, createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 
 describe("Market Registery Complete Tests",()=>{
     const provider = anchor.AnchorProvider.env()
@@ -24,7 +24,7 @@ describe("Market Registery Complete Tests",()=>{
     let market1YesMint : Keypair; //we need to create their mint account in test 
     let market1NoMint : Keypair;
     let market1EscrowVault : PublicKey; //hodl usdc for market1 
-    let market1ResolutionAdapter : Keypair; // oracle feed 
+    let market1ResolutionAdapter : PublicKey; // resolution proposal PDA
 
 
     // market - 2  account 
@@ -36,8 +36,10 @@ describe("Market Registery Complete Tests",()=>{
     let market2ResolutionAdapter : PublicKey;
 
 
-      // Mock escrow program (for testing - would be real in production)
-    let mockEscrowProgram: PublicKey;
+    // vault + resolution proposal are created by CPI during market creation
+    const escrowProgramId = anchor.workspace.EscrowVault.programId as PublicKey;
+    const resolutionProgramId = anchor.workspace.ResolutionAdapter.programId as PublicKey;
+    let usdcMint : PublicKey;
 
     // Time constants
     const nowTimestamp = Math.floor(Date.now() / 1000);
@@ -67,10 +69,39 @@ describe("Market Registery Complete Tests",()=>{
         if(state.paused) return "PAUSED";
         if(state.resolving) return "RESOLVING";
         if(state.resolved) return "RESOLVED";
+        if(state.close) return "CLOSED";
+        if(state.cancelled) return "CANCELLED";
         return "UNKNOWN"
     }
 
-    async function createMarket(marketId : Uint8Array , question : string , expireAt : number , signer : Keypair = admin){
+    // accounts every market constructor needs to create its vault and resolution proposal
+    function bootstrapAccounts(marketPda : PublicKey){
+        const [escrowVault] = PublicKey.findProgramAddressSync(
+            [Buffer.from("escrow_vault"),marketPda.toBuffer()],
+            escrowProgramId
+        );
+        const [resolutionAdapter] = PublicKey.findProgramAddressSync(
+            [Buffer.from("resolution"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+        const [resolutionBondVault] = PublicKey.findProgramAddressSync(
+            [Buffer.from("bond_vault"),marketPda.toBuffer()],
+            resolutionProgramId
+        );
+        return {
+            escrowVault,
+            escrowProgram : escrowProgramId,
+            usdcMint,
+            usdcVault : getAssociatedTokenAddressSync(usdcMint,escrowVault,true),
+            resolutionAdapter,
+            resolutionBondVault,
+            resolutionProgram : resolutionProgramId,
+            collateralTokenProgram : TOKEN_PROGRAM_ID,
+            associatedTokenProgram : ASSOCIATED_TOKEN_PROGRAM_ID,
+        };
+    }
+
+    async function createMarket(marketId : Uint8Array , question : string , expireAt : number , signer : Keypair = admin , bond : {creatorBondAccount : PublicKey, bondVault : PublicKey} | null = null , schedule : {openAt : number | null, tradingCloseAt : number | null} | null = null , resolutionConfig : any = null){
        const yesMint = Keypair.generate();
        const noMint = Keypair.generate();
 
@@ -81,18 +112,20 @@ describe("Market Registery Complete Tests",()=>{
 
        const [escrowVaultPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("escrow_vault"),marketPda.toBuffer()],
-        mockEscrowProgram
+        escrowProgramId
        );
 
-       const resolutionAdapter  = Keypair.generate();
-
        const params = {
         marketId : Array.from(marketId),
         question,
         description : `Test market : ${question}`,
         category : "Test",
         expireAt :new anchor.BN(expireAt),
-        resolutionSource: "Test Oracle" 
+        resolutionSource: "Test Oracle",
+        resolutionCategory : { crypto : {} },
+        openAt : schedule?.openAt ? new anchor.BN(schedule.openAt) : null,
+        tradingCloseAt : schedule?.tradingCloseAt ? new anchor.BN(schedule.tradingCloseAt) : null,
+        resolutionConfig
        }
 
        await program.methods
@@ -103,9 +136,9 @@ describe("Market Registery Complete Tests",()=>{
         market: marketPda,
         yesTokenMint: yesMint.publicKey,
         noTokenMint: noMint.publicKey,
-        escrowVault: escrowVaultPda,
-        escrowProgram: mockEscrowProgram,
-        resolutionAdapter:resolutionAdapter.publicKey,
+        ...bootstrapAccounts(marketPda),
+        creatorBondAccount : bond ? bond.creatorBondAccount : null,
+        bondVault : bond ? bond.bondVault : null,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -118,7 +151,7 @@ describe("Market Registery Complete Tests",()=>{
       yesMint,
       noMint,
       escrowVaultPda,
-      resolutionAdapter,
+      resolutionAdapter : bootstrapAccounts(marketPda).resolutionAdapter,
     };
 
 
@@ -133,7 +166,6 @@ describe("Market Registery Complete Tests",()=>{
         admin = Keypair.generate();
         nonAdmin = Keypair.generate();
         user = Keypair.generate();
-        market1ResolutionAdapter = Keypair.generate();
         // airdrop solana to all the users 
 
         await Promise.all([
@@ -142,7 +174,28 @@ describe("Market Registery Complete Tests",()=>{
             airdrop(user.publicKey)
         ])
 
-        mockEscrowProgram  = Keypair.generate().publicKey;
+        // collateral of every market in this file
+        usdcMint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+
+        // protocol config is a singleton shared by every test file -> created once by the provider wallet
+        const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("admin")], program.programId);
+        if(!(await provider.connection.getAccountInfo(configPda))){
+            await program.methods.initializeConfig().accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+            // creation starts admin-only, the test files create markets from their own keypairs
+            await program.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(0)).accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+        }
+
+        // admin becomes the protocol admin -> can pause, resume, cancel and emergency finalize any market
+        await program.methods.transferAdmin(admin.publicKey).accounts({
+            admin : provider.wallet.publicKey,
+        }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+        await program.methods.acceptAdmin().accounts({
+            newAdmin : admin.publicKey,
+        }).signers([admin]).rpc();
 
         market1Id = new Uint8Array(32).fill(1);
         market2Id = new Uint8Array(32).fill(2);
@@ -150,10 +203,20 @@ describe("Market Registery Complete Tests",()=>{
         console.log("  Admin:", admin.publicKey.toString());
         console.log("  Non-Admin:", nonAdmin.publicKey.toString());
         console.log("  User:", user.publicKey.toString());
-        console.log("  Mock Escrow Program:", mockEscrowProgram.toString());
+        console.log("  USDC Mint:", usdcMint.toString());
         console.log("\n✅ Setup complete!\n");
     })
 
+    // the config is shared by every test file -> the later files find the protocol admin on the provider wallet again
+    after(async ()=>{
+        await program.methods.transferAdmin(provider.wallet.publicKey).accounts({
+            admin : admin.publicKey,
+        }).signers([admin]).rpc();
+        await program.methods.acceptAdmin().accounts({
+            newAdmin : provider.wallet.publicKey,
+        }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+    })
+
 
 
 
@@ -174,10 +237,9 @@ describe("Market Registery Complete Tests",()=>{
 
             [market1EscrowVault] = PublicKey.findProgramAddressSync(
                 [Buffer.from("escrow_vault"), market1Pda.toBuffer()],
-                mockEscrowProgram
+                escrowProgramId
             );
-
-            
+            market1ResolutionAdapter = bootstrapAccounts(market1Pda).resolutionAdapter;
 
             const question  = "Will BTC reach $100k by the end of janurary 2026";
             const description = "Market will resolve yes if Bitcoin will resovle yes"
@@ -190,7 +252,11 @@ describe("Market Registery Complete Tests",()=>{
                 description,
                 category,
                 expireAt : new anchor.BN(futureExpiry),
-                resolutionSource
+                resolutionSource,
+                resolutionCategory : { crypto : {} },
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
             }
 
             await program.methods.initializeMarket(params).accounts({
@@ -200,9 +266,9 @@ describe("Market Registery Complete Tests",()=>{
                 market : market1Pda,
                 yesTokenMint : market1YesMint.publicKey,
                 noTokenMint : market1NoMint.publicKey,
-                escrowVault : market1EscrowVault,
-                escrowProgram : mockEscrowProgram,
-                resolutionAdapter : market1ResolutionAdapter.publicKey,
+                ...bootstrapAccounts(market1Pda),
+                creatorBondAccount : null,
+                bondVault : null,
                 systemProgram : SystemProgram.programId,
                 tokenProgram : TOKEN_PROGRAM_ID,
                 rent : anchor.web3.SYSVAR_RENT_PUBKEY
@@ -221,7 +287,7 @@ describe("Market Registery Complete Tests",()=>{
             expect(market.yesTokenMint.toString()).to.equal(market1YesMint.publicKey.toString());
             expect(market.noTokenMint.toString()).to.equal(market1NoMint.publicKey.toString());
             expect(market.escrowVault.toString()).to.equal(market1EscrowVault.toString());
-            expect(market.resolutionAdapter.toString()).to.equal(market1ResolutionAdapter.publicKey.toString());
+            expect(market.resolutionAdapter.toString()).to.equal(market1ResolutionAdapter.toString());
             expect(market.resolutionSource).to.equal(resolutionSource);
             expect(getMarketState(market.state)).to.equal("CREATED");
             expect(market.resolutionOutcome).to.be.null;
@@ -242,7 +308,7 @@ describe("Market Registery Complete Tests",()=>{
             market2YesMint = result.yesMint;
             market2NoMint = result.noMint;
             market2EscrowVault = result.escrowVaultPda;
-            market2ResolutionAdapter = result.resolutionAdapter.publicKey;
+            market2ResolutionAdapter = result.resolutionAdapter;
 
             const market  = await program.account.market.fetch(market2Pda);
 
@@ -254,6 +320,222 @@ describe("Market Registery Complete Tests",()=>{
         })
 
 
+        it("Should create a categorical market with three outcomes",async()=>{
+            const categoricalId = Keypair.generate().publicKey.toBytes();
+
+            const [categoricalPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("market"), Buffer.from(categoricalId)],
+                program.programId
+            );
+            const [categoricalVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"), categoricalPda.toBuffer()],
+                escrowProgramId
+            );
+
+            const labels = ["India","Australia","England"];
+            // outcome mints are created up front with the escrow vault as mint authority
+            const outcomeMints : PublicKey[] = [];
+            for (const _ of labels){
+                outcomeMints.push(await createMint(provider.connection,admin,categoricalVault,null,6));
+            }
+
+            const params = {
+                marketId : Array.from(categoricalId),
+                question : "Who will win the 2026 T20 World Cup?",
+                description : "Resolves to the team that wins the final",
+                category : "Sports",
+                expireAt : new anchor.BN(futureExpiry),
+                resolutionSource : "ESPN",
+                resolutionCategory : { sports : {} },
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
+            }
+
+            await program.methods.initializeCategoricalMarket(params,labels).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : categoricalPda,
+                ...bootstrapAccounts(categoricalPda),
+                creatorBondAccount : null,
+                bondVault : null,
+                systemProgram : SystemProgram.programId,
+                tokenProgram : TOKEN_PROGRAM_ID,
+            }).remainingAccounts(outcomeMints.map((mint)=>({pubkey : mint, isSigner : false, isWritable : false})))
+            .signers([admin]).rpc()
+
+            const market = await program.account.market.fetch(categoricalPda);
+
+            expect(market.outcomeCount).to.equal(3);
+            expect(market.outcomeLabels).to.deep.equal(labels);
+            expect(market.outcomeMints.map((m)=>m.toString())).to.deep.equal(outcomeMints.map((m)=>m.toString()));
+            expect(getMarketState(market.state)).to.equal("CREATED");
+
+            console.log(" Categorical market initialized with", market.outcomeCount, "outcomes");
+        })
+
+        it("Should reject a categorical market with two outcomes",async()=>{
+            const categoricalId = Keypair.generate().publicKey.toBytes();
+
+            const [categoricalPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("market"), Buffer.from(categoricalId)],
+                program.programId
+            );
+            const [categoricalVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"), categoricalPda.toBuffer()],
+                escrowProgramId
+            );
+
+            // two outcomes are a binary market, they must go through initialize_market
+            const labels = ["Yes","No"];
+            const outcomeMints : PublicKey[] = [];
+            for (const _ of labels){
+                outcomeMints.push(await createMint(provider.connection,admin,categoricalVault,null,6));
+            }
+
+            try{
+                await program.methods.initializeCategoricalMarket({
+                    marketId : Array.from(categoricalId),
+                    question : "Two outcome categorical",
+                    description : "Should fail",
+                    category : "Sports",
+                    expireAt : new anchor.BN(futureExpiry),
+                    resolutionSource : "ESPN",
+                    resolutionCategory : { sports : {} },
+                    openAt : null,
+                    tradingCloseAt : null,
+                    resolutionConfig : null
+                },labels).accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : categoricalPda,
+                    ...bootstrapAccounts(categoricalPda),
+                    creatorBondAccount : null,
+                    bondVault : null,
+                    systemProgram : SystemProgram.programId,
+                    tokenProgram : TOKEN_PROGRAM_ID,
+                }).remainingAccounts(outcomeMints.map((mint)=>({pubkey : mint, isSigner : false, isWritable : false})))
+                .signers([admin]).rpc()
+
+                expect.fail("Two outcomes should be rejected")
+            }catch(e){
+                expect(e.message).to.include("InvalidOutcomeCount");
+                console.log(" Correctly rejected a two outcome categorical market");
+            }
+        })
+
+        it("Binary market should expose YES/NO as outcome mints",async()=>{
+            const market = await program.account.market.fetch(market1Pda);
+
+            expect(market.outcomeCount).to.equal(2);
+            expect(market.outcomeLabels).to.deep.equal(["Yes","No"]);
+            expect(market.outcomeMints[0].toString()).to.equal(market1YesMint.publicKey.toString());
+            expect(market.outcomeMints[1].toString()).to.equal(market1NoMint.publicKey.toString());
+        })
+
+        it("Should create a scalar market with LONG/SHORT bounds",async()=>{
+            const scalarId = Keypair.generate().publicKey.toBytes();
+            const longMint = Keypair.generate();
+            const shortMint = Keypair.generate();
+
+            const [scalarPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("market"), Buffer.from(scalarId)],
+                program.programId
+            );
+            const [scalarVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"), scalarPda.toBuffer()],
+                escrowProgramId
+            );
+
+            const params = {
+                marketId : Array.from(scalarId),
+                question : "Where will BTC close on 31 Dec 2026?",
+                description : "LONG pays linearly between $80k and $120k",
+                category : "Crypto",
+                expireAt : new anchor.BN(futureExpiry),
+                resolutionSource : "Pyth BTC/USD",
+                resolutionCategory : { crypto : {} },
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
+            }
+            // bounds use the same 8-decimal scale as the oracle consensus price
+            const bounds = {
+                lower : new anchor.BN("8000000000000"),
+                upper : new anchor.BN("12000000000000")
+            }
+
+            await program.methods.initializeScalarMarket(params,bounds).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : scalarPda,
+                yesTokenMint : longMint.publicKey,
+                noTokenMint : shortMint.publicKey,
+                ...bootstrapAccounts(scalarPda),
+                creatorBondAccount : null,
+                bondVault : null,
+                systemProgram : SystemProgram.programId,
+                tokenProgram : TOKEN_PROGRAM_ID,
+                rent : anchor.web3.SYSVAR_RENT_PUBKEY
+            }).signers([admin,longMint,shortMint]).rpc()
+
+            const market = await program.account.market.fetch(scalarPda);
+
+            expect(market.scalarBounds.lower.toString()).to.equal(bounds.lower.toString());
+            expect(market.scalarBounds.upper.toString()).to.equal(bounds.upper.toString());
+            expect(market.outcomeLabels).to.deep.equal(["Long","Short"]);
+            expect(market.yesTokenMint.toString()).to.equal(longMint.publicKey.toString());
+
+            console.log(" Scalar market initialized");
+        })
+
+        it("Should reject a scalar market with inverted bounds",async()=>{
+            const scalarId = Keypair.generate().publicKey.toBytes();
+            const longMint = Keypair.generate();
+            const shortMint = Keypair.generate();
+
+            const [scalarPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("market"), Buffer.from(scalarId)],
+                program.programId
+            );
+            const [scalarVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_vault"), scalarPda.toBuffer()],
+                escrowProgramId
+            );
+
+            try{
+                await program.methods.initializeScalarMarket({
+                    marketId : Array.from(scalarId),
+                    question : "Inverted range",
+                    description : "Should fail",
+                    category : "Crypto",
+                    expireAt : new anchor.BN(futureExpiry),
+                    resolutionSource : "Pyth",
+                    resolutionCategory : { crypto : {} },
+                    openAt : null,
+                    tradingCloseAt : null,
+                    resolutionConfig : null
+                },{ lower : new anchor.BN(200), upper : new anchor.BN(100) }).accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : scalarPda,
+                    yesTokenMint : longMint.publicKey,
+                    noTokenMint : shortMint.publicKey,
+                    ...bootstrapAccounts(scalarPda),
+                    creatorBondAccount : null,
+                    bondVault : null,
+                    systemProgram : SystemProgram.programId,
+                    tokenProgram : TOKEN_PROGRAM_ID,
+                    rent : anchor.web3.SYSVAR_RENT_PUBKEY
+                }).signers([admin,longMint,shortMint]).rpc()
+
+                expect.fail("Inverted bounds should be rejected")
+            }catch(e){
+                expect(e.message).to.include("InvalidScalarBounds");
+                console.log(" Correctly rejected inverted scalar bounds");
+            }
+        })
+
         // done
         it("Should fail to Create Duplicate Market (Same Market Id)",async ()=>{
             try{
@@ -295,7 +577,7 @@ describe("Market Registery Complete Tests",()=>{
 
             const [escrowVaultPda] = PublicKey.findProgramAddressSync(
                 [Buffer.from("escrow_vault"),marketPda.toBuffer()],
-                mockEscrowProgram
+                escrowProgramId
             );
 
             const params = {
@@ -304,7 +586,11 @@ describe("Market Registery Complete Tests",()=>{
                 description : "Test",
                 category : "Sports",
                 expireAt : new anchor.BN(futureExpiry),
-                resolutionSource : "Test"
+                resolutionSource : "Test",
+                resolutionCategory : { crypto : {} },
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig : null
             }
 
 
@@ -315,9 +601,9 @@ describe("Market Registery Complete Tests",()=>{
                     market: marketPda,
                     yesTokenMint: yesMint.publicKey,
                     noTokenMint: noMint.publicKey,
-                    escrowVault: escrowVaultPda,
-                    escrowProgram: mockEscrowProgram,
-                    resolutionAdapter: Keypair.generate().publicKey,
+                    ...bootstrapAccounts(marketPda),
+                    creatorBondAccount : null,
+                    bondVault : null,
                     systemProgram: SystemProgram.programId,
                     tokenProgram: TOKEN_PROGRAM_ID,
                     rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -495,14 +781,14 @@ describe("Market Registery Complete Tests",()=>{
             console.log(`  Current time: ${new Date(nowTime * 1000).toISOString()}`);
             console.log(`  Market expired: ${nowTime > shortExpiry ? "YES ✅" : "NO ❌"}`);
             
-            // Step 5: Finalize
-            await program.methods.finalizeMarket({ yes: {} })
+            // Step 5: Finalize (resolution proposal PDA is the only regular finalizer -> admin override here)
+            await program.methods.emergencyFinalizeMarket({ yes: {} }, "registry test outcome")
                 .accounts({
-                    resolutionAdapter: result.resolutionAdapter.publicKey,
+                    admin: admin.publicKey,
                     // @ts-ignore
                     market: result.marketPda
                 })
-                .signers([result.resolutionAdapter])
+                .signers([admin])
                 .rpc();
             
             // Step 6: Verify
@@ -516,6 +802,256 @@ describe("Market Registery Complete Tests",()=>{
             console.log(`   Resolved at: ${new Date(market.resolvedAt.toNumber() * 1000).toISOString()}`);
             
         });
+
+        it("Should crank an expired market to CLOSED (permissionless)",async()=>{
+            const shortExpiry = Math.floor(Date.now() / 1000) + 15;
+            const result = await createMarket(new Uint8Array(32).fill(40), "Crank Expire Test", shortExpiry);
+
+            await program.methods.openMarket().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+
+            try{
+                await program.methods.crankExpire().accounts({
+                    cranker : user.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("MarketNotExpired");
+            }
+
+            console.log("  ⏳ Waiting for market to expire (16 seconds)...");
+            await new Promise(resolve => setTimeout(resolve, 16000));
+
+            // trading stops at expiry even while the state still says OPEN
+            try{
+                await program.methods.assertMarketOpen().accounts({
+                    market : result.marketPda
+                }).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("MarketExpired");
+            }
+
+            await program.methods.crankExpire().accounts({
+                cranker : user.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([user]).rpc();
+
+            const market = await program.account.market.fetch(result.marketPda);
+            expect(getMarketState(market.state)).to.equal("CLOSED");
+
+            try{
+                await program.methods.crankExpire().accounts({
+                    cranker : user.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidMarketState");
+            }
+
+            // CLOSED markets cannot be reopened, only resolved
+            try{
+                await program.methods.resumeMarket().accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidMarketState");
+            }
+
+            console.log("✅ Expired market cranked to CLOSED");
+        })
+
+        it("Should open a scheduled market permissionlessly and stop trading at trading close",async()=>{
+            const now = Math.floor(Date.now() / 1000);
+            const openAt = now + 12;
+            const tradingCloseAt = now + 25;
+            const result = await createMarket(new Uint8Array(32).fill(41), "Scheduled Market Test", now + 60, admin, null, { openAt, tradingCloseAt });
+
+            let market = await program.account.market.fetch(result.marketPda);
+            expect(market.openAt.toNumber()).to.equal(openAt);
+            expect(market.tradingCloseAt.toNumber()).to.equal(tradingCloseAt);
+
+            // nobody opens before open_at, not even the creator
+            try{
+                await program.methods.openMarket().accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("MarketNotYetOpen");
+            }
+
+            await new Promise(resolve => setTimeout(resolve, Math.max(0, (openAt + 1) * 1000 - Date.now())));
+
+            // any signer can open once open_at has passed
+            await program.methods.openMarket().accounts({
+                admin : user.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([user]).rpc();
+            await program.methods.assertMarketOpen().accounts({ market : result.marketPda }).rpc();
+
+            await new Promise(resolve => setTimeout(resolve, Math.max(0, (tradingCloseAt + 1) * 1000 - Date.now())));
+
+            try{
+                await program.methods.assertMarketOpen().accounts({ market : result.marketPda }).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("TradingClosed");
+            }
+
+            // trading close (not expiry) is what lets the crank close the market
+            await program.methods.crankExpire().accounts({
+                cranker : user.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([user]).rpc();
+            market = await program.account.market.fetch(result.marketPda);
+            expect(getMarketState(market.state)).to.equal("CLOSED");
+
+            // the event is postponed before expiry -> moving the expiry reopens trading
+            const newExpireAt = now + 3600;
+            await program.methods.updateExpiry({ expireAt : new anchor.BN(newExpireAt), holdForPostponement : false }).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+            market = await program.account.market.fetch(result.marketPda);
+            expect(getMarketState(market.state)).to.equal("OPEN");
+            expect(market.tradingCloseAt.toNumber()).to.equal(tradingCloseAt + (newExpireAt - (now + 60)));
+            const lastTransition = market.stateHistory[market.stateHistory.length - 1];
+            expect([getMarketState(lastTransition.oldState),getMarketState(lastTransition.newState)]).to.deep.equal(["CLOSED","OPEN"]);
+            expect(lastTransition.actor.equals(admin.publicKey)).to.be.true;
+            await program.methods.assertMarketOpen().accounts({ market : result.marketPda }).rpc();
+
+            console.log("✅ Scheduled market opened by anyone, closed at trading close and reopened by a postponement");
+        })
+
+        it("Should reject a trading close after expiry",async()=>{
+            const now = Math.floor(Date.now() / 1000);
+            try{
+                await createMarket(new Uint8Array(32).fill(42), "Bad Schedule Test", now + 60, admin, null, { openAt : null, tradingCloseAt : now + 120 });
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidTradingCloseTime");
+            }
+        })
+
+        it("Should let the creator move the expiry of a postponed event",async()=>{
+            const now = Math.floor(Date.now() / 1000);
+            const expireAt = now + 60;
+            const tradingCloseAt = now + 40;
+            const result = await createMarket(new Uint8Array(32).fill(43), "Postponed Event Test", expireAt, admin, null, { openAt : null, tradingCloseAt });
+
+            await program.methods.openMarket().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+
+            const newExpireAt = now + 3600;
+            try{
+                await program.methods.updateExpiry({ expireAt : new anchor.BN(newExpireAt), holdForPostponement : true }).accounts({
+                    admin : user.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("Unauthorized");
+            }
+
+            // below MIN_EXPIRY_DURATION
+            try{
+                await program.methods.updateExpiry({ expireAt : new anchor.BN(now + 2), holdForPostponement : false }).accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("ExpiryTooShort");
+            }
+
+            await program.methods.updateExpiry({ expireAt : new anchor.BN(newExpireAt), holdForPostponement : true }).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+
+            const market = await program.account.market.fetch(result.marketPda);
+            expect(market.expireAt.toNumber()).to.equal(newExpireAt);
+            // trading close moves with the event
+            expect(market.tradingCloseAt.toNumber()).to.equal(tradingCloseAt + (newExpireAt - expireAt));
+            expect(market.postponementHold).to.equal(true);
+            expect(getMarketState(market.state)).to.equal("OPEN");
+
+            console.log("✅ Expiry moved and postponement hold set");
+        })
+
+        it("Should store a per-market resolution config",async()=>{
+            const now = Math.floor(Date.now() / 1000);
+            // 15-minute BTC market: 10 minute dispute window, 1 hour to resolve
+            const resolutionConfig = {
+                resolutionWindow : new anchor.BN(3600),
+                disputeWindow : new anchor.BN(600),
+                disputeExtension : new anchor.BN(600),
+                minProposalBond : new anchor.BN(10_000_000),
+                maxPriceDeviationBps : 100,
+                maxOracleStaleness : new anchor.BN(30),
+                maxEscalationRounds : 3,
+                voteDuration : new anchor.BN(600),
+                slashFeeBps : 1000,
+                proposalWindow : new anchor.BN(600)
+            };
+            const result = await createMarket(new Uint8Array(32).fill(44), "Short BTC Market Test", now + 900, admin, null, null, resolutionConfig);
+
+            const market = await program.account.market.fetch(result.marketPda);
+            expect(market.resolutionConfig.resolutionWindow.toNumber()).to.equal(3600);
+            expect(market.resolutionConfig.disputeWindow.toNumber()).to.equal(600);
+            expect(market.resolutionConfig.minProposalBond.toNumber()).to.equal(10_000_000);
+            expect(market.resolutionConfig.maxPriceDeviationBps).to.equal(100);
+
+            // markets without a config get the protocol defaults
+            const defaults = await program.account.market.fetch(market1Pda);
+            expect(defaults.resolutionConfig.resolutionWindow.toNumber()).to.equal(604_800);
+        })
+
+        it("Should reject a resolution config outside the protocol bounds",async()=>{
+            const now = Math.floor(Date.now() / 1000);
+            // escalated through every round, the proposal could not finalize inside the resolution window
+            const resolutionConfig = {
+                resolutionWindow : new anchor.BN(3600),
+                disputeWindow : new anchor.BN(3000),
+                disputeExtension : new anchor.BN(3000),
+                minProposalBond : new anchor.BN(10_000_000),
+                maxPriceDeviationBps : 100,
+                maxOracleStaleness : new anchor.BN(30),
+                maxEscalationRounds : 3,
+                voteDuration : new anchor.BN(600),
+                slashFeeBps : 1000,
+                proposalWindow : new anchor.BN(600)
+            };
+            try{
+                await createMarket(new Uint8Array(32).fill(45), "Bad Resolution Config Test", now + 900, admin, null, null, resolutionConfig);
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidResolutionConfig");
+            }
+        })
         
     })
 
@@ -524,7 +1060,6 @@ describe("Market Registery Complete Tests",()=>{
     // Done
     describe("Resolution Outcome",()=>{
         let market3Pda : PublicKey;
-        let market3ResolutionAdapter : Keypair;
         before(async()=>{
             const market3Id = new Uint8Array(32).fill(3);
 
@@ -536,7 +1071,6 @@ describe("Market Registery Complete Tests",()=>{
             );
             
             market3Pda = result.marketPda;
-            market3ResolutionAdapter = result.resolutionAdapter;
             await program.methods
             .openMarket() 
             .accounts({
@@ -557,14 +1091,14 @@ describe("Market Registery Complete Tests",()=>{
         })
 
         it("It Should Finalized With No Outcome",async()=>{
-            await program.methods.finalizeMarket({no:{}})
+            await program.methods.emergencyFinalizeMarket({no:{}},"registry test outcome")
             .accounts(
                 {
-                    resolutionAdapter : market3ResolutionAdapter.publicKey,
+                    admin : admin.publicKey,
                     // @ts-ignore
                     market : market3Pda
                 }
-            ).signers([market3ResolutionAdapter]).rpc()
+            ).signers([admin]).rpc()
 
             const market = await program.account.market.fetch(market3Pda);
             expect(getMarketState(market.state)).to.equal("RESOLVED");
@@ -613,12 +1147,12 @@ describe("Market Registery Complete Tests",()=>{
             console.log(`  Current time: ${new Date(nowTime * 1000).toISOString()}`);
             console.log(`  Market expired: ${nowTime > shortExpiry ? "YES ✅" : "NO ❌"}`);
             
-            await program.methods.finalizeMarket({invalid:{}})
+            await program.methods.emergencyFinalizeMarket({invalid:{}},"registry test outcome")
                 .accounts({
-                    resolutionAdapter : result.resolutionAdapter.publicKey,
+                    admin : admin.publicKey,
                     // @ts-ignore
                     market : market4Pda
-                }).signers([result.resolutionAdapter]).rpc();
+                }).signers([admin]).rpc();
             
             const marketFin = await program.account.market.fetch(market4Pda);
             expect(getMarketState(marketFin.state)).to.equal("RESOLVED");
@@ -627,7 +1161,7 @@ describe("Market Registery Complete Tests",()=>{
         })
 
         // Done 
-        it("Should to fail To Finalizing before Resolving State",async()=>{
+        it("Should only accept finalization from the market's resolution proposal",async()=>{
             const market5id = new Uint8Array(32).fill(5);
 
             const result = await createMarket(
@@ -636,30 +1170,35 @@ describe("Market Registery Complete Tests",()=>{
                 futureExpiry
             )
 
+            const market = await program.account.market.fetch(result.marketPda);
+            expect(market.resolutionAdapter.toString()).to.equal(result.resolutionAdapter.toString());
+
+            // a keypair can no longer stand in for the resolution adapter
+            const impostor = Keypair.generate();
             try{
             await program.methods.finalizeMarket({yes:{}})
                   .accounts({
-                    resolutionAdapter : result.resolutionAdapter.publicKey,
+                    resolutionAdapter : impostor.publicKey,
                     // @ts-ignore
                     market : result.marketPda
-                  }).signers([result.resolutionAdapter]).rpc()
+                  }).signers([impostor]).rpc()
                   expect.fail("Should have thrown error");
                 }
                 catch(e:any){
-                    expect(e.error.errorCode.code).to.equal("InvalidMarketState");
-                    console.log("✅ Correctly rejected early finalization");
+                    expect(e.error.errorCode.code).to.equal("InvalidResolutionAdapter");
+                    console.log("✅ Correctly rejected finalization by a stray signer");
                 }  
         })
 
         // Done 
         it("Should Failed to Finalize Twice",async()=>{
             try{
-                await program.methods.finalizeMarket({no:{}})
+                await program.methods.emergencyFinalizeMarket({no:{}},"registry test outcome")
                       .accounts({
-                        resolutionAdapter : market3ResolutionAdapter.publicKey,
+                        admin : admin.publicKey,
                         // @ts-ignore
                         market : market3Pda
-                      }).signers([market3ResolutionAdapter]).rpc()
+                      }).signers([admin]).rpc()
                 expect.fail("Should have thrown error");      
             }catch(e:any){
                 expect(e.error.errorCode.code).to.equal("MarketAlreadyResolved");
@@ -727,6 +1266,18 @@ describe("Market Registery Complete Tests",()=>{
                 console.log("Correctly rejected non admin Resume");
                 
             }
+
+            // a paused market cannot be moved into resolution by its creator
+            try{
+                await program.methods.resolvingMarket().accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : testmarketPda
+                }).signers([admin]).rpc();
+                expect.fail("Should Have Thrown Error")
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidMarketState");
+            }
         })
 
         it("Should reject Non admin set resolving",async()=>{
@@ -771,6 +1322,260 @@ describe("Market Registery Complete Tests",()=>{
             }
         })
 
+        it("Should reject pause by a market creator who is not the protocol admin",async()=>{
+            const result = await createMarket(
+                new Uint8Array(32).fill(11),
+                "Creator Pause Test",
+                futureExpiry,
+                user
+            );
+
+            await program.methods.openMarket().accounts({
+                admin : user.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([user]).rpc();
+
+            try{
+                await program.methods.pauseMarket().accounts({
+                    admin : user.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("Unauthorized");
+                console.log("✅ Creator cannot pause without protocol admin rights");
+            }
+        })
+
+        it("Should hand over the protocol admin in two steps",async()=>{
+            const [configPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("admin")],
+                program.programId
+            );
+
+            await program.methods.transferAdmin(nonAdmin.publicKey).accounts({
+                admin : admin.publicKey,
+            }).signers([admin]).rpc();
+
+            // nothing changes until the nominee accepts
+            let config = await program.account.adminAuthority.fetch(configPda);
+            expect(config.admin.toString()).to.equal(admin.publicKey.toString());
+            expect(config.pendingAdmin.toString()).to.equal(nonAdmin.publicKey.toString());
+
+            try{
+                await program.methods.acceptAdmin().accounts({
+                    newAdmin : user.publicKey,
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("Unauthorized");
+            }
+
+            await program.methods.acceptAdmin().accounts({
+                newAdmin : nonAdmin.publicKey,
+            }).signers([nonAdmin]).rpc();
+
+            config = await program.account.adminAuthority.fetch(configPda);
+            expect(config.admin.toString()).to.equal(nonAdmin.publicKey.toString());
+            expect(config.pendingAdmin).to.be.null;
+
+            // hand it back so the remaining tests keep using admin
+            await program.methods.transferAdmin(admin.publicKey).accounts({
+                admin : nonAdmin.publicKey,
+            }).signers([nonAdmin]).rpc();
+            await program.methods.acceptAdmin().accounts({
+                newAdmin : admin.publicKey,
+            }).signers([admin]).rpc();
+
+            console.log("✅ Protocol admin handed over and back");
+        })
+
+        it("Should enforce admin-only creation and the per-creator cooldown",async()=>{
+            await program.methods.updateCreationPolicy({ adminOnly : {} }, new anchor.BN(0)).accounts({
+                admin : admin.publicKey,
+            }).signers([admin]).rpc();
+
+            try{
+                await createMarket(new Uint8Array(32).fill(12), "Admin Only Test", futureExpiry, nonAdmin);
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("CreationRestricted");
+            }
+
+            await program.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(3600)).accounts({
+                admin : admin.publicKey,
+            }).signers([admin]).rpc();
+
+            await createMarket(new Uint8Array(32).fill(13), "Cooldown Test 1", futureExpiry, nonAdmin);
+            try{
+                await createMarket(new Uint8Array(32).fill(14), "Cooldown Test 2", futureExpiry, nonAdmin);
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("CreationRateLimited");
+            }
+
+            // back to open creation for the remaining tests
+            await program.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(0)).accounts({
+                admin : admin.publicKey,
+            }).signers([admin]).rpc();
+
+            console.log("✅ Creation mode and cooldown enforced");
+        })
+
+        it("Should slash the creator bond of a cancelled market",async()=>{
+            const bondMint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+            const treasury = (await getOrCreateAssociatedTokenAccount(provider.connection, admin, bondMint, admin.publicKey)).address;
+            const userBond = (await getOrCreateAssociatedTokenAccount(provider.connection, user, bondMint, user.publicKey)).address;
+            await mintTo(provider.connection, admin, bondMint, userBond, admin, 10_000_000);
+
+            const [bondVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("creator_bond_vault")],
+                program.programId
+            );
+
+            await program.methods.configureCreatorBond(new anchor.BN(5_000_000)).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                bondMint,
+                bondVault,
+                bondTreasury : treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([admin]).rpc();
+
+            // no bond accounts -> rejected while a bond is required
+            try{
+                await createMarket(new Uint8Array(32).fill(15), "Missing Bond Test", futureExpiry, user);
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("InvalidBondAccount");
+            }
+
+            const result = await createMarket(new Uint8Array(32).fill(15), "Creator Bond Test", futureExpiry, user, { creatorBondAccount : userBond, bondVault });
+            let market = await program.account.market.fetch(result.marketPda);
+            expect(market.creatorBond.toNumber()).to.equal(5_000_000);
+            expect(Number((await getAccount(provider.connection, userBond)).amount)).to.equal(5_000_000);
+
+            await program.methods.cancelMarket().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+            market = await program.account.market.fetch(result.marketPda);
+            expect(getMarketState(market.state)).to.equal("CANCELLED");
+
+            // cancelled market -> creator cannot take the bond back
+            try{
+                await program.methods.refundCreatorBond().accounts({
+                    creator : user.publicKey,
+                    // @ts-ignore
+                    market : result.marketPda,
+                    bondVault,
+                    creatorBondAccount : userBond,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([user]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("BondNotRefundable");
+            }
+
+            await program.methods.slashCreatorBond().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda,
+                bondVault,
+                bondTreasury : treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([admin]).rpc();
+
+            market = await program.account.market.fetch(result.marketPda);
+            expect(market.creatorBond.toNumber()).to.equal(0);
+            expect(Number((await getAccount(provider.connection, treasury)).amount)).to.equal(5_000_000);
+
+            // an ordinary INVALID resolution is not slashable, the creator gets the bond back
+            const invalidResult = await createMarket(new Uint8Array(32).fill(17), "Invalid Bond Test", futureExpiry, user, { creatorBondAccount : userBond, bondVault });
+            await program.methods.emergencyFinalizeMarket({ invalid : {} }, "registry test outcome").accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : invalidResult.marketPda
+            }).signers([admin]).rpc();
+
+            try{
+                await program.methods.slashCreatorBond().accounts({
+                    admin : admin.publicKey,
+                    // @ts-ignore
+                    market : invalidResult.marketPda,
+                    bondVault,
+                    bondTreasury : treasury,
+                    tokenProgram : TOKEN_PROGRAM_ID
+                }).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("BondNotSlashable");
+            }
+
+            await program.methods.refundCreatorBond().accounts({
+                creator : user.publicKey,
+                // @ts-ignore
+                market : invalidResult.marketPda,
+                bondVault,
+                creatorBondAccount : userBond,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([user]).rpc();
+            expect(Number((await getAccount(provider.connection, userBond)).amount)).to.equal(5_000_000);
+
+            // remaining tests create markets without a bond
+            await program.methods.configureCreatorBond(new anchor.BN(0)).accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                bondMint,
+                bondVault,
+                bondTreasury : treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([admin]).rpc();
+
+            console.log("✅ Creator bond posted and slashed");
+        })
+
+        it("Should keep a cancelled market until the grace period passed and its vault is closed",async()=>{
+            const result = await createMarket(new Uint8Array(32).fill(16), "Close Market Test", futureExpiry, user);
+
+            await program.methods.cancelMarket().accounts({
+                admin : admin.publicKey,
+                // @ts-ignore
+                market : result.marketPda
+            }).signers([admin]).rpc();
+
+            const closeAccounts = {
+                closer : admin.publicKey,
+                market : result.marketPda,
+                creator : user.publicKey,
+                escrowVault : result.escrowVaultPda
+            };
+
+            try{
+                // @ts-ignore
+                await program.methods.closeMarket().accounts(closeAccounts).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("MarketNotClosable");
+            }
+
+            await new Promise(resolve => setTimeout(resolve, 12000));
+
+            // the vault created with the market still holds its rent -> it has to be closed first
+            try{
+                // @ts-ignore
+                await program.methods.closeMarket().accounts(closeAccounts).signers([admin]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("EscrowVaultNotClosed");
+            }
+
+            console.log("✅ Cancelled market stays open until its vault is closed");
+        })
+
 
     })
 
@@ -1003,15 +1808,26 @@ describe("Market Registery Complete Tests",()=>{
             await new Promise(resolve => setTimeout(resolve, 16000));
             // Resolving to Resolved 
 
-            await program.methods.finalizeMarket({yes:{}}).accounts({
-                resolutionAdapter : result.resolutionAdapter.publicKey,
+            await program.methods.emergencyFinalizeMarket({yes:{}},"registry test outcome").accounts({
+                admin : admin.publicKey,
                 // @ts-ignore
                 market : marketPda
-            }).signers([result.resolutionAdapter]).rpc();
+            }).signers([admin]).rpc();
 
             const market = await program.account.market.fetch(marketPda);
 
             expect(getMarketState(market.state)).to.equal("RESOLVED");
+
+            // every transition is recorded on the market in order
+            expect(market.stateTransitionCount).to.equal(5);
+            expect(market.stateHistory.map((t)=>[getMarketState(t.oldState),getMarketState(t.newState)])).to.deep.equal([
+                ["CREATED","OPEN"],
+                ["OPEN","PAUSED"],
+                ["PAUSED","OPEN"],
+                ["OPEN","RESOLVING"],
+                ["RESOLVING","RESOLVED"],
+            ]);
+            expect(market.stateHistory.every((t)=>t.actor.equals(admin.publicKey))).to.be.true;
             console.log("✅ Rapid transitions completed successfully");
         })
 
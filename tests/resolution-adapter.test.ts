@@ -3,8 +3,9 @@ import { Program } from "@coral-xyz/anchor";
 import { ResolutionAdapter } from "../target/types/resolution_adapter";
 import { MarketRegistry } from "../target/types/market_registry";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, TokenAccountNotFoundError, calculateEpochFee, createMint, getAccount, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, TokenAccountNotFoundError, getAssociatedTokenAddressSync, calculateEpochFee, createMint, getAccount, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { EscrowVault } from "../target/types/escrow_vault";
 
 
@@ -22,7 +23,6 @@ describe("Resolution Adapter Complete Test",()=>{
     let oracle3 : Keypair;
     let disputer : Keypair;
     let nonOracle : Keypair;
-    let market1ResolutionAdapter: Keypair;
     // Market Account 
 
     let market1Pda : PublicKey;
@@ -46,7 +46,9 @@ describe("Resolution Adapter Complete Test",()=>{
     let oracle2Usdc : PublicKey;
     let oracle3Usdc : PublicKey;
     let disputerUsdc : PublicKey;
-    let protocolTreasuryUsdc : PublicKey;
+    let rewardVault : PublicKey;
+    // protocol admin's bond-mint account, receives the cut of slashed bonds
+    let treasury : PublicKey;
     const nowTimestamp = Math.floor(Date.now()/1000);
     const shortExpiry = nowTimestamp + 15;
     const futureExpiry = nowTimestamp + 30 * 24 * 60 * 60;
@@ -78,10 +80,12 @@ describe("Resolution Adapter Complete Test",()=>{
         if (state.paused) return "PAUSED";
         if (state.resolving) return "RESOLVING";
         if (state.resolved) return "RESOLVED";
+        if (state.cancelled) return "CANCELLED";
         return "UNKNOWN";
     }
 
-    async function createMarket(marketId :Uint8Array,question:string,expiry:number){
+    // escrow vault + resolution proposal (in the given category) are created with the market
+    async function createMarket(marketId :Uint8Array,question:string,expiry:number,resolutionCategory:any = {crypto:{}},resolutionConfig:any = null){
         const yesMint = Keypair.generate();
         const noMint  = Keypair.generate();
 
@@ -91,15 +95,21 @@ describe("Resolution Adapter Complete Test",()=>{
             marketProgram.programId
         );
 
-        const resolutionAdapter = Keypair.generate();
-
-
         const [escrowVault] = PublicKey.findProgramAddressSync(
             [Buffer.from("escrow_vault"),marketPda.toBuffer()],
             escrowProgram.programId
         );
 
-    
+        const [resolutionPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("resolution"),marketPda.toBuffer()],
+            resolutionProgram.programId
+        );
+
+        const [bondVault] = PublicKey.findProgramAddressSync(
+            [Buffer.from("bond_vault"),marketPda.toBuffer()],
+            resolutionProgram.programId
+        );
+
 
         const params = {
             marketId : Array.from(marketId),
@@ -107,7 +117,11 @@ describe("Resolution Adapter Complete Test",()=>{
                 description : `Test ${question}`,
                 category:"Crypto",
                 expireAt : new anchor.BN(expiry),
-                resolutionSource:"Pyth BTC/USDC"
+                resolutionSource:"Pyth BTC/USDC",
+                resolutionCategory,
+                openAt : null,
+                tradingCloseAt : null,
+                resolutionConfig
           };
 
         await marketProgram.methods.initializeMarket(params).accounts({
@@ -118,16 +132,36 @@ describe("Resolution Adapter Complete Test",()=>{
             noTokenMint : noMint.publicKey,
             escrowVault : escrowVault,
             escrowProgram : escrowProgram.programId,
-            resolutionAdapter : resolutionAdapter.publicKey,
+            usdcMint,
+            usdcVault : getAssociatedTokenAddressSync(usdcMint,escrowVault,true),
+            resolutionAdapter : resolutionPda,
+            resolutionBondVault : bondVault,
+            resolutionProgram : resolutionProgram.programId,
+            creatorBondAccount : null,
+            bondVault : null,
             systemProgram : SystemProgram.programId,
             tokenProgram : TOKEN_PROGRAM_ID,
+            collateralTokenProgram : TOKEN_PROGRAM_ID,
+            associatedTokenProgram : ASSOCIATED_TOKEN_PROGRAM_ID,
             rent : anchor.web3.SYSVAR_RENT_PUBKEY
         }).signers([admin , yesMint ,noMint]).rpc();
 
-        return { marketPda, yesMint, noMint, resolutionAdapter };
+        return { marketPda, yesMint, noMint, resolutionPda, bondVault };
     }
 
     before(async()=>{
+        // protocol config is a singleton shared by every test file -> created once by the provider wallet
+        const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("admin")], marketProgram.programId);
+        if(!(await provider.connection.getAccountInfo(configPda))){
+            await marketProgram.methods.initializeConfig().accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+            // creation starts admin-only, the test files create markets from their own keypairs
+            await marketProgram.methods.updateCreationPolicy({ permissionless : {} }, new anchor.BN(0)).accounts({
+                admin : provider.wallet.publicKey,
+            }).signers([(provider.wallet as anchor.Wallet).payer]).rpc();
+        }
+
         admin = Keypair.generate();
         oracle1 = Keypair.generate();  
         oracle2 = Keypair.generate();
@@ -222,7 +256,6 @@ describe("Resolution Adapter Complete Test",()=>{
             market1Pda = result.marketPda;
             market1YesMint = result.yesMint;
             market1NoMint = result.noMint;
-            market1ResolutionAdapter = result.resolutionAdapter;
 
             [resolution1Pda] = PublicKey.findProgramAddressSync(
                 [Buffer.from("resolution"), market1Pda.toBuffer()],
@@ -234,18 +267,6 @@ describe("Resolution Adapter Complete Test",()=>{
                 resolutionProgram.programId
             );
 
-            await resolutionProgram.methods.initializeResolution({crypto:{}}).accounts({
-                authority : admin.publicKey,
-                market : market1Pda,
-                // @ts-ignore
-                resolutionProposal: resolution1Pda,
-                bondVault : bondVault1,
-                bondMint : usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-            }).signers([admin]).rpc();
-
             const resolution = await resolutionProgram.account.resolutionProposal.fetch(resolution1Pda);
       
             expect(resolution.market.toString()).to.equal(market1Pda.toString());
@@ -254,6 +275,9 @@ describe("Resolution Adapter Complete Test",()=>{
             expect(resolution.isDisputed).to.be.false;
             expect(resolution.isFinalized).to.be.false;
             expect(resolution.bondVault.toString()).to.equal(bondVault1.toString());
+            // protocol default config copied from the market (testing dispute window)
+            expect(resolution.config.disputeWindow.toNumber()).to.equal(10);
+            expect(resolution.config.minProposalBond.toNumber()).to.equal(1_000_000_000);
       
             console.log(" Resolution initialized");
             console.log("   Category: Crypto");
@@ -262,7 +286,7 @@ describe("Resolution Adapter Complete Test",()=>{
 
         it("Should Shoudl Initialize a resolution For Sports Market",async()=>{
             console.log("Initilizing Sports Market Resolution");
-            const result = await createMarket(market2Id,"Will india Win against Nz",shortExpiry);
+            const result = await createMarket(market2Id,"Will india Win against Nz",shortExpiry, { sports: {} });
             market2Pda = result.marketPda;
             market2YesMint = result.yesMint;
             market2NoMint = result.noMint;
@@ -277,30 +301,29 @@ describe("Resolution Adapter Complete Test",()=>{
                 resolutionProgram.programId
             );
 
-            await resolutionProgram.methods.initializeResolution({sports:{}}).accounts({
-                authority : admin.publicKey,
-                market : market2Pda,
-                // @ts-ignore
-                resolutionProposal : resolution2Pda,
-                bondVault : bondVault2,
-                bondMint : usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-            }).signers([admin]).rpc();
-
             const resolution = await resolutionProgram.account.resolutionProposal.fetch(resolution2Pda);
 
             expect(resolution.category).to.deep.equal({ sports: {} });
             console.log(" Sports resolution initialized");
         })
 
-        it("Should Failsed To Initlize a Duplicate reolution",async()=>{
+        it("Should reject a resolution initialized outside market creation",async()=>{
 
-            console.log("Testing Duplicate Initialization");
+            console.log("Testing standalone Initialization");
 
             try{
-                await resolutionProgram.methods.initializeResolution({crypto:{}}).accounts({
+                await resolutionProgram.methods.initializeResolution({crypto:{}}, {
+                    resolutionWindow : new anchor.BN(604_800),
+                    disputeWindow : new anchor.BN(10),
+                    disputeExtension : new anchor.BN(86_400),
+                    minProposalBond : new anchor.BN(1_000_000_000),
+                    maxPriceDeviationBps : 500,
+                    maxOracleStaleness : new anchor.BN(300),
+                    maxEscalationRounds : 3,
+                    voteDuration : new anchor.BN(5),
+                    slashFeeBps : 1000,
+                    proposalWindow : new anchor.BN(5)
+                }).accounts({
                     authority : admin.publicKey,
                     market : market1Pda,
                     // @ts-ignore
@@ -313,8 +336,9 @@ describe("Resolution Adapter Complete Test",()=>{
                 }).signers([admin]).rpc()
                 expect.fail("Should have Thrown Error")
             }catch(e){
-                expect(e.message).to.include("already in use");
-                console.log("Correctly rejected duplicate");
+                // only the market registry can sign for the market PDA
+                expect(e.message).to.match(/Signature verification failed|Missing signature/);
+                console.log("Correctly rejected standalone initialization");
             }
             
         })
@@ -407,19 +431,7 @@ describe("Resolution Adapter Complete Test",()=>{
                 [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
                 resolutionProgram.programId
             );
-        
-            await resolutionProgram.methods.initializeResolution({ crypto: {} }).accounts({
-                authority: admin.publicKey,
-                market: result.marketPda,
-                // @ts-ignore
-                resolutionProposal: resolutionPda,
-                bondMint: usdcMint,
-                bondVault,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-            }).signers([admin]).rpc();
-        
+
             await marketProgram.methods.openMarket().accounts({
                 admin: admin.publicKey,
                 // @ts-ignore
@@ -501,7 +513,8 @@ describe("Resolution Adapter Complete Test",()=>{
         const result = await createMarket(
             marketId,
             "Will India Win against NZ",
-            freshExpiry
+            freshExpiry,
+            { sports: {} }
         );
 
         sportMarketPda = result.marketPda;
@@ -517,18 +530,6 @@ describe("Resolution Adapter Complete Test",()=>{
             resolutionProgram.programId
         );
 
-        await resolutionProgram.methods.initializeResolution({ sports: {} }).accounts({
-            authority: admin.publicKey,
-            market: sportMarketPda,
-            // @ts-ignore
-            resolutionProposal: sportResolutionPda,
-            bondVault: sportBondVault,
-            bondMint: usdcMint,
-            systemProgram: SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-        }).signers([admin]).rpc();
-
         // Open the market
         await marketProgram.methods.openMarket().accounts({
             admin: admin.publicKey,
@@ -592,7 +593,7 @@ describe("Resolution Adapter Complete Test",()=>{
 
             const marketId = new Uint8Array(32).fill(31);
             const freshExpiry = Math.floor(Date.now() / 1000) + 15;
-            const result = await createMarket(marketId,"Sports Market(Ind vd Nz)",freshExpiry);
+            const result = await createMarket(marketId,"Sports Market(Ind vd Nz)",freshExpiry, { sports: {} });
 
             const [resolutionPda] = PublicKey.findProgramAddressSync(
                 [Buffer.from("resolution"), result.marketPda.toBuffer()],
@@ -604,22 +605,6 @@ describe("Resolution Adapter Complete Test",()=>{
                 resolutionProgram.programId
             );
 
-            await resolutionProgram.methods
-            .initializeResolution({ sports: {} })
-            .accounts({
-              authority: admin.publicKey,
-              market: result.marketPda,
-              // @ts-ignore
-              resolutionProposal: resolutionPda,
-              bondVault,
-              bondMint: usdcMint,
-              systemProgram: SystemProgram.programId,
-              tokenProgram: TOKEN_PROGRAM_ID,
-              rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-            })
-            .signers([admin])
-            .rpc();
-
             await marketProgram.methods.openMarket().accounts({
                 admin : admin.publicKey,
                 // @ts-ignore
@@ -697,23 +682,7 @@ describe("Resolution Adapter Complete Test",()=>{
                 [Buffer.from("bond_vault"), disputeMarketPda.toBuffer()],
                 resolutionProgram.programId
               );
-            
-              await resolutionProgram.methods
-              .initializeResolution({ crypto: {} })
-              .accounts({
-                authority: admin.publicKey,
-                market: disputeMarketPda,
-                // @ts-ignore
-                resolutionProposal: disputeResolutionPda,
-                bondVault: disputeBondVault,
-                bondMint: usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-              })
-              .signers([admin])
-              .rpc();
-      
+
             await marketProgram.methods.openMarket()
               .accounts({ admin: admin.publicKey, 
                 // @ts-ignore
@@ -757,10 +726,11 @@ describe("Resolution Adapter Complete Test",()=>{
             const disputerBefore = await getTokenbalance(disputerUsdc);
             const vaultBefore = await getTokenbalance(disputeBondVault);
 
+            // a dispute has to at least double the proposal bond
             await resolutionProgram.methods.disputeProposal(
                 { no:{} },
                 "Price did not reach $100k",
-                new anchor.BN(1000 * 1_000_000)
+                new anchor.BN(2000 * 1_000_000)
             ).accounts({
                 disputer : disputer.publicKey,
                 // @ts-ignore
@@ -773,8 +743,8 @@ describe("Resolution Adapter Complete Test",()=>{
             const disputerAfter = await getTokenbalance(disputerUsdc);
             const vaultAfter = await getTokenbalance(disputeBondVault);
 
-            expect(disputerBefore - disputerAfter).to.equal(1000 * 1_000_000);
-            expect(vaultAfter - vaultBefore).to.equal(1000 * 1_000_000);
+            expect(disputerBefore - disputerAfter).to.equal(2000 * 1_000_000);
+            expect(vaultAfter - vaultBefore).to.equal(2000 * 1_000_000);
 
             const resolution = await resolutionProgram.account.resolutionProposal.fetch(disputeResolutionPda);
             expect(resolution.isDisputed).to.be.true;
@@ -782,7 +752,7 @@ describe("Resolution Adapter Complete Test",()=>{
 
             console.log("✅ Dispute submitted");
             console.log("   Counter-outcome: NO");
-            console.log("   Dispute bond: 1000 USDC");
+            console.log("   Dispute bond: 2000 USDC");
             console.log("   Dispute window extended: +24 hours");
         })
         // Done
@@ -813,10 +783,11 @@ describe("Resolution Adapter Complete Test",()=>{
 
         it("Should fail with insufficient Dispute bond",async()=>{
             try{
+                // escalating back to YES needs twice the 2000 USDC of the standing NO round
                 await resolutionProgram.methods.disputeProposal(
-                    {no:{}},
+                    {yes:{}},
                     "Fails With Insuffucient Bond amount",
-                    new anchor.BN(500 * 1_000_000)
+                    new anchor.BN(3000 * 1_000_000)
                 ).accounts({
                     disputer : oracle2.publicKey,
                     // @ts-ignore
@@ -834,63 +805,108 @@ describe("Resolution Adapter Complete Test",()=>{
     })
 
     describe("Finalize Market",()=>{
-        let protocolTreasury: PublicKey;  // Add this variable
-
     before(async () => {
-        // Create protocol treasury USDC account (owned by admin)
+        // Reward vault of the bond mint, owned by the program and spent only by finalize_outcome
+        [rewardVault] = PublicKey.findProgramAddressSync(
+            [Buffer.from("reward_vault"), usdcMint.toBuffer()],
+            resolutionProgram.programId
+        );
+        // only the protocol admin (provider wallet) creates and funds reward vaults
+        const protocolAdmin = (provider.wallet as anchor.Wallet).payer;
+        try{
+            await resolutionProgram.methods.initializeRewardVault().accounts({
+                admin : admin.publicKey,
+                bondMint : usdcMint,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([admin]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("Unauthorized");
+        }
+        await resolutionProgram.methods.initializeRewardVault().accounts({
+            admin : protocolAdmin.publicKey,
+            bondMint : usdcMint,
+            tokenProgram : TOKEN_PROGRAM_ID
+        }).signers([protocolAdmin]).rpc();
+
+        // protocol tops the vault up from its own USDC account, which is also the treasury
         const treasuryAccount = await getOrCreateAssociatedTokenAccount(
             provider.connection,
             admin,
             usdcMint,
-            admin.publicKey  // Treasury owned by admin
+            protocolAdmin.publicKey
         );
-        
-        protocolTreasuryUsdc = treasuryAccount.address;
-
-        // Fund the treasury with USDC for rewards
-        // ORACLE_REWARD in your Rust code is the reward amount
-        // Make sure treasury has enough USDC to pay rewards
-        await mintTo(
-            provider.connection,
-            admin,
-            usdcMint,
-            protocolTreasuryUsdc,
-            admin,
-            100_000 * 1_000_000  // 100,000 USDC for rewards
-        );
-
-        console.log("Protocol Treasury:", protocolTreasuryUsdc.toString());
-        console.log("Treasury funded with 100,000 USDC for rewards");
+        treasury = treasuryAccount.address;
+        await mintTo(provider.connection, admin, usdcMint, treasury, admin, 100_000 * 1_000_000);
+
+        const adminUsdc = await getOrCreateAssociatedTokenAccount(provider.connection, admin, usdcMint, admin.publicKey);
+        await mintTo(provider.connection, admin, usdcMint, adminUsdc.address, admin, 1_000 * 1_000_000);
+        try{
+            await resolutionProgram.methods.fundRewardVault(new anchor.BN(1_000 * 1_000_000)).accounts({
+                funder : admin.publicKey,
+                funderTokenAccount : adminUsdc.address,
+                bondMint : usdcMint,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([admin]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("Unauthorized");
+        }
+
+        await resolutionProgram.methods.fundRewardVault(new anchor.BN(100_000 * 1_000_000)).accounts({
+            funder : protocolAdmin.publicKey,
+            funderTokenAccount : treasury,
+            bondMint : usdcMint,
+            tokenProgram : TOKEN_PROGRAM_ID
+        }).signers([protocolAdmin]).rpc();
+
+        expect(await getTokenbalance(rewardVault)).to.equal(100_000 * 1_000_000);
+        console.log("Reward vault funded with 100,000 USDC for rewards");
     });
         it("Should Finalize Indispute proposal",async()=>{
             console.log("Finlaizing Undisputed proposal");
             
             const oracle1Before = await getTokenbalance(oracle1Usdc);
 
-            await resolutionProgram.methods.finalizeOutcome({yes:{}}).accounts({
-                authority : market1ResolutionAdapter.publicKey,
-                rewardAuthority : admin.publicKey,
+            // permissionless: a keeper without any treasury key finalizes
+            await resolutionProgram.methods.finalizeOutcome().accounts({
+                finalizer : nonOracle.publicKey,
                 market : market1Pda,
                 // @ts-ignore
                 resolutionProposal : resolution1Pda,
                 bondVault : bondVault1,
+                winner : oracle1.publicKey,
                 winnerAccount : oracle1Usdc,
-                protocolTreasury : protocolTreasuryUsdc,
+                rewardVault,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
-            }).signers([market1ResolutionAdapter,admin]).rpc();
-            const oracle1After = await getTokenbalance(oracle1Usdc);
-            const expectedReturn = 1000 * 1_000_000 + 100 * 1_000_000;
-            expect(oracle1After - oracle1Before).to.equal(expectedReturn);
+            }).signers([nonOracle]).rpc();
+            const oracle1Rewarded = await getTokenbalance(oracle1Usdc);
+            // nothing was slashed, the reward still comes from the reward vault
+            expect(oracle1Rewarded - oracle1Before).to.equal(100 * 1_000_000);
             
             const resolution = await resolutionProgram.account.resolutionProposal.fetch(resolution1Pda);
             expect(resolution.isFinalized).to.be.true;
+            expect(resolution.settlement.winningBonds.toNumber()).to.equal(1000 * 1_000_000);
+            expect(resolution.settlement.slashedBonds.toNumber()).to.equal(0);
+
+            await resolutionProgram.methods.claimBond().accounts({
+                participant : oracle1.publicKey,
+                // @ts-ignore
+                resolutionProposal : resolution1Pda,
+                bondVault : bondVault1,
+                participantTokenAccount : oracle1Usdc,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([oracle1]).rpc();
+            const oracle1After = await getTokenbalance(oracle1Usdc);
+            expect(oracle1After - oracle1Rewarded).to.equal(1000 * 1_000_000);
             
             const market = await marketProgram.account.market.fetch(market1Pda);
             expect(getMarketState(market.state)).to.equal("RESOLVED");
             expect(market.resolutionOutcome).to.deep.equal({ yes: {} });
             
             console.log("✅ Finalized successfully");
-            console.log("   Oracle received: 1100 USDC (bond + reward)");
+            console.log("   Oracle received: 1100 USDC (bond back + 100 reward)");
             console.log("   Market state: RESOLVED");
             console.log("   Outcome: YES");
 
@@ -911,24 +927,7 @@ describe("Resolution Adapter Complete Test",()=>{
                 [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
                 resolutionProgram.programId
               );
-        
-              await resolutionProgram.methods
-                .initializeResolution({ crypto: {} })
-                .accounts({
-                  authority: admin.publicKey,
-                  
-                  market: result.marketPda,
-                  // @ts-ignore
-                  resolutionProposal: resolutionPda,
-                  bondVault,
-                  bondMint: usdcMint,
-                  systemProgram: SystemProgram.programId,
-                  tokenProgram: TOKEN_PROGRAM_ID,
-                  rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-                })
-                .signers([admin])
-                .rpc();
-        
+
               await marketProgram.methods.openMarket()
                 .accounts({ 
                     admin: admin.publicKey, 
@@ -967,18 +966,19 @@ describe("Resolution Adapter Complete Test",()=>{
             }).signers([oracle1]).rpc();
            
             try{
-                await resolutionProgram.methods.finalizeOutcome({yes:{}}).accounts({
-                    authority : result.resolutionAdapter.publicKey,
-                    rewardAuthority : admin.publicKey,
+                await resolutionProgram.methods.finalizeOutcome().accounts({
+                    finalizer : nonOracle.publicKey,
                     // @ts-ignore
                     resolutionProposal : resolutionPda,
                     market : result.marketPda,
                     bondVault : bondVault,
+                    winner : oracle1.publicKey,
                     winnerAccount : oracle1Usdc,
-                    protocolTreasury : protocolTreasuryUsdc,
+                    rewardVault,
+                    treasury,
                     tokenProgram : TOKEN_PROGRAM_ID
 
-                }).signers([result.resolutionAdapter,admin]).rpc();
+                }).signers([nonOracle]).rpc();
                 expect.fail("It should Throw Error")
             }catch(e){
                 expect(e.error.errorCode.code).to.equal("DisputeWindowOpen");
@@ -992,17 +992,18 @@ describe("Resolution Adapter Complete Test",()=>{
             
 
           try{
-            await resolutionProgram.methods.finalizeOutcome({yes:{}}).accounts({
-                authority : market1ResolutionAdapter.publicKey,
-                rewardAuthority : admin.publicKey,
+            await resolutionProgram.methods.finalizeOutcome().accounts({
+                finalizer : nonOracle.publicKey,
                 market: market1Pda,
                 // @ts-ignore
                 resolutionProposal : resolution1Pda,
                 bondVault : bondVault1,
+                winner : oracle1.publicKey,
                 winnerAccount : oracle1Usdc,
-                protocolTreasury : protocolTreasuryUsdc,
+                rewardVault,
+                treasury,
                 tokenProgram : TOKEN_PROGRAM_ID
-            }).signers([admin,market1ResolutionAdapter]).rpc();
+            }).signers([nonOracle]).rpc();
 
             expect.fail("It should be Fail")
           }catch(e){
@@ -1363,7 +1364,7 @@ describe("Resolution Adapter Complete Test",()=>{
 
             const marketId = new Uint8Array(32).fill(50);
             const expire = Math.floor(Date.now() / 1000) + 15;
-            const result = await createMarket(marketId, "Max sources test", expire);
+            const result = await createMarket(marketId, "Max sources test", expire, { sports: {} });
       
             const [resolutionPda] = PublicKey.findProgramAddressSync(
               [Buffer.from("resolution"), result.marketPda.toBuffer()],
@@ -1374,23 +1375,7 @@ describe("Resolution Adapter Complete Test",()=>{
               [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
               resolutionProgram.programId
             );
-      
-            await resolutionProgram.methods
-              .initializeResolution({ sports: {} })
-              .accounts({
-                authority: admin.publicKey,
-                market: result.marketPda,
-                // @ts-ignore
-                resolutionProposal: resolutionPda,
-                bondVault,
-                bondMint: usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-              })
-              .signers([admin])
-              .rpc();
-      
+
             await marketProgram.methods.openMarket()
               .accounts({ admin: admin.publicKey,
                 // @ts-ignore
@@ -1448,7 +1433,7 @@ describe("Resolution Adapter Complete Test",()=>{
 
             const marketId = new Uint8Array(32).fill(51);
             const expire = Math.floor(Date.now() / 1000) + 15;
-            const result = await createMarket(marketId, "Max sources test", expire);
+            const result = await createMarket(marketId, "Max sources test", expire, { sports: {} });
       
             const [resolutionPda] = PublicKey.findProgramAddressSync(
               [Buffer.from("resolution"), result.marketPda.toBuffer()],
@@ -1459,23 +1444,7 @@ describe("Resolution Adapter Complete Test",()=>{
               [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
               resolutionProgram.programId
             );
-      
-            await resolutionProgram.methods
-              .initializeResolution({ sports: {} })
-              .accounts({
-                authority: admin.publicKey,
-                market: result.marketPda,
-                // @ts-ignore
-                resolutionProposal: resolutionPda,
-                bondVault,
-                bondMint: usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-              })
-              .signers([admin])
-              .rpc();
-      
+
             await marketProgram.methods.openMarket()
               .accounts({ admin: admin.publicKey,
                 // @ts-ignore
@@ -1531,7 +1500,7 @@ describe("Resolution Adapter Complete Test",()=>{
             }
         })
 
-        it("Should handle maximum 3 Disputes",async()=>{
+        it("Should escalate through 3 doubling disputes to a vote and fall back to the arbitrator",async()=>{
             console.log("\n Testing maximum data sources...");
 
             const marketId = new Uint8Array(32).fill(95);
@@ -1547,23 +1516,7 @@ describe("Resolution Adapter Complete Test",()=>{
               [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
               resolutionProgram.programId
             );
-      
-            await resolutionProgram.methods
-              .initializeResolution({ crypto: {} })
-              .accounts({
-                authority: admin.publicKey,
-                market: result.marketPda,
-                // @ts-ignore
-                resolutionProposal: resolutionPda,
-                bondVault,
-                bondMint: usdcMint,
-                systemProgram: SystemProgram.programId,
-                tokenProgram: TOKEN_PROGRAM_ID,
-                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-              })
-              .signers([admin])
-              .rpc();
-      
+
             await marketProgram.methods.openMarket()
               .accounts({ admin: admin.publicKey,
                 // @ts-ignore
@@ -1610,7 +1563,7 @@ describe("Resolution Adapter Complete Test",()=>{
         .disputeProposal(
           { no: {} },
           "Dispute 1",
-          new anchor.BN(1000 * 1_000_000)
+          new anchor.BN(2000 * 1_000_000)
         )
         .accounts({
           disputer: oracle2.publicKey,
@@ -1624,12 +1577,27 @@ describe("Resolution Adapter Complete Test",()=>{
         .signers([oracle2])
         .rpc();
 
+        // the standing round cannot be escalated by whoever posted it
+        try{
+            await resolutionProgram.methods.disputeProposal({ invalid: {} }, "Own round", new anchor.BN(4000 * 1_000_000)).accounts({
+                disputer: oracle2.publicKey,
+                // @ts-ignore
+                resolutionProposal: resolutionPda,
+                bondVault,
+                disputeBonderAccount: oracle2Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            }).signers([oracle2]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("CannotDisputeOwnProposal");
+        }
+
               // Dispute 2
       await resolutionProgram.methods
       .disputeProposal(
         { invalid: {} },
         "Dispute 2",
-        new anchor.BN(1000 * 1_000_000)
+        new anchor.BN(4000 * 1_000_000)
       )
       .accounts({
         disputer: oracle3.publicKey,
@@ -1642,28 +1610,594 @@ describe("Resolution Adapter Complete Test",()=>{
       .signers([oracle3])
       .rpc();
 
-    // Dispute 3
+    // Dispute 3 = the original proposer counter-escalates back to YES
+    await mintTo(provider.connection, admin, usdcMint, oracle1Usdc, admin, 8000 * 1_000_000);
     await resolutionProgram.methods
       .disputeProposal(
         { yes: {} },
         "Dispute 3",
-        new anchor.BN(1000 * 1_000_000)
+        new anchor.BN(8000 * 1_000_000)
       )
       .accounts({
-        disputer: disputer.publicKey,
+        disputer: oracle1.publicKey,
         // @ts-ignore
         resolutionProposal: resolutionPda,
         bondVault,
-        disputeBonderAccount: disputerUsdc,
+        disputeBonderAccount: oracle1Usdc,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .signers([disputer])
+      .signers([oracle1])
       .rpc();
 
         const resolution = await resolutionProgram.account.resolutionProposal.fetch(resolutionPda);
         expect(resolution.disputes.length).to.equal(3);
+        expect(resolution.arbitratedOutcome).to.be.null;
 
         console.log("✅ Accepted 3 disputes");
+
+        const finalizeAccounts = {
+            finalizer : nonOracle.publicKey,
+            market : result.marketPda,
+            resolutionProposal : resolutionPda,
+            bondVault,
+            winner : oracle2.publicKey,
+            winnerAccount : oracle2Usdc,
+            rewardVault,
+            treasury,
+            tokenProgram : TOKEN_PROGRAM_ID
+        };
+
+        // the last round (YES) is not final, stakers vote first
+        expect(resolution.vote.startedAt.toNumber()).to.be.greaterThan(0);
+        try{
+            // @ts-ignore
+            await resolutionProgram.methods.finalizeOutcome().accounts(finalizeAccounts).signers([nonOracle]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("VoteInProgress");
+        }
+
+        try{
+            await resolutionProgram.methods.disputeProposal({ invalid: {} }, "Dispute 4", new anchor.BN(16_000 * 1_000_000)).accounts({
+                disputer: oracle2.publicKey,
+                // @ts-ignore
+                resolutionProposal: resolutionPda,
+                bondVault,
+                disputeBonderAccount: oracle2Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            }).signers([oracle2]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("MaxDisputesReached");
+        }
+
+        // the arbitrator is set at config creation (provider wallet) until set_arbitrator changes it
+        const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("admin")], marketProgram.programId);
+        const config = await marketProgram.account.adminAuthority.fetch(configPda);
+        expect(config.arbitrator.toString()).to.equal(provider.wallet.publicKey.toString());
+
+        try{
+            await resolutionProgram.methods.arbitrateDispute({ no: {} }).accounts({
+                arbitrator : nonOracle.publicKey,
+                // @ts-ignore
+                resolutionProposal : resolutionPda
+            }).signers([nonOracle]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("Unauthorized");
+        }
+
+        try{
+            await resolutionProgram.methods.arbitrateDispute({ no: {} }).accounts({
+                arbitrator : provider.wallet.publicKey,
+                // @ts-ignore
+                resolutionProposal : resolutionPda
+            }).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("VoteInProgress");
+        }
+
+        // nobody voted: commit (5s) and reveal (5s) pass without a result
+        await new Promise(resolve => setTimeout(resolve, 11000));
+        try{
+            // @ts-ignore
+            await resolutionProgram.methods.finalizeOutcome().accounts(finalizeAccounts).signers([nonOracle]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("AwaitingArbitration");
+        }
+
+        await resolutionProgram.methods.arbitrateDispute({ no: {} }).accounts({
+            arbitrator : provider.wallet.publicKey,
+            // @ts-ignore
+            resolutionProposal : resolutionPda
+        }).rpc();
+
+        const oracle2Before = await getTokenbalance(oracle2Usdc);
+        // only the winning bond's own ATA can receive the reward
+        try{
+            // @ts-ignore
+            await resolutionProgram.methods.finalizeOutcome().accounts({ ...finalizeAccounts, winner : disputer.publicKey, winnerAccount : disputerUsdc }).signers([nonOracle]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("WinnerMismatch");
+        }
+
+        const rewardVaultBefore = await getTokenbalance(rewardVault);
+        const treasuryBefore = await getTokenbalance(treasury);
+        // @ts-ignore
+        await resolutionProgram.methods.finalizeOutcome().accounts(finalizeAccounts).signers([nonOracle]).rpc();
+        const market = await marketProgram.account.market.fetch(result.marketPda);
+        expect(market.resolutionOutcome).to.deep.equal({ no: {} });
+
+        // 13000 slashed (1000 + 4000 + 8000): 10% to the treasury, the reward vault only paid the 100 reward
+        expect(await getTokenbalance(treasury) - treasuryBefore).to.equal(1300 * 1_000_000);
+        expect(rewardVaultBefore - await getTokenbalance(rewardVault)).to.equal(100 * 1_000_000);
+
+        const claimAccounts = (participant : PublicKey, account : PublicKey) => ({
+            participant,
+            resolutionProposal : resolutionPda,
+            bondVault,
+            participantTokenAccount : account,
+            tokenProgram : TOKEN_PROGRAM_ID
+        });
+        // @ts-ignore
+        await resolutionProgram.methods.claimBond().accounts(claimAccounts(oracle2.publicKey, oracle2Usdc)).signers([oracle2]).rpc();
+        const oracle2After = await getTokenbalance(oracle2Usdc);
+
+        // the only NO bond gets its 2000 back plus the slashed bonds after the fee (11700) and the reward
+        expect(oracle2After - oracle2Before).to.equal((2000 + 11_700 + 100) * 1_000_000);
+        expect(await getTokenbalance(bondVault)).to.equal(0);
+
+        try{
+            // @ts-ignore
+            await resolutionProgram.methods.claimBond().accounts(claimAccounts(oracle1.publicKey, oracle1Usdc)).signers([oracle1]).rpc();
+            expect.fail("Should have thrown error");
+        }catch(e){
+            expect(e.error.errorCode.code).to.equal("NothingToClaim");
+        }
+
+        console.log("✅ Arbitrator ruled NO, settled to the first NO bond");
+        })
+
+        it("Should settle an escalated dispute by staker vote and slash the minority",async()=>{
+            const marketId = new Uint8Array(32).fill(96);
+            const expire = Math.floor(Date.now() / 1000) + 15;
+            const result = await createMarket(marketId, "Staker vote test", expire);
+
+            const [resolutionPda] = PublicKey.findProgramAddressSync(
+              [Buffer.from("resolution"), result.marketPda.toBuffer()],
+              resolutionProgram.programId
+            );
+            const [bondVault] = PublicKey.findProgramAddressSync(
+              [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
+              resolutionProgram.programId
+            );
+
+            await Promise.all([oracle1Usdc, oracle2Usdc, oracle3Usdc, disputerUsdc].map(account =>
+                mintTo(provider.connection, admin, usdcMint, account, admin, 20_000 * 1_000_000)
+            ));
+
+            await marketProgram.methods.openMarket()
+              // @ts-ignore
+              .accounts({ admin: admin.publicKey, market: result.marketPda })
+              .signers([admin])
+              .rpc();
+            await new Promise(resolve => setTimeout(resolve, 16000));
+            await marketProgram.methods.resolvingMarket()
+              // @ts-ignore
+              .accounts({ admin: admin.publicKey, market: result.marketPda })
+              .signers([admin])
+              .rpc();
+
+            await resolutionProgram.methods
+              .proposeCryptoOutcome("BTC/USD", { greaterOrEqual: { target: new anchor.BN(100_000) } }, ["0xe62df..."], new anchor.BN(1000 * 1_000_000))
+              .accounts({
+                proposer: oracle1.publicKey,
+                market: result.marketPda,
+                // @ts-ignore
+                resolutionProposal: resolutionPda,
+                bondVault,
+                proposerBondAccount: oracle1Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([oracle1])
+              .rpc();
+
+            const rounds : [any, Keypair, PublicKey, number][] = [
+                [{ no: {} }, oracle2, oracle2Usdc, 2000],
+                [{ invalid: {} }, oracle3, oracle3Usdc, 4000],
+                [{ yes: {} }, disputer, disputerUsdc, 8000],
+            ];
+            for (const [outcome, signer, account, bond] of rounds) {
+                await resolutionProgram.methods.disputeProposal(outcome, "Escalation", new anchor.BN(bond * 1_000_000)).accounts({
+                    disputer: signer.publicKey,
+                    // @ts-ignore
+                    resolutionProposal: resolutionPda,
+                    bondVault,
+                    disputeBonderAccount: account,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                }).signers([signer]).rpc();
+            }
+
+            // commitment = sha256(borsh(outcome) || salt || voter), outcome byte: YES 0, NO 1, INVALID 2
+            const votes : [Keypair, PublicKey, any, number, number][] = [
+                [oracle3, oracle3Usdc, { no: {} }, 1, 6000],
+                [disputer, disputerUsdc, { yes: {} }, 0, 3000],
+                [oracle1, oracle1Usdc, { no: {} }, 1, 1000],
+            ];
+            const voteRecord = (voter : PublicKey) => PublicKey.findProgramAddressSync(
+                [Buffer.from("vote"), resolutionPda.toBuffer(), voter.toBuffer(), Buffer.from([1])],
+                resolutionProgram.programId
+            )[0];
+            const salt = Buffer.alloc(32, 7);
+
+            for (const [voter, account, , outcomeByte, stake] of votes) {
+                const commitment = createHash("sha256")
+                    .update(Buffer.concat([Buffer.from([outcomeByte]), salt, voter.publicKey.toBuffer()]))
+                    .digest();
+                await resolutionProgram.methods.commitVote([...commitment], new anchor.BN(stake * 1_000_000)).accounts({
+                    voter: voter.publicKey,
+                    // @ts-ignore
+                    resolutionProposal: resolutionPda,
+                    voteRecord: voteRecord(voter.publicKey),
+                    bondVault,
+                    voterTokenAccount: account,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                }).signers([voter]).rpc();
+            }
+
+            // commit phase is 5s, oracle1 never reveals
+            await new Promise(resolve => setTimeout(resolve, 5000));
+            for (const [voter, , outcome] of votes.slice(0, 2)) {
+                await resolutionProgram.methods.revealVote(outcome, [...salt]).accounts({
+                    voter: voter.publicKey,
+                    // @ts-ignore
+                    resolutionProposal: resolutionPda,
+                    voteRecord: voteRecord(voter.publicKey),
+                }).signers([voter]).rpc();
+            }
+
+            const tallied = await resolutionProgram.account.resolutionProposal.fetch(resolutionPda);
+            // keyed by bonded outcome, in reveal order
+            expect(tallied.vote.tally.map(entry => [Object.keys(entry.outcome)[0], entry.stake.toNumber()])).to.deep.equal([["no", 6000 * 1_000_000], ["yes", 3000 * 1_000_000]]);
+            expect(tallied.vote.totalStake.toNumber()).to.equal(10_000 * 1_000_000);
+
+            await new Promise(resolve => setTimeout(resolve, 5000));
+
+            const oracle2Before = await getTokenbalance(oracle2Usdc);
+            await resolutionProgram.methods.finalizeOutcome().accounts({
+                finalizer : nonOracle.publicKey,
+                market : result.marketPda,
+                // @ts-ignore
+                resolutionProposal : resolutionPda,
+                bondVault,
+                winner : oracle2.publicKey,
+                winnerAccount : oracle2Usdc,
+                rewardVault,
+                treasury,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([nonOracle]).rpc();
+            const oracle2After = await getTokenbalance(oracle2Usdc);
+
+            const market = await marketProgram.account.market.fetch(result.marketPda);
+            expect(market.resolutionOutcome).to.deep.equal({ no: {} });
+
+            await resolutionProgram.methods.claimBond().accounts({
+                participant : oracle2.publicKey,
+                // @ts-ignore
+                resolutionProposal : resolutionPda,
+                bondVault,
+                participantTokenAccount : oracle2Usdc,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([oracle2]).rpc();
+            const oracle2Claimed = await getTokenbalance(oracle2Usdc);
+
+            expect(oracle2After - oracle2Before).to.equal(100 * 1_000_000);
+            // 10% of the 4000 vote stake outside the majority is slashed (400): 200 to the NO voters, 200 to the NO bond,
+            // which also takes the 13000 slashed bonds minus the 10% protocol fee
+            expect(oracle2Claimed - oracle2After).to.equal((2000 + 11_700 + 200) * 1_000_000);
+
+            const expected = [6200, 2700, 900];
+            for (let i = 0; i < votes.length; i++) {
+                const [voter, account] = votes[i];
+                const before = await getTokenbalance(account);
+                await resolutionProgram.methods.claimVote().accounts({
+                    voter: voter.publicKey,
+                    // @ts-ignore
+                    resolutionProposal: resolutionPda,
+                    voteRecord: voteRecord(voter.publicKey),
+                    bondVault,
+                    voterTokenAccount: account,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                }).signers([voter]).rpc();
+                const after = await getTokenbalance(account);
+                expect(after - before).to.equal(expected[i] * 1_000_000);
+            }
+
+            expect(await getTokenbalance(bondVault)).to.equal(0);
+            console.log("✅ Staker vote settled NO, minority and unrevealed stake slashed");
+        })
+
+        it("Should open a new proposal round when nobody proposed in time",async()=>{
+            const marketId = new Uint8Array(32).fill(97);
+            const expire = Math.floor(Date.now() / 1000) + 15;
+            const result = await createMarket(marketId, "Proposal round test", expire);
+
+            const [resolutionPda] = PublicKey.findProgramAddressSync(
+              [Buffer.from("resolution"), result.marketPda.toBuffer()],
+              resolutionProgram.programId
+            );
+            const [bondVault] = PublicKey.findProgramAddressSync(
+              [Buffer.from("bond_vault"), result.marketPda.toBuffer()],
+              resolutionProgram.programId
+            );
+            const roundAccounts = {
+                caller : nonOracle.publicKey,
+                market : result.marketPda,
+                marketRegistryProgram : marketProgram.programId,
+                resolutionProposal : resolutionPda
+            };
+
+            await marketProgram.methods.openMarket()
+              // @ts-ignore
+              .accounts({ admin: admin.publicKey, market: result.marketPda })
+              .signers([admin])
+              .rpc();
+
+            // the proposal window (5s in testing) counts from expiry
+            try{
+                // @ts-ignore
+                await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("RoundStillActive");
+            }
+
+            await new Promise(resolve => setTimeout(resolve, 22000));
+
+            // permissionless, any keeper can reopen
+            // @ts-ignore
+            await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+
+            let resolution = await resolutionProgram.account.resolutionProposal.fetch(resolutionPda);
+            expect(resolution.round).to.equal(2);
+            expect(resolution.roundHistory.length).to.equal(1);
+            expect(resolution.roundHistory[0].reason).to.deep.equal({ noProposal: {} });
+            expect(resolution.roundOpenedAt.toNumber()).to.be.greaterThan(expire);
+
+            // the new round has its own proposal window
+            try{
+                // @ts-ignore
+                await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("RoundStillActive");
+            }
+
+            await resolutionProgram.methods
+              .proposeCryptoOutcome("BTC/USD", { greaterOrEqual: { target: new anchor.BN(100_000) } }, ["0xe62df..."], new anchor.BN(1000 * 1_000_000))
+              .accounts({
+                proposer: oracle1.publicKey,
+                market: result.marketPda,
+                // @ts-ignore
+                resolutionProposal: resolutionPda,
+                bondVault,
+                proposerBondAccount: oracle1Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([oracle1])
+              .rpc();
+
+            resolution = await resolutionProgram.account.resolutionProposal.fetch(resolutionPda);
+            expect(resolution.proposedOutcome).to.deep.equal({ yes: {} });
+            expect(resolution.bondContributors[0].round).to.equal(2);
+
+            console.log("✅ Empty round closed, round 2 accepted a proposal");
+        })
+
+        it("Should reopen a stale round and extend the market resolution window",async()=>{
+            const marketId = new Uint8Array(32).fill(98);
+            const expire = Math.floor(Date.now() / 1000) + 15;
+            // a proposal window that alone nearly fills the resolution window, so round 2 cannot settle inside it
+            const resolutionConfig = {
+                resolutionWindow : new anchor.BN(3600),
+                disputeWindow : new anchor.BN(60),
+                disputeExtension : new anchor.BN(1),
+                minProposalBond : new anchor.BN(1000 * 1_000_000),
+                maxPriceDeviationBps : 500,
+                maxOracleStaleness : new anchor.BN(300),
+                maxEscalationRounds : 1,
+                voteDuration : new anchor.BN(2),
+                slashFeeBps : 1000,
+                proposalWindow : new anchor.BN(3590),
+            };
+            const result = await createMarket(marketId, "Stale round test", expire, { crypto: {} }, resolutionConfig);
+            const roundAccounts = {
+                caller : nonOracle.publicKey,
+                market : result.marketPda,
+                marketRegistryProgram : marketProgram.programId,
+                resolutionProposal : result.resolutionPda
+            };
+
+            await marketProgram.methods.openMarket()
+              // @ts-ignore
+              .accounts({ admin: admin.publicKey, market: result.marketPda })
+              .signers([admin])
+              .rpc();
+
+            await mintTo(provider.connection, admin, usdcMint, oracle1Usdc, admin, 1000 * 1_000_000);
+            await mintTo(provider.connection, admin, usdcMint, oracle2Usdc, admin, 2000 * 1_000_000);
+
+            await new Promise(resolve => setTimeout(resolve, 16000));
+
+            await resolutionProgram.methods
+              .proposeCryptoOutcome("BTC/USD", { greaterOrEqual: { target: new anchor.BN(100_000) } }, ["0xe62df..."], new anchor.BN(1000 * 1_000_000))
+              .accounts({
+                proposer: oracle1.publicKey,
+                market: result.marketPda,
+                // @ts-ignore
+                resolutionProposal: result.resolutionPda,
+                bondVault: result.bondVault,
+                proposerBondAccount: oracle1Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([oracle1])
+              .rpc();
+
+            // one escalation round -> the first dispute goes straight to the staker vote
+            await resolutionProgram.methods.disputeProposal(
+                { no:{} },
+                "Price did not reach $100k",
+                new anchor.BN(2000 * 1_000_000)
+            ).accounts({
+                disputer : oracle2.publicKey,
+                // @ts-ignore
+                resolutionProposal : result.resolutionPda,
+                bondVault : result.bondVault,
+                disputeBonderAccount : oracle2Usdc,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([oracle2]).rpc();
+
+            const windowEnd = expire + 3600;
+            let market = await marketProgram.account.market.fetch(result.marketPda);
+            expect(market.resolutionWindowExtension.toNumber()).to.equal(0);
+
+            // nobody votes, the vote ends after 4s and the silent arbitrator is stale after one more second
+            await new Promise(resolve => setTimeout(resolve, 8000));
+
+            // @ts-ignore
+            await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+
+            const resolution = await resolutionProgram.account.resolutionProposal.fetch(result.resolutionPda);
+            expect(resolution.round).to.equal(2);
+            expect(resolution.roundHistory[0].reason).to.deep.equal({ stale: {} });
+            expect(resolution.refundPool.toNumber()).to.equal(3000 * 1_000_000);
+
+            // the new round's proposal window plus a full escalation still fits in the registry window
+            const roundDuration = 1 + 60 + 2 * 2;
+            market = await marketProgram.account.market.fetch(result.marketPda);
+            const extendedEnd = windowEnd + market.resolutionWindowExtension.toNumber();
+            expect(extendedEnd).to.equal(resolution.roundOpenedAt.toNumber() + 3590 + roundDuration);
+            expect(extendedEnd).to.be.greaterThan(windowEnd);
+
+            console.log("✅ Stale round closed, market resolution window extended for round 2");
+        })
+
+        it("Should reopen a round whose proposal was overturned by a dispute",async()=>{
+            const marketId = new Uint8Array(32).fill(99);
+            const expire = Math.floor(Date.now() / 1000) + 15;
+            const resolutionConfig = {
+                resolutionWindow : new anchor.BN(3600),
+                disputeWindow : new anchor.BN(3),
+                disputeExtension : new anchor.BN(2),
+                minProposalBond : new anchor.BN(1000 * 1_000_000),
+                maxPriceDeviationBps : 500,
+                maxOracleStaleness : new anchor.BN(300),
+                maxEscalationRounds : 2,
+                voteDuration : new anchor.BN(2),
+                slashFeeBps : 1000,
+                proposalWindow : new anchor.BN(60),
+            };
+            const result = await createMarket(marketId, "Overturned round test", expire, { crypto: {} }, resolutionConfig);
+            const roundAccounts = {
+                caller : nonOracle.publicKey,
+                market : result.marketPda,
+                marketRegistryProgram : marketProgram.programId,
+                resolutionProposal : result.resolutionPda
+            };
+            const claimAccounts = (participant: PublicKey, participantTokenAccount: PublicKey) => ({
+                participant,
+                resolutionProposal : result.resolutionPda,
+                bondVault : result.bondVault,
+                participantTokenAccount,
+                tokenProgram : TOKEN_PROGRAM_ID
+            });
+
+            await marketProgram.methods.openMarket()
+              // @ts-ignore
+              .accounts({ admin: admin.publicKey, market: result.marketPda })
+              .signers([admin])
+              .rpc();
+
+            await mintTo(provider.connection, admin, usdcMint, oracle1Usdc, admin, 1000 * 1_000_000);
+            await mintTo(provider.connection, admin, usdcMint, oracle2Usdc, admin, 2000 * 1_000_000);
+
+            await new Promise(resolve => setTimeout(resolve, 16000));
+
+            await resolutionProgram.methods
+              .proposeCryptoOutcome("BTC/USD", { greaterOrEqual: { target: new anchor.BN(100_000) } }, ["0xe62df..."], new anchor.BN(1000 * 1_000_000))
+              .accounts({
+                proposer: oracle1.publicKey,
+                market: result.marketPda,
+                // @ts-ignore
+                resolutionProposal: result.resolutionPda,
+                bondVault: result.bondVault,
+                proposerBondAccount: oracle1Usdc,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([oracle1])
+              .rpc();
+
+            await resolutionProgram.methods.disputeProposal(
+                { no:{} },
+                "Price did not reach $100k",
+                new anchor.BN(2000 * 1_000_000)
+            ).accounts({
+                disputer : oracle2.publicKey,
+                // @ts-ignore
+                resolutionProposal : result.resolutionPda,
+                bondVault : result.bondVault,
+                disputeBonderAccount : oracle2Usdc,
+                tokenProgram : TOKEN_PROGRAM_ID
+            }).signers([oracle2]).rpc();
+
+            // the proposer can still counter-escalate, the round is not overturned yet
+            try{
+                // @ts-ignore
+                await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("RoundStillActive");
+            }
+
+            // the dispute goes unanswered
+            await new Promise(resolve => setTimeout(resolve, 7000));
+
+            // @ts-ignore
+            await resolutionProgram.methods.openProposalRound().accounts(roundAccounts).signers([nonOracle]).rpc();
+
+            const resolution = await resolutionProgram.account.resolutionProposal.fetch(result.resolutionPda);
+            expect(resolution.round).to.equal(2);
+            expect(resolution.roundHistory.length).to.equal(1);
+            expect(resolution.roundHistory[0].reason).to.deep.equal({ disputeWon: {} });
+            expect(resolution.roundHistory[0].proposer.toString()).to.equal(oracle1.publicKey.toString());
+            expect(resolution.roundHistory[0].disputes).to.equal(1);
+            expect(resolution.proposedOutcome).to.be.null;
+            expect(resolution.bondAmount.toNumber()).to.equal(0);
+            expect(resolution.isDisputed).to.be.false;
+            expect(resolution.disputes.length).to.equal(0);
+            expect(resolution.refundPool.toNumber()).to.equal(3000 * 1_000_000);
+
+            // the overturned proposal's bond went to the winning dispute
+            try{
+                // @ts-ignore
+                await resolutionProgram.methods.claimBond().accounts(claimAccounts(oracle1.publicKey, oracle1Usdc)).signers([oracle1]).rpc();
+                expect.fail("Should have thrown error");
+            }catch(e){
+                expect(e.error.errorCode.code).to.equal("NothingToClaim");
+            }
+
+            const oracle2Before = await getTokenbalance(oracle2Usdc);
+            // @ts-ignore
+            await resolutionProgram.methods.claimBond().accounts(claimAccounts(oracle2.publicKey, oracle2Usdc)).signers([oracle2]).rpc();
+            expect(await getTokenbalance(oracle2Usdc) - oracle2Before).to.equal(3000 * 1_000_000);
+
+            const after = await resolutionProgram.account.resolutionProposal.fetch(result.resolutionPda);
+            expect(after.refundPool.toNumber()).to.equal(0);
+
+            console.log("✅ Overturned proposal closed, round 2 open for a new proposal");
         })
     })
 